@@ -8,18 +8,22 @@
 //! can become daunting to query a [`Dictionary`](crate::Dictionary) for even
 //! the most basic operation.
 
-use crate::{dict, dict::FixDatatype, TagU32};
+use crate::{dict, dict::FixDatatype, FieldType, TagU32};
+use std::marker::PhantomData;
 
+/// `V` is the Rust type of the field values, i.e. the generated `enum` for
+/// fields with dictionary-defined values, or `()` otherwise.
 #[derive(Debug, Clone)]
 #[doc(hidden)]
-pub struct HardCodedFixFieldDefinition {
+pub struct HardCodedFixFieldDefinition<V = ()> {
     pub name: &'static str,
     pub tag: u32,
     pub data_type: FixDatatype,
     pub location: dict::FieldLocation,
+    pub value_type: PhantomData<fn() -> V>,
 }
 
-impl dict::IsFieldDefinition for HardCodedFixFieldDefinition {
+impl<V> dict::IsFieldDefinition for HardCodedFixFieldDefinition<V> {
     #[inline]
     fn tag(&self) -> TagU32 {
         TagU32::new(self.tag).expect("Invalid tag number 0.")
@@ -36,6 +40,206 @@ impl dict::IsFieldDefinition for HardCodedFixFieldDefinition {
     }
 }
 
+/// A [`dict::IsFieldDefinition`] with a statically known Rust type for its
+/// values, e.g. the generated `enum` of a field with dictionary-defined values.
+/// See [`FieldMap::get`](crate::FieldMap::get).
+pub trait IsTypedFieldDefinition: dict::IsFieldDefinition {
+    /// The Rust type of the values of `self`.
+    type Value: for<'a> FieldType<'a>;
+}
+
+impl<F> IsTypedFieldDefinition for &F
+where
+    F: IsTypedFieldDefinition + ?Sized,
+{
+    type Value = F::Value;
+}
+
+impl<V> IsTypedFieldDefinition for HardCodedFixFieldDefinition<V>
+where
+    V: for<'a> FieldType<'a>,
+{
+    type Value = V;
+}
+
+/// The type returned in the event of an error when converting between FIX
+/// messages and the owned message structs generated with
+/// `codegen::Settings::owned_messages`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MessageFieldError {
+    /// A required field is missing.
+    #[error("Missing required field {tag}.")]
+    Missing {
+        /// The tag of the missing field.
+        tag: u32,
+    },
+    /// A field has an invalid value, e.g. text that can't be represented in
+    /// the `MessageEncoding <347>` of the message.
+    #[error("Invalid value for field {tag}.")]
+    Invalid {
+        /// The tag of the invalid field.
+        tag: u32,
+    },
+}
+
+/// Conversions between FIX messages and the fields of owned message structs,
+/// as used by code generated with `codegen::Settings::owned_messages`.
+#[doc(hidden)]
+pub mod owned {
+    use super::MessageFieldError;
+    use crate::field_types::MessageEncoding;
+    use crate::tagvalue::MessageTree;
+    use crate::{FieldMap, FieldType, RepeatingGroup, SetField, TagU32};
+
+    const MSG_TYPE: u32 = 35;
+    const MESSAGE_ENCODING: u32 = 347;
+
+    pub fn required<T>(value: Option<T>, tag: u32) -> Result<T, MessageFieldError> {
+        value.ok_or(MessageFieldError::Missing { tag })
+    }
+
+    pub fn message_encoding<M>(fields: &M) -> Result<MessageEncoding, MessageFieldError>
+    where
+        M: FieldMap<u32>,
+    {
+        fields
+            .message_encoding()
+            .map_err(|_| MessageFieldError::Invalid {
+                tag: MESSAGE_ENCODING,
+            })
+    }
+
+    pub fn read_msg_type<M>(fields: &M, msg_type: &str) -> Result<(), MessageFieldError>
+    where
+        M: FieldMap<u32>,
+    {
+        let value = required(fields.fv_raw(MSG_TYPE), MSG_TYPE)?;
+        if value == msg_type.as_bytes() {
+            Ok(())
+        } else {
+            Err(MessageFieldError::Invalid { tag: MSG_TYPE })
+        }
+    }
+
+    pub fn read_value<'a, M, T>(fields: &'a M, tag: u32) -> Result<Option<T>, MessageFieldError>
+    where
+        M: FieldMap<u32>,
+        T: FieldType<'a>,
+    {
+        fields
+            .fv_raw(tag)
+            .map(|bytes| T::deserialize(bytes).map_err(|_| MessageFieldError::Invalid { tag }))
+            .transpose()
+    }
+
+    pub fn read_text<M>(
+        fields: &M,
+        tag: u32,
+        encoding: MessageEncoding,
+    ) -> Result<Option<String>, MessageFieldError>
+    where
+        M: FieldMap<u32>,
+    {
+        fields
+            .fv_raw(tag)
+            .map(|bytes| {
+                encoding
+                    .decode(bytes)
+                    .map(|text| text.into_owned())
+                    .map_err(|_| MessageFieldError::Invalid { tag })
+            })
+            .transpose()
+    }
+
+    pub fn read_data<M>(fields: &M, tag: u32) -> Option<Vec<u8>>
+    where
+        M: FieldMap<u32>,
+    {
+        fields.fv_raw(tag).map(|bytes| bytes.to_vec())
+    }
+
+    /// Missing repeating groups are read as empty.
+    pub fn read_group<M, T, F>(fields: &M, tag: u32, read: F) -> Result<Vec<T>, MessageFieldError>
+    where
+        M: FieldMap<u32>,
+        F: Fn(&M) -> Result<T, MessageFieldError>,
+    {
+        let group = match fields.group_opt(tag) {
+            Ok(Some(group)) => group,
+            Ok(None) => return Ok(Vec::new()),
+            Err(_) => return Err(MessageFieldError::Invalid { tag }),
+        };
+        (0..group.len())
+            .map(|i| {
+                group
+                    .get(i)
+                    .ok_or(MessageFieldError::Invalid { tag })
+                    .and_then(|entry| read(&entry))
+            })
+            .collect()
+    }
+
+    pub fn write_msg_type(tree: &mut MessageTree, msg_type: &str) {
+        write_data(tree, MSG_TYPE, Some(msg_type.as_bytes()));
+    }
+
+    pub fn write_value<T>(tree: &mut MessageTree, tag: u32, value: Option<&T>)
+    where
+        T: for<'a> FieldType<'a>,
+    {
+        if let Some(value) = value {
+            write_data(tree, tag, Some(&value.to_bytes()[..]));
+        }
+    }
+
+    pub fn write_text(
+        tree: &mut MessageTree,
+        tag: u32,
+        text: Option<&str>,
+        encoding: MessageEncoding,
+    ) -> Result<(), MessageFieldError> {
+        if let Some(text) = text {
+            let bytes = encoding
+                .encode(text)
+                .map_err(|_| MessageFieldError::Invalid { tag })?;
+            write_data(tree, tag, Some(&bytes[..]));
+        }
+        Ok(())
+    }
+
+    pub fn write_data(tree: &mut MessageTree, tag: u32, data: Option<&[u8]>) {
+        if let Some(data) = data {
+            tree.set(tag, data);
+        }
+    }
+
+    /// Empty repeating groups are omitted altogether.
+    pub fn write_group<T, F>(
+        tree: &mut MessageTree,
+        tag: u32,
+        entries: &[T],
+        write: F,
+    ) -> Result<(), MessageFieldError>
+    where
+        F: Fn(&T, &mut MessageTree) -> Result<(), MessageFieldError>,
+    {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let num_in_group_tag = TagU32::new(tag).ok_or(MessageFieldError::Invalid { tag })?;
+        let entries = entries
+            .iter()
+            .map(|entry| {
+                let mut entry_tree = MessageTree::new();
+                write(entry, &mut entry_tree)?;
+                Ok(entry_tree)
+            })
+            .collect::<Result<Vec<_>, MessageFieldError>>()?;
+        tree.set_group(num_in_group_tag, entries);
+        Ok(())
+    }
+}
+
 #[cfg(feature = "fix40")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "fix40")))]
 #[allow(dead_code, unused, warnings)]
@@ -43,6 +247,11 @@ impl dict::IsFieldDefinition for HardCodedFixFieldDefinition {
 /// Field and message definitions for FIX.4.0.
 pub mod fix40 {
     include!(concat!(env!("OUT_DIR"), "/fix40.rs"));
+
+    /// Typed readers and builders for FIX.4.0 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix40_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix41")]
@@ -52,6 +261,11 @@ pub mod fix40 {
 /// Field and message definitions for FIX.4.1.
 pub mod fix41 {
     include!(concat!(env!("OUT_DIR"), "/fix41.rs"));
+
+    /// Typed readers and builders for FIX.4.1 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix41_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix42")]
@@ -61,6 +275,11 @@ pub mod fix41 {
 /// Field and message definitions for FIX.4.2.
 pub mod fix42 {
     include!(concat!(env!("OUT_DIR"), "/fix42.rs"));
+
+    /// Typed readers and builders for FIX.4.2 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix42_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix43")]
@@ -70,6 +289,11 @@ pub mod fix42 {
 /// Field and message definitions for FIX.4.3.
 pub mod fix43 {
     include!(concat!(env!("OUT_DIR"), "/fix43.rs"));
+
+    /// Typed readers and builders for FIX.4.3 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix43_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix44")]
@@ -79,6 +303,11 @@ pub mod fix43 {
 /// Field and message definitions for FIX.4.4.
 pub mod fix44 {
     include!(concat!(env!("OUT_DIR"), "/fix44.rs"));
+
+    /// Typed readers and builders for FIX.4.4 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix44_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix50")]
@@ -88,6 +317,11 @@ pub mod fix44 {
 /// Field and message definitions for FIX.5.0.
 pub mod fix50 {
     include!(concat!(env!("OUT_DIR"), "/fix50.rs"));
+
+    /// Typed readers and builders for FIX.5.0 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix50_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix50sp1")]
@@ -97,6 +331,11 @@ pub mod fix50 {
 /// Field and message definitions for FIX.5.0 SP1.
 pub mod fix50sp1 {
     include!(concat!(env!("OUT_DIR"), "/fix50sp1.rs"));
+
+    /// Typed readers and builders for FIX.5.0 SP1 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix50sp1_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fix50sp2")]
@@ -106,6 +345,11 @@ pub mod fix50sp1 {
 /// Field and message definitions for FIX.5.0 SP2.
 pub mod fix50sp2 {
     include!(concat!(env!("OUT_DIR"), "/fix50sp2.rs"));
+
+    /// Typed readers and builders for FIX.5.0 SP2 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fix50sp2_messages.rs"));
+    }
 }
 
 #[cfg(feature = "fixt11")]
@@ -115,4 +359,9 @@ pub mod fix50sp2 {
 /// Field and message definitions for FIXT.1.1.
 pub mod fixt11 {
     include!(concat!(env!("OUT_DIR"), "/fixt11.rs"));
+
+    /// Typed readers and builders for FIXT.1.1 messages and components.
+    pub mod messages {
+        include!(concat!(env!("OUT_DIR"), "/fixt11_messages.rs"));
+    }
 }
@@ -2,11 +2,15 @@
 
 use super::{dict, TagU32};
 use fnv::FnvHashSet;
-use heck::{ToPascalCase, ToShoutySnakeCase};
+use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
 use indoc::indoc;
 use std::marker::PhantomData;
 
 const FEFIX_VERSION: &str = env!("CARGO_PKG_VERSION");
+const BODY_LENGTH: u32 = 9;
+const CHECK_SUM: u32 = 10;
+const MSG_TYPE: u32 = 35;
+const SERDE_DERIVES: &[&str] = &["serde::Serialize", "serde::Deserialize"];
 
 /// Creates a [`String`] that contains a multiline Rust "Doc" comment explaining
 /// that all subsequent code was automatically generated.
@@ -38,7 +42,15 @@ pub fn generated_code_notice() -> String {
 /// Generates the Rust code for an `enum` that has variants that map 1:1 the
 /// available values for `field`.
 pub fn codegen_field_type_enum(field: dict::Field, settings: &Settings) -> String {
-    let derives = settings.derives_for_allowed_values.join(", ");
+    let mut derives = settings.derives_for_allowed_values.clone();
+    if settings.owned_messages {
+        for derive in SERDE_DERIVES {
+            if !derives.iter().any(|d| d == derive) {
+                derives.push(derive.to_string());
+            }
+        }
+    }
+    let derives = derives.join(", ");
     let attributes = settings.attributes_for_allowed_values.join("\n");
     let variants = field
         .enums()
@@ -132,6 +144,20 @@ pub struct Settings {
     ///
     /// Empty by default.
     pub attributes_for_allowed_values: Vec<String>,
+    /// Whether [`gen_messages`] should also generate an owned struct for each
+    /// message and component, with lossless conversions to and from
+    /// `tagvalue::MessageTree`. Generated `enum`s then derive
+    /// `serde::Serialize` and `serde::Deserialize` too, so the `serde` crate
+    /// (with the `derive` feature) must be a dependency.
+    ///
+    /// `false` by default.
+    pub owned_messages: bool,
+    /// A list of derive macros on top of all owned message structs (see
+    /// [`Settings::owned_messages`]).
+    ///
+    /// Contains [`Debug`], [`Clone`], [`PartialEq`], `serde::Serialize`,
+    /// `serde::Deserialize` by default.
+    pub derives_for_owned_messages: Vec<String>,
 }
 
 impl Default for Settings {
@@ -149,6 +175,14 @@ impl Default for Settings {
                 "FieldType".to_string(),
             ],
             attributes_for_allowed_values: vec![],
+            owned_messages: false,
+            derives_for_owned_messages: vec![
+                "Debug".to_string(),
+                "Clone".to_string(),
+                "PartialEq".to_string(),
+                "serde::Serialize".to_string(),
+                "serde::Deserialize".to_string(),
+            ],
             fefix_crate_name: "fefix".to_string(),
             phantom: PhantomData::default(),
         }
@@ -221,6 +255,7 @@ pub fn gen_definitions(fix_dictionary: dict::Dictionary, settings: &Settings) ->
             use {fefix_path}::dict::FixDatatype;
             use {fefix_path}::definitions::HardCodedFixFieldDefinition;
             use {fefix_path}::FieldType;
+            use std::marker::PhantomData;
 
             {enum_definitions}
 
@@ -235,6 +270,868 @@ pub fn gen_definitions(fix_dictionary: dict::Dictionary, settings: &Settings) ->
     code
 }
 
+/// Generates typed readers and builders for all messages and components of
+/// `fix_dictionary`, on top of the definitions generated by [`gen_definitions`].
+///
+/// The generated code is meant to be placed in a child module of the
+/// definitions (e.g. `fix44::messages`), and it will contain:
+///
+/// - A generated code notice ([generated_code_notice]).
+/// - A zero-copy `{Name}Reader` for each message and component, with a typed
+///   getter for each one of its fields, components, and repeating groups.
+///   Getters of optional fields and groups return [`Option`]s. Repeating
+///   groups are read as [`Iterator`]s over the readers of their entries.
+/// - A `{Name}Builder` for each message and component, with a typed setter for
+///   each one of its fields. Repeating groups are written by
+///   `{Name}GroupBuilder`s, which start entries and then close the group.
+/// - If [`Settings::owned_messages`] is enabled, an owned `{Name}` struct for
+///   each message and component, with [`String`]s for text fields and
+///   [`Vec`]s for repeating groups. Owned structs are read from any
+///   [`FieldMap`](crate::FieldMap) with `u32` tags and written to
+///   `tagvalue::MessageTree`s; `BodyLength <9>`, `MsgType <35>` and
+///   `CheckSum <10>` are implied.
+///
+/// Readers wrap any [`FieldMap`](crate::FieldMap) with `u32` tags, e.g.
+/// `tagvalue::Message`, and builders wrap any `tagvalue::StartGroup`
+/// implementor, e.g. `tagvalue::EncoderHandle`.
+pub fn gen_messages(fix_dictionary: dict::Dictionary, settings: &Settings) -> String {
+    let has_header = fix_dictionary.component_by_name("StandardHeader").is_some();
+    let has_trailer = fix_dictionary
+        .component_by_name("StandardTrailer")
+        .is_some();
+    let mut containers = Vec::new();
+    for message in fix_dictionary.iter_messages() {
+        let doc = format!(
+            "messages of type `{} <{}>`",
+            message.name(),
+            message.msg_type()
+        );
+        let info = MessageInfo {
+            msg_type: message.msg_type(),
+            has_header,
+            has_trailer,
+        };
+        gen_container(
+            &mut containers,
+            &message.name().to_pascal_case(),
+            &doc,
+            message.layout(),
+            Some(&info),
+            settings,
+        );
+    }
+    for component in fix_dictionary.iter_components() {
+        let doc = format!("the component `{}`", component.name());
+        gen_container(
+            &mut containers,
+            &component.name().to_pascal_case(),
+            &doc,
+            component.items(),
+            None,
+            settings,
+        );
+    }
+    let owned_prelude = if settings.owned_messages {
+        gen_owned_prelude(settings)
+    } else {
+        String::new()
+    };
+    format!(
+        indoc!(
+            r#"
+            {notice}
+
+            use super::*;
+            use {fefix_path}::tagvalue::{{Configure, EncodeError, EncoderGroup, StartGroup}};
+            use {fefix_path}::{{Buffer, FieldMap, FieldType, FieldValueError, RepeatingGroup, SetField}};
+            {owned_prelude}
+            {containers}"#
+        ),
+        notice = generated_code_notice(),
+        fefix_path = settings.fefix_crate_name,
+        owned_prelude = owned_prelude,
+        containers = containers.join("\n\n"),
+    )
+}
+
+/// The details of a message, as opposed to a component or a repeating group
+/// entry, for [`gen_container`].
+struct MessageInfo<'a> {
+    msg_type: &'a str,
+    has_header: bool,
+    has_trailer: bool,
+}
+
+/// The pieces of code that make up an owned struct, one per field, component
+/// or repeating group.
+#[derive(Default)]
+struct OwnedItems {
+    declarations: Vec<String>,
+    reads: Vec<String>,
+    writes: Vec<String>,
+    presence_checks: Vec<String>,
+}
+
+/// Generates a reader and a builder for a sequence of layout items (i.e. a
+/// message, a component, or the entries of a repeating group), plus those of
+/// all repeating groups within it.
+fn gen_container<'a>(
+    output: &mut Vec<String>,
+    name: &str,
+    doc: &str,
+    items: impl Iterator<Item = dict::LayoutItem<'a>>,
+    message: Option<&MessageInfo>,
+    settings: &Settings,
+) {
+    let mut getters = Vec::new();
+    let mut setters = Vec::new();
+    let mut owned = OwnedItems::default();
+    let mut method_names = FnvHashSet::default();
+    if message.map_or(false, |info| info.has_header) {
+        method_names.insert("header".to_string());
+        getters.push(
+            indoc!(
+                r#"
+            /// Returns a reader for the `StandardHeader` of `self`.
+            pub fn header(&self) -> StandardHeaderReader<M>
+            where
+                M: Clone,
+            {
+                StandardHeaderReader::new(self.message.clone())
+            }"#
+            )
+            .to_string(),
+        );
+        setters.push(
+            indoc!(
+                r#"
+            /// Returns a builder for the `StandardHeader` of `self`.
+            pub fn header(&mut self) -> StandardHeaderBuilder<'_, S> {
+                StandardHeaderBuilder::new(self.fields)
+            }"#
+            )
+            .to_string(),
+        );
+        owned.add_component("StandardHeader", "header", true);
+    }
+    // Session-level fields which are implied by owned structs.
+    let implied_tags: &[u32] = match name {
+        "StandardHeader" => &[BODY_LENGTH, MSG_TYPE],
+        "StandardTrailer" => &[CHECK_SUM],
+        _ => &[],
+    };
+    // Readers and builders of repeating groups within `self`.
+    let mut nested = Vec::new();
+    for item in items {
+        let required = item.required();
+        match item.kind() {
+            dict::LayoutItemKind::Field(field) => {
+                let method = field.name().to_snake_case();
+                if !method_names.insert(method.clone()) {
+                    continue;
+                }
+                let (getter, setter) = gen_field_accessors(field, &method, required, settings);
+                getters.push(getter);
+                setters.push(setter);
+                if !implied_tags.contains(&field.tag().get()) {
+                    owned.add_field(field, &method, required, settings);
+                }
+            }
+            dict::LayoutItemKind::Component(component) => {
+                let method = component.name().to_snake_case();
+                if !method_names.insert(method.clone()) {
+                    continue;
+                }
+                let component_name = component.name().to_pascal_case();
+                getters.push(format!(
+                    indoc!(
+                        r#"
+                        /// Returns a reader for the component `{name}` within `self`.
+                        pub fn {method}(&self) -> {component_name}Reader<M>
+                        where
+                            M: Clone,
+                        {{
+                            {component_name}Reader::new(self.message.clone())
+                        }}"#
+                    ),
+                    name = component.name(),
+                    method = rust_identifier(&method),
+                    component_name = component_name,
+                ));
+                setters.push(format!(
+                    indoc!(
+                        r#"
+                        /// Returns a builder for the component `{name}` within `self`.
+                        pub fn {method}(&mut self) -> {component_name}Builder<'_, S> {{
+                            {component_name}Builder::new(self.fields)
+                        }}"#
+                    ),
+                    name = component.name(),
+                    method = rust_identifier(&method),
+                    component_name = component_name,
+                ));
+                owned.add_component(component.name(), &method, required);
+            }
+            dict::LayoutItemKind::Group(field, group_items) => {
+                let method = field.name().to_snake_case();
+                if !method_names.insert(method.clone()) {
+                    continue;
+                }
+                let entry_name = format!("{}{}", name, field.name().to_pascal_case());
+                let (getter, setter) = gen_group_accessors(field, &method, &entry_name, required);
+                getters.push(getter);
+                setters.push(setter);
+                owned.add_group(field, &method, &entry_name);
+                let group_doc = format!(
+                    "the entries of the repeating group `{} <{}>` within {}",
+                    field.name(),
+                    field.tag(),
+                    doc
+                );
+                nested.push(gen_group_builder(&entry_name, &group_doc));
+                gen_container(
+                    &mut nested,
+                    &entry_name,
+                    &group_doc,
+                    group_items.into_iter(),
+                    None,
+                    settings,
+                );
+            }
+        }
+    }
+    if message.map_or(false, |info| info.has_trailer) && method_names.insert("trailer".to_string())
+    {
+        owned.add_component("StandardTrailer", "trailer", true);
+    }
+    let indentation = settings.indentation.as_str();
+    output.push(format!(
+        indoc!(
+            r#"
+            /// A typed, zero-copy reader for {doc}.
+            #[derive(Debug, Clone)]
+            pub struct {name}Reader<M> {{
+                message: M,
+            }}
+
+            impl<M> {name}Reader<M>
+            where
+                M: FieldMap<u32>,
+            {{
+                /// Wraps `message` into a [`{name}Reader`].
+                pub fn new(message: M) -> Self {{
+                    Self {{ message }}
+                }}
+
+                /// Returns the underlying [`FieldMap`] of `self`.
+                pub fn into_inner(self) -> M {{
+                    self.message
+                }}
+
+            {getters}
+            }}
+
+            /// A typed builder for {doc}.
+            #[derive(Debug)]
+            pub struct {name}Builder<'e, S> {{
+                fields: &'e mut S,
+            }}
+
+            impl<'e, S> {name}Builder<'e, S>
+            where
+                S: SetField<u32>,
+            {{
+                /// Wraps `fields` into a [`{name}Builder`].
+                pub fn new(fields: &'e mut S) -> Self {{
+                    Self {{ fields }}
+                }}
+
+            {setters}
+            }}"#
+        ),
+        doc = doc,
+        name = name,
+        getters = indent_methods(&getters, indentation),
+        setters = indent_methods(&setters, indentation),
+    ));
+    if settings.owned_messages {
+        output.push(gen_owned_struct(name, doc, message, &owned, settings));
+    }
+    output.append(&mut nested);
+}
+
+/// Generates the builder of a repeating group, whose entries are written by
+/// `{entry_name}Builder`.
+fn gen_group_builder(entry_name: &str, doc: &str) -> String {
+    format!(
+        indoc!(
+            r#"
+            /// A typed builder for {doc}, which starts new entries and then
+            /// closes the repeating group.
+            #[derive(Debug)]
+            pub struct {name}GroupBuilder<'g, 'a, B, C>
+            where
+                B: Buffer,
+                C: Configure,
+            {{
+                group: EncoderGroup<'g, 'a, B, C>,
+            }}
+
+            impl<'g, 'a, B, C> {name}GroupBuilder<'g, 'a, B, C>
+            where
+                B: Buffer,
+                C: Configure,
+            {{
+                /// Starts a new entry and returns a builder for it.
+                pub fn entry(&mut self) -> {name}Builder<'_, EncoderGroup<'g, 'a, B, C>> {{
+                    {name}Builder::new(self.group.entry())
+                }}
+
+                /// Closes the repeating group. See [`EncoderGroup::done`].
+                pub fn done(self) -> Result<(), EncodeError> {{
+                    self.group.done()
+                }}
+            }}"#
+        ),
+        doc = doc,
+        name = entry_name,
+    )
+}
+
+fn gen_field_accessors(
+    field: dict::Field,
+    method: &str,
+    required: bool,
+    settings: &Settings,
+) -> (String, String) {
+    let value_type = field_value_type(field, settings);
+    let (return_type, fv) = if required {
+        (
+            format!(
+                "Result<{value_type}, FieldValueError<<{value_type} as FieldType<'_>>::Error>>",
+                value_type = value_type
+            ),
+            "fv",
+        )
+    } else {
+        (
+            format!(
+                "Result<Option<{value_type}>, <{value_type} as FieldType<'_>>::Error>",
+                value_type = value_type
+            ),
+            "fv_opt",
+        )
+    };
+    let getter = format!(
+        indoc!(
+            r#"
+            /// Returns the value of the {required} field `{name} <{tag}>`.
+            pub fn {method}(&self) -> {return_type} {{
+                self.message.{fv}({constant}.tag)
+            }}"#
+        ),
+        required = if required { "required" } else { "optional" },
+        name = field.name(),
+        tag = field.tag(),
+        method = rust_identifier(method),
+        return_type = return_type,
+        fv = fv,
+        constant = field.name().to_shouty_snake_case(),
+    );
+    let setter = format!(
+        indoc!(
+            r#"
+            /// Sets the value of the field `{name} <{tag}>`.
+            pub fn set_{method}(&mut self, value: {value_type}) -> &mut Self {{
+                self.fields.set({constant}.tag, value);
+                self
+            }}"#
+        ),
+        name = field.name(),
+        tag = field.tag(),
+        method = method,
+        value_type = value_type,
+        constant = field.name().to_shouty_snake_case(),
+    );
+    (getter, setter)
+}
+
+fn gen_group_accessors(
+    field: dict::Field,
+    method: &str,
+    entry_name: &str,
+    required: bool,
+) -> (String, String) {
+    let entries = format!("impl Iterator<Item = {}Reader<M>>", entry_name);
+    let (return_type, body) = if required {
+        (
+            format!(
+                "Result<{}, FieldValueError<<usize as FieldType<'static>>::Error>>",
+                entries
+            ),
+            format!(
+                "let group = self.message.group({}.tag)?;\n    Ok({})",
+                field.name().to_shouty_snake_case(),
+                group_entries_expr(entry_name)
+            ),
+        )
+    } else {
+        (
+            format!(
+                "Result<Option<{}>, <usize as FieldType<'static>>::Error>",
+                entries
+            ),
+            format!(
+                "let group = self.message.group_opt({}.tag)?;\n    Ok(group.map(|group| {}))",
+                field.name().to_shouty_snake_case(),
+                group_entries_expr(entry_name)
+            ),
+        )
+    };
+    let getter = format!(
+        indoc!(
+            r#"
+            /// Returns the entries of the {required} repeating group `{name} <{tag}>`.
+            pub fn {method}(&self) -> {return_type} {{
+                {body}
+            }}"#
+        ),
+        required = if required { "required" } else { "optional" },
+        name = field.name(),
+        tag = field.tag(),
+        method = rust_identifier(method),
+        return_type = return_type,
+        body = body,
+    );
+    let setter = format!(
+        indoc!(
+            r#"
+            /// Starts the repeating group `{name} <{tag}>`.
+            pub fn start_{method}<'a, B, C>(&mut self) -> {entry_name}GroupBuilder<'_, 'a, B, C>
+            where
+                S: StartGroup<'a, B, C>,
+                B: Buffer,
+                C: Configure,
+            {{
+                {entry_name}GroupBuilder {{
+                    group: self.fields.start_group({constant}),
+                }}
+            }}"#
+        ),
+        name = field.name(),
+        tag = field.tag(),
+        method = method,
+        entry_name = entry_name,
+        constant = field.name().to_shouty_snake_case(),
+    );
+    (getter, setter)
+}
+
+fn group_entries_expr(entry_name: &str) -> String {
+    format!(
+        "(0..group.len()).filter_map(move |i| group.get(i)).map({}Reader::new)",
+        entry_name
+    )
+}
+
+fn gen_owned_prelude(settings: &Settings) -> String {
+    format!(
+        indoc!(
+            r#"
+            use {fefix_path}::definitions::{{owned, MessageFieldError}};
+            use {fefix_path}::field_types::MessageEncoding;
+            use {fefix_path}::tagvalue::MessageTree;
+            "#
+        ),
+        fefix_path = settings.fefix_crate_name,
+    )
+}
+
+/// How the values of a field are stored within owned structs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum OwnedValue {
+    /// A [`FieldType`](crate::FieldType) implementor.
+    FieldType,
+    /// A [`String`], encoded according to `MessageEncoding <347>`.
+    Text,
+    /// Raw bytes.
+    Data,
+}
+
+impl OwnedItems {
+    fn add_field(&mut self, field: dict::Field, method: &str, required: bool, settings: &Settings) {
+        let (value_type, owned_value) = owned_value_type(field, settings);
+        let identifier = rust_identifier(method);
+        let constant = field.name().to_shouty_snake_case();
+        let read = match owned_value {
+            OwnedValue::FieldType => format!("owned::read_value(fields, {}.tag)?", constant),
+            OwnedValue::Text => format!("owned::read_text(fields, {}.tag, encoding)?", constant),
+            OwnedValue::Data => format!("owned::read_data(fields, {}.tag)", constant),
+        };
+        let value = match (owned_value, required) {
+            (OwnedValue::FieldType, true) => format!("Some(&self.{})", identifier),
+            (OwnedValue::FieldType, false) => format!("self.{}.as_ref()", identifier),
+            (OwnedValue::Text, true) => format!("Some(self.{}.as_str())", identifier),
+            (OwnedValue::Data, true) => format!("Some(&self.{}[..])", identifier),
+            (_, false) => format!("self.{}.as_deref()", identifier),
+        };
+        self.declarations.push(format!(
+            "/// The {required} field `{name} <{tag}>`.\npub {identifier}: {value_type},",
+            required = if required { "required" } else { "optional" },
+            name = field.name(),
+            tag = field.tag(),
+            identifier = identifier,
+            value_type = if required {
+                value_type
+            } else {
+                format!("Option<{}>", value_type)
+            },
+        ));
+        self.reads.push(if required {
+            format!(
+                "{}: owned::required({}, {}.tag)?,",
+                identifier, read, constant
+            )
+        } else {
+            format!("{}: {},", identifier, read)
+        });
+        self.writes.push(match owned_value {
+            OwnedValue::FieldType => {
+                format!("owned::write_value(tree, {}.tag, {});", constant, value)
+            }
+            OwnedValue::Text => format!(
+                "owned::write_text(tree, {}.tag, {}, encoding)?;",
+                constant, value
+            ),
+            OwnedValue::Data => format!("owned::write_data(tree, {}.tag, {});", constant, value),
+        });
+        self.presence_checks
+            .push(format!("fields.fv_raw({}.tag).is_some()", constant));
+    }
+
+    fn add_component(&mut self, name: &str, method: &str, required: bool) {
+        let identifier = rust_identifier(method);
+        let struct_name = name.to_pascal_case();
+        self.declarations.push(format!(
+            "/// The {required} component `{name}`.\npub {identifier}: {value_type},",
+            required = if required { "required" } else { "optional" },
+            name = name,
+            identifier = identifier,
+            value_type = if required {
+                struct_name.clone()
+            } else {
+                format!("Option<{}>", struct_name)
+            },
+        ));
+        if required {
+            self.reads.push(format!(
+                "{}: {}::read(fields, encoding)?,",
+                identifier, struct_name
+            ));
+            self.writes
+                .push(format!("self.{}.write(tree, encoding)?;", identifier));
+        } else {
+            self.reads.push(format!(
+                "{identifier}: if {struct_name}::is_present(fields) {{\n    Some({struct_name}::read(fields, encoding)?)\n}} else {{\n    None\n}},",
+                identifier = identifier,
+                struct_name = struct_name,
+            ));
+            self.writes.push(format!(
+                "if let Some(component) = &self.{} {{\n    component.write(tree, encoding)?;\n}}",
+                identifier
+            ));
+        }
+        self.presence_checks
+            .push(format!("{}::is_present(fields)", struct_name));
+    }
+
+    fn add_group(&mut self, field: dict::Field, method: &str, entry_name: &str) {
+        let identifier = rust_identifier(method);
+        let constant = field.name().to_shouty_snake_case();
+        self.declarations.push(format!(
+            "/// The entries of the repeating group `{name} <{tag}>`.\npub {identifier}: Vec<{entry_name}>,",
+            name = field.name(),
+            tag = field.tag(),
+            identifier = identifier,
+            entry_name = entry_name,
+        ));
+        self.reads.push(format!(
+            "{identifier}: owned::read_group(fields, {constant}.tag, |entry| {{\n    {entry_name}::read(entry, encoding)\n}})?,",
+            identifier = identifier,
+            constant = constant,
+            entry_name = entry_name,
+        ));
+        self.writes.push(format!(
+            "owned::write_group(tree, {constant}.tag, &self.{identifier}, |entry, tree| {{\n    entry.write(tree, encoding)\n}})?;",
+            constant = constant,
+            identifier = identifier,
+        ));
+        self.presence_checks.push(format!(
+            "matches!(fields.group_opt({}.tag), Ok(Some(_)))",
+            constant
+        ));
+    }
+}
+
+/// Generates an owned struct for a message, a component, or the entries of a
+/// repeating group, with conversions from [`FieldMap`](crate::FieldMap)s and
+/// to `tagvalue::MessageTree`s.
+fn gen_owned_struct(
+    name: &str,
+    doc: &str,
+    message: Option<&MessageInfo>,
+    owned: &OwnedItems,
+    settings: &Settings,
+) -> String {
+    let indentation = settings.indentation.as_str();
+    let mut methods = Vec::new();
+    let mut reads = owned.reads.clone();
+    let mut writes = owned.writes.clone();
+    if let Some(info) = message {
+        methods.push(format!(
+            indoc!(
+                r#"
+                /// The `MsgType <35>` of [`{name}`].
+                pub const MSG_TYPE: &'static str = "{msg_type}";"#
+            ),
+            name = name,
+            msg_type = info.msg_type,
+        ));
+        methods.push(format!(
+            indoc!(
+                r#"
+                /// Reads a [`{name}`] from `message`, decoding text according to
+                /// its `MessageEncoding <347>`.
+                pub fn from_field_map<M>(message: &M) -> Result<Self, MessageFieldError>
+                where
+                    M: FieldMap<u32>,
+                {{
+                    let encoding = owned::message_encoding(message)?;
+                    Self::read(message, encoding)
+                }}"#
+            ),
+            name = name,
+        ));
+        // Header fields are written as UTF-8 until `MessageEncoding <347>` is
+        // known.
+        let header_pass = if info.has_header {
+            "self.header.write(&mut tree, MessageEncoding::Utf8)?;\n    "
+        } else {
+            ""
+        };
+        methods.push(format!(
+            indoc!(
+                r#"
+                /// Converts `self` into a [`MessageTree`], encoding text according
+                /// to `MessageEncoding <347>`. See [`MessageTree::encode`].
+                pub fn to_message_tree(&self) -> Result<MessageTree, MessageFieldError> {{
+                    let mut tree = MessageTree::new();
+                    {header_pass}let encoding = owned::message_encoding(&tree)?;
+                    self.write(&mut tree, encoding)?;
+                    Ok(tree)
+                }}"#
+            ),
+            header_pass = header_pass,
+        ));
+        reads.insert(
+            0,
+            "owned::read_msg_type(fields, Self::MSG_TYPE)?;".to_string(),
+        );
+        let i = if info.has_header { 1 } else { 0 };
+        writes.insert(
+            i,
+            "owned::write_msg_type(tree, Self::MSG_TYPE);".to_string(),
+        );
+    }
+    // `read` starts with a `MsgType <35>` check for messages, so the
+    // struct literal only contains field initializers otherwise.
+    let (checks, initializers) = if message.is_some() {
+        (format!("{}\n    ", reads[0]), &reads[1..])
+    } else {
+        (String::new(), &reads[..])
+    };
+    let uses_encoding = initializers.iter().any(|read| read.contains(", encoding)"))
+        || writes.iter().any(|write| write.contains(", encoding)"));
+    let encoding_param = if uses_encoding {
+        "encoding"
+    } else {
+        "_encoding"
+    };
+    let fields_param = if checks.is_empty() && initializers.is_empty() {
+        "_fields"
+    } else {
+        "fields"
+    };
+    let tree_param = if writes.is_empty() { "_tree" } else { "tree" };
+    methods.push(format!(
+        indoc!(
+            r#"
+            /// Reads a [`{name}`] from `fields`, decoding text according to
+            /// `encoding`.
+            pub fn read<M>({fields_param}: &M, {encoding_param}: MessageEncoding) -> Result<Self, MessageFieldError>
+            where
+                M: FieldMap<u32>,
+            {{
+                {checks}Ok(Self {{
+            {initializers}
+                }})
+            }}"#
+        ),
+        name = name,
+        fields_param = fields_param,
+        encoding_param = encoding_param,
+        checks = checks,
+        initializers = indent_methods(initializers, &indentation.repeat(2)),
+    ));
+    methods.push(format!(
+        indoc!(
+            r#"
+            /// Writes all fields of `self` to `tree`, encoding text according to
+            /// `encoding`.
+            pub fn write(&self, {tree_param}: &mut MessageTree, {encoding_param}: MessageEncoding) -> Result<(), MessageFieldError> {{
+            {writes}
+                Ok(())
+            }}"#
+        ),
+        tree_param = tree_param,
+        encoding_param = encoding_param,
+        writes = indent_methods(&writes, indentation),
+    ));
+    if message.is_none() {
+        let presence_checks = if owned.presence_checks.is_empty() {
+            "false".to_string()
+        } else {
+            owned.presence_checks.join("\n    || ")
+        };
+        methods.push(format!(
+            indoc!(
+                r#"
+                /// Returns `true` if any field of [`{name}`] is within `fields`,
+                /// `false` otherwise.
+                pub fn is_present<M>({fields_param}: &M) -> bool
+                where
+                    M: FieldMap<u32>,
+                {{
+                    {presence_checks}
+                }}"#
+            ),
+            name = name,
+            fields_param = if owned.presence_checks.is_empty() {
+                "_fields"
+            } else {
+                "fields"
+            },
+            presence_checks = presence_checks,
+        ));
+    }
+    format!(
+        indoc!(
+            r#"
+            /// An owned representation of {doc}.
+            #[derive({derives})]
+            pub struct {name} {{
+            {declarations}
+            }}
+
+            impl {name} {{
+            {methods}
+            }}"#
+        ),
+        doc = doc,
+        derives = settings.derives_for_owned_messages.join(", "),
+        name = name,
+        declarations = indent_methods(&owned.declarations, indentation),
+        methods = indent_methods(&methods, indentation),
+    )
+}
+
+/// Returns the Rust type of the values of `field` within owned structs.
+fn owned_value_type(field: dict::Field, settings: &Settings) -> (String, OwnedValue) {
+    use dict::FixDatatype;
+
+    match (
+        field_value_type(field, settings).as_str(),
+        field.fix_datatype(),
+    ) {
+        ("&[u8]", FixDatatype::Data) => ("Vec<u8>".to_string(), OwnedValue::Data),
+        ("&[u8]", _) => ("String".to_string(), OwnedValue::Text),
+        (value_type, _) => (value_type.to_string(), OwnedValue::FieldType),
+    }
+}
+
+/// Returns the Rust type of the values of `field`, as used by generated
+/// readers and builders.
+fn field_value_type(field: dict::Field, settings: &Settings) -> String {
+    use dict::FixDatatype;
+
+    // Generated `enum`s are referred to by path, as their names might clash
+    // with those of generated structs.
+    if let Some(value_enum) = field_value_enum(field) {
+        return format!("super::{}", value_enum);
+    }
+    let fefix_path = settings.fefix_crate_name.as_str();
+    match field.fix_datatype() {
+        FixDatatype::Int => "i64".to_string(),
+        FixDatatype::Length | FixDatatype::NumInGroup => "usize".to_string(),
+        FixDatatype::SeqNum => "u64".to_string(),
+        FixDatatype::DayOfMonth => "u32".to_string(),
+        FixDatatype::TagNum => format!("{}::TagU32", fefix_path),
+        FixDatatype::Float
+        | FixDatatype::Amt
+        | FixDatatype::Price
+        | FixDatatype::PriceOffset
+        | FixDatatype::Qty
+        | FixDatatype::Percentage => "f64".to_string(),
+        FixDatatype::Boolean => "bool".to_string(),
+        FixDatatype::MonthYear => format!("{}::field_types::MonthYear", fefix_path),
+        FixDatatype::UtcDateOnly | FixDatatype::LocalMktDate => {
+            format!("{}::field_types::Date", fefix_path)
+        }
+        FixDatatype::UtcTimeOnly => format!("{}::field_types::Time", fefix_path),
+        FixDatatype::UtcTimestamp => format!("{}::field_types::Timestamp", fefix_path),
+        // Everything else, e.g. `String` and `data`, is read as-is.
+        _ => "&[u8]".to_string(),
+    }
+}
+
+/// Turns `identifier` into a raw identifier if it's a Rust keyword.
+fn rust_identifier(identifier: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
+        "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+        "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+        "virtual", "where", "while", "yield",
+    ];
+    if KEYWORDS.contains(&identifier) {
+        format!("r#{}", identifier)
+    } else {
+        identifier.to_string()
+    }
+}
+
+fn indent_methods(methods: &[String], prefix: &str) -> String {
+    methods
+        .iter()
+        .map(|method| {
+            method
+                .lines()
+                .map(|line| format!("{}{}\n", prefix, line))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the name of the generated `enum` for the values of `field`, if any.
+fn field_value_enum(field: dict::Field) -> Option<String> {
+    // Multiple-value fields can't be deserialized into a single variant.
+    match field.fix_datatype() {
+        dict::FixDatatype::MultipleCharValue | dict::FixDatatype::MultipleStringValue => None,
+        _ => field.enums().map(|_| field.name().to_pascal_case()),
+    }
+}
+
 fn indent_string(s: &str, prefix: &str) -> String {
     s.lines().fold(String::new(), |mut s, line| {
         if line.contains(char::is_whitespace) {
@@ -291,6 +1188,7 @@ fn gen_field_definition_with_hashsets(
     } else {
         "Body"
     };
+    let value_type = field_value_enum(field);
     let doc_link = onixs_link_to_field(fix_dictionary.get_version(), field);
     let doc = if let Some(doc_link) = doc_link {
         format!(
@@ -305,15 +1203,17 @@ fn gen_field_definition_with_hashsets(
         indoc!(
             r#"
                 {doc}
-                pub const {identifier}: &HardCodedFixFieldDefinition = &HardCodedFixFieldDefinition {{
+                pub const {identifier}: &HardCodedFixFieldDefinition<{value_type}> = &HardCodedFixFieldDefinition {{
                     name: "{name}",
                     tag: {tag},
                     data_type: FixDatatype::{data_type},
                     location: FieldLocation::{field_location},
+                    value_type: PhantomData,
                 }};"#
         ),
         doc = doc,
         identifier = name,
+        value_type = value_type.as_deref().unwrap_or("()"),
         name = field.name(),
         tag = tag,
         field_location = field_location,
@@ -334,6 +1234,56 @@ mod test {
         }
     }
 
+    #[test]
+    fn syntax_of_messages_is_ok() {
+        let codegen_settings = Settings::default();
+        for dict in dict::Dictionary::all().into_iter() {
+            let code = gen_messages(dict, &codegen_settings);
+            syn::parse_file(code.as_str()).unwrap();
+        }
+    }
+
+    #[test]
+    fn syntax_of_owned_messages_is_ok() {
+        let codegen_settings = &mut Settings::default();
+        codegen_settings.owned_messages = true;
+        for dict in dict::Dictionary::all().into_iter() {
+            let code = gen_messages(dict, codegen_settings);
+            syn::parse_file(code.as_str()).unwrap();
+        }
+    }
+
+    #[test]
+    fn syntax_of_customized_dictionaries_is_ok() {
+        let dict = dict::Dictionary::fix44()
+            .with_overlay(
+                r#"<fix>
+                    <fields>
+                        <field number="5001" name="VenueAccount" type="STRING">
+                            <value enum="H" description="HOUSE"/>
+                        </field>
+                    </fields>
+                    <messages>
+                        <message name="VenueStatus" msgtype="U1">
+                            <field name="VenueAccount" required="Y"/>
+                            <group name="NoPartyIDs" required="N">
+                                <field name="PartyID" required="Y"/>
+                            </group>
+                        </message>
+                    </messages>
+                </fix>"#,
+            )
+            .unwrap();
+        let settings = &mut Settings::default();
+        let definitions = gen_definitions(dict.clone(), settings);
+        assert!(definitions.contains("VENUE_ACCOUNT"));
+        syn::parse_file(definitions.as_str()).unwrap();
+        settings.owned_messages = true;
+        let messages = gen_messages(dict, settings);
+        assert!(messages.contains("VenueStatus"));
+        syn::parse_file(messages.as_str()).unwrap();
+    }
+
     #[test]
     fn generated_code_notice_is_trimmed() {
         let notice = generated_code_notice();
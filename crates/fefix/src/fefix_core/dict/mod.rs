@@ -2,7 +2,10 @@
 
 #![allow(dead_code)]
 
+mod orchestra;
+mod overlay;
 mod quickfix;
+mod repository;
 
 use self::symbol_table::{Key, KeyRef, SymbolTable, SymbolTableIndex};
 use super::TagU32;
@@ -12,6 +15,7 @@ use std::fmt;
 use std::sync::Arc;
 
 pub use datatype::FixDatatype;
+pub use repository::RepositoryFiles;
 
 pub trait DataFieldLookup<F> {
     fn field_is_data(&self, field: F) -> bool;
@@ -55,6 +59,10 @@ pub enum FieldLocation {
 
 type InternalId = u32;
 
+/// The name of the default scenario of FIX Orchestra messages and
+/// components. Dictionaries read from any other source only have this one.
+pub const BASE_SCENARIO: &str = "base";
+
 /// A mapping from FIX version strings to [`Dictionary`] values.
 pub type Dictionaries = FnvHashMap<String, Dictionary>;
 
@@ -90,6 +98,8 @@ struct DictionaryData {
     messages: Vec<MessageData>,
     //layout_items: Vec<LayoutItemData>,
     categories: Vec<CategoryData>,
+    sections: Vec<SectionData>,
+    state_machines: Vec<StateMachine>,
     header: Vec<FieldData>,
 }
 
@@ -187,6 +197,8 @@ impl Dictionary {
                 messages: Vec::new(),
                 //layout_items: Vec::new(),
                 categories: Vec::new(),
+                sections: Vec::new(),
+                state_machines: Vec::new(),
                 header: Vec::new(),
             }),
         }
@@ -200,6 +212,56 @@ impl Dictionary {
         QuickFixReader::new(&xml_document)
     }
 
+    /// Attempts to read the "Basic" (a.k.a. "Base") files of an official FIX
+    /// Repository and convert them into a [`Dictionary`]. Unlike QuickFIX
+    /// specs, FIX Repository files also carry abbreviations, categories,
+    /// sections, descriptions, and versioning information (see [`Pedigree`]).
+    ///
+    /// See [`RepositoryFiles::read_dir`] for reading all files at once.
+    pub fn from_repository(files: &RepositoryFiles) -> Result<Self, ParseDictionaryError> {
+        repository::read_repository(files)
+    }
+
+    /// Attempts to read a FIX Orchestra repository (e.g. the rules of
+    /// engagement published by a venue) and convert it into a [`Dictionary`].
+    ///
+    /// Besides fields, code sets, components, repeating groups, and messages,
+    /// Orchestra files can define message scenarios (see
+    /// [`Dictionary::message_by_scenario`]), conditional rules (see
+    /// [`Message::conditional_rules`]), and workflows (see
+    /// [`Message::responses`] and [`Dictionary::iter_state_machines`]).
+    pub fn from_orchestra<S: AsRef<str>>(input: S) -> Result<Self, ParseDictionaryError> {
+        orchestra::read_orchestra(input.as_ref())
+    }
+
+    /// Returns a [`DictionaryBuilder`] that starts off with all definitions of
+    /// `self`, so that they can be extended or overridden.
+    pub fn to_builder(&self) -> DictionaryBuilder {
+        let data = self.inner.as_ref().clone();
+        DictionaryBuilder {
+            version: data.version,
+            symbol_table: data.symbol_table,
+            abbreviations: data.abbreviations,
+            data_types: data.data_types,
+            fields: data.fields,
+            components: data.components,
+            messages: data.messages,
+            categories: data.categories,
+            sections: data.sections,
+            state_machines: data.state_machines,
+            header: data.header,
+        }
+    }
+
+    /// Returns a copy of `self` with all definitions of the overlay XML
+    /// `input` applied on top. See [`DictionaryBuilder::apply_overlay`] for
+    /// the format of overlays.
+    pub fn with_overlay<S: AsRef<str>>(&self, input: S) -> Result<Self, ParseDictionaryError> {
+        let mut builder = self.to_builder();
+        builder.apply_overlay(input)?;
+        Ok(builder.build())
+    }
+
     /// Creates a new empty FIX Dictionary with `FIX.???` as its version string.
     pub fn empty() -> Self {
         Self::new("FIX.???")
@@ -357,6 +419,15 @@ impl Dictionary {
             .map(|data| Message(self, data))
     }
 
+    /// Returns the [`Message`] named `name` in the given FIX Orchestra
+    /// `scenario`, if any. [`Dictionary::message_by_name`] only returns
+    /// messages in their [`BASE_SCENARIO`].
+    pub fn message_by_scenario<S: AsRef<str>>(&self, name: S, scenario: S) -> Option<Message> {
+        self.symbol(KeyRef::MessageByScenario(name.as_ref(), scenario.as_ref()))
+            .and_then(|iid| self.inner.messages.get(*iid as usize))
+            .map(|data| Message(self, data))
+    }
+
     /// Returns the [`Component`] named `name`, if any.
     pub fn component_by_name<S: AsRef<str>>(&self, name: S) -> Option<Component> {
         self.symbol(KeyRef::ComponentByName(name.as_ref()))
@@ -433,6 +504,7 @@ impl Dictionary {
         self.inner
             .messages
             .iter()
+            .filter(|data| data.scenario == BASE_SCENARIO)
             .map(move |data| Message(self, data))
     }
 
@@ -445,6 +517,39 @@ impl Dictionary {
             .map(move |data| Category(self, data))
     }
 
+    /// Returns the [`Category`] named `name`, if any.
+    pub fn category_by_name<S: AsRef<str>>(&self, name: S) -> Option<Category> {
+        self.symbol(KeyRef::CategoryByName(name.as_ref()))
+            .and_then(|iid| self.inner.categories.get(*iid as usize))
+            .map(|data| Category(self, data))
+    }
+
+    /// Returns the [`Section`] with the given `id`, if any. Sections are only
+    /// available in dictionaries read from a FIX Repository.
+    pub fn section_by_id<S: AsRef<str>>(&self, id: S) -> Option<Section> {
+        self.symbol(KeyRef::SectionById(id.as_ref()))
+            .and_then(|iid| self.inner.sections.get(*iid as usize))
+            .map(|data| Section(self, data))
+    }
+
+    /// Returns an [`Iterator`] over this [`Dictionary`]'s sections. Items are
+    /// in no particular order.
+    pub fn iter_sections(&self) -> impl Iterator<Item = Section> {
+        self.inner
+            .sections
+            .iter()
+            .map(move |data| Section(self, data))
+    }
+
+    /// Returns an [`Iterator`] over this [`Dictionary`]'s abbreviations. Items
+    /// are in no particular order.
+    pub fn iter_abbreviations(&self) -> impl Iterator<Item = Abbreviation> {
+        self.inner
+            .abbreviations
+            .iter()
+            .map(move |data| Abbreviation(self, data))
+    }
+
     /// Returns an [`Iterator`] over this [`Dictionary`]'s fields. Items are
     /// in no particular order.
     pub fn iter_fields(&self) -> impl Iterator<Item = Field> {
@@ -457,11 +562,48 @@ impl Dictionary {
         self.inner
             .components
             .iter()
+            .filter(|data| data.scenario == BASE_SCENARIO)
             .map(move |data| Component(self, data))
     }
+
+    /// Returns an [`Iterator`] over this [`Dictionary`]'s FIX Orchestra state
+    /// machines. Items are in no particular order.
+    pub fn iter_state_machines(&self) -> impl Iterator<Item = &StateMachine> {
+        self.inner.state_machines.iter()
+    }
 }
 
-struct DictionaryBuilder {
+/// A mutable version of [`Dictionary`], used to define venue-specific
+/// extensions (e.g. custom fields in the 5000-9999 range) on top of standard
+/// dictionaries, either programmatically or with overlay files (see
+/// [`DictionaryBuilder::apply_overlay`]).
+///
+/// # Examples
+///
+/// ```
+/// use fefix::prelude::*;
+/// use fefix::dict::FixDatatype;
+///
+/// let mut builder = Dictionary::fix44().to_builder();
+/// let venue_account = TagU32::new(5001).unwrap();
+/// builder
+///     .define_field(venue_account, "VenueAccount", FixDatatype::String)
+///     .unwrap()
+///     .define_field_enum(venue_account, "H", "House")
+///     .unwrap()
+///     .set_layout_item("NewOrderSingle", "VenueAccount", true)
+///     .unwrap();
+/// let dict = builder.build();
+///
+/// let field = dict.field_by_name("VenueAccount").unwrap();
+/// let new_order_single = dict.message_by_name("NewOrderSingle").unwrap();
+/// assert!(new_order_single
+///     .layout()
+///     .any(|item| item.required() && item.tag_text() == "VenueAccount"));
+/// assert_eq!(field.enums().unwrap().count(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DictionaryBuilder {
     version: String,
     symbol_table: FnvHashMap<Key, InternalId>,
     abbreviations: Vec<AbbreviationData>,
@@ -471,10 +613,15 @@ struct DictionaryBuilder {
     messages: Vec<MessageData>,
     //layout_items: Vec<LayoutItemData>,
     categories: Vec<CategoryData>,
+    sections: Vec<SectionData>,
+    state_machines: Vec<StateMachine>,
     header: Vec<FieldData>,
 }
 
 impl DictionaryBuilder {
+    /// Creates a new empty [`DictionaryBuilder`] for the FIX version
+    /// `version`. Use [`Dictionary::to_builder`] to start from an existing
+    /// [`Dictionary`] instead.
     pub fn new(version: String) -> Self {
         Self {
             version,
@@ -486,15 +633,17 @@ impl DictionaryBuilder {
             messages: Vec::new(),
             //layout_items: Vec::new(),
             categories: Vec::new(),
+            sections: Vec::new(),
+            state_machines: Vec::new(),
             header: Vec::new(),
         }
     }
 
-    pub fn symbol(&self, pkey: KeyRef) -> Option<&InternalId> {
+    fn symbol(&self, pkey: KeyRef) -> Option<&InternalId> {
         self.symbol_table.get(&pkey as &dyn SymbolTableIndex)
     }
 
-    pub fn add_field(&mut self, field: FieldData) -> InternalId {
+    fn add_field(&mut self, field: FieldData) -> InternalId {
         let iid = self.fields.len() as InternalId;
         self.symbol_table
             .insert(Key::FieldByName(field.name.clone()), iid);
@@ -504,25 +653,82 @@ impl DictionaryBuilder {
         iid
     }
 
-    pub fn add_message(&mut self, message: MessageData) -> InternalId {
+    fn add_message(&mut self, message: MessageData) -> InternalId {
         let iid = self.messages.len() as InternalId;
-        self.symbol_table
-            .insert(Key::MessageByName(message.name.clone()), iid);
-        self.symbol_table
-            .insert(Key::MessageByMsgType(message.msg_type.to_string()), iid);
+        self.symbol_table.insert(
+            Key::MessageByScenario(message.name.clone(), message.scenario.clone()),
+            iid,
+        );
+        // Names and message types only refer to base scenarios.
+        if message.scenario == BASE_SCENARIO {
+            self.symbol_table
+                .insert(Key::MessageByName(message.name.clone()), iid);
+            self.symbol_table
+                .insert(Key::MessageByMsgType(message.msg_type.to_string()), iid);
+        }
         self.messages.push(message);
         iid
     }
 
-    pub fn add_component(&mut self, component: ComponentData) -> InternalId {
+    fn add_component(&mut self, component: ComponentData) -> InternalId {
         let iid = self.components.len() as InternalId;
-        self.symbol_table
-            .insert(Key::ComponentByName(component.name.to_string()), iid);
+        if component.scenario == BASE_SCENARIO {
+            self.symbol_table
+                .insert(Key::ComponentByName(component.name.to_string()), iid);
+        }
         self.components.push(component);
         iid
     }
 
-    pub fn build(self) -> Dictionary {
+    /// Returns the internal ID of the [`Datatype`] of `datatype`, adding it
+    /// with `description` and `examples` if it's missing.
+    fn add_datatype(
+        &mut self,
+        datatype: FixDatatype,
+        description: &str,
+        examples: Vec<String>,
+    ) -> InternalId {
+        let name = datatype.name();
+        match self.symbol(KeyRef::DatatypeByName(name)) {
+            Some(x) => *x,
+            None => {
+                let iid = self.data_types.len() as InternalId;
+                self.data_types.push(DatatypeData {
+                    datatype,
+                    description: description.to_string(),
+                    examples,
+                });
+                self.symbol_table
+                    .insert(Key::DatatypeByName(name.to_string()), iid);
+                iid
+            }
+        }
+    }
+
+    /// Returns the internal ID of the [`Category`] named `name`, adding an
+    /// undocumented one if it's missing.
+    fn category_iid(&mut self, name: &str) -> InternalId {
+        match self.symbol(KeyRef::CategoryByName(name)) {
+            Some(x) => *x,
+            None => {
+                let iid = self.categories.len() as InternalId;
+                self.categories.push(CategoryData {
+                    name: name.to_string(),
+                    fixml_filename: String::new(),
+                    section_id: None,
+                    description: None,
+                    pedigree: Pedigree::default(),
+                });
+                self.symbol_table
+                    .insert(Key::CategoryByName(name.to_string()), iid);
+                iid
+            }
+        }
+    }
+
+    /// Turns `self` into a [`Dictionary`].
+    pub fn build(mut self) -> Dictionary {
+        self.link_data_fields();
         Dictionary {
             inner: Arc::new(DictionaryData {
                 version: self.version,
@@ -534,16 +740,524 @@ impl DictionaryBuilder {
                 messages: self.messages,
                 //layout_items: self.layout_items,
                 categories: self.categories,
+                sections: self.sections,
+                state_machines: self.state_machines,
                 header: self.header,
             }),
         }
     }
 }
 
+impl DictionaryBuilder {
+    /// Sets the associated data field of every `Length` field that doesn't
+    /// have one yet. QuickFIX specs don't carry this information, so the data
+    /// field is looked up by name first (e.g. `RawDataLength <95>` ->
+    /// `RawData <96>`, `SignatureLength <93>` -> `Signature <89>`), and then
+    /// by the next tag number.
+    fn link_data_fields(&mut self) {
+        for i in 0..self.fields.len() {
+            let field = &self.fields[i];
+            if field.associated_data_tag.is_some()
+                || self.fix_datatype(field) != Some(FixDatatype::Length)
+            {
+                continue;
+            }
+            let by_name = field
+                .name
+                .strip_suffix("Length")
+                .or_else(|| field.name.strip_suffix("Len"))
+                .and_then(|name| self.symbol(KeyRef::FieldByName(name)));
+            let by_tag = self.symbol(KeyRef::FieldByTag(field.tag + 1));
+            let data_tag = by_name
+                .into_iter()
+                .chain(by_tag)
+                .filter_map(|iid| self.fields.get(*iid as usize))
+                .find(|data| self.fix_datatype(data) == Some(FixDatatype::Data))
+                .map(|data| data.tag as usize);
+            self.fields[i].associated_data_tag = data_tag;
+        }
+    }
+
+    fn fix_datatype(&self, field: &FieldData) -> Option<FixDatatype> {
+        self.data_types
+            .get(field.data_type_iid as usize)
+            .map(|data| data.datatype)
+    }
+}
+
+impl DictionaryBuilder {
+    /// Sets the FIX version string of `self`, e.g. to tell apart a
+    /// venue-specific [`Dictionary`] from the standard one.
+    pub fn set_version<S: ToString>(&mut self, version: S) -> &mut Self {
+        self.version = version.to_string();
+        self
+    }
+
+    /// Adds a field with the given `tag`, `name`, and `datatype`. If a field
+    /// with the same `tag` already exists, its name and datatype are
+    /// overridden instead, while its values (if any) are kept.
+    ///
+    /// Fails if `name` is already used by a field with a different tag.
+    pub fn define_field(
+        &mut self,
+        tag: TagU32,
+        name: &str,
+        datatype: FixDatatype,
+    ) -> Result<&mut Self, EditDictionaryError> {
+        let data_type_iid = self.add_datatype(datatype, "", Vec::new());
+        let by_tag = self.symbol(KeyRef::FieldByTag(tag.get())).copied();
+        match (by_tag, self.symbol(KeyRef::FieldByName(name)).copied()) {
+            (_, Some(iid)) if by_tag != Some(iid) => {
+                return Err(EditDictionaryError::DuplicateName(name.to_string()));
+            }
+            (Some(iid), _) => {
+                let field = &mut self.fields[iid as usize];
+                if field.data_type_iid != data_type_iid {
+                    field.data_type_iid = data_type_iid;
+                    field.associated_data_tag = None;
+                }
+                let old_name = std::mem::replace(&mut field.name, name.to_string());
+                self.symbol_table
+                    .remove(&KeyRef::FieldByName(old_name.as_str()) as &dyn SymbolTableIndex);
+                self.symbol_table
+                    .insert(Key::FieldByName(name.to_string()), iid);
+            }
+            (None, _) => {
+                self.add_field(FieldData {
+                    name: name.to_string(),
+                    tag: tag.get(),
+                    data_type_iid,
+                    associated_data_tag: None,
+                    value_restrictions: None,
+                    abbr_name: None,
+                    base_category_id: None,
+                    base_category_abbr_name: None,
+                    required: true,
+                    description: None,
+                    pedigree: Pedigree::default(),
+                });
+            }
+        }
+        Ok(self)
+    }
+
+    /// Adds `value` to the values of the field `tag`, or overrides its
+    /// description if it's already there. Fields with at least one value
+    /// don't accept any other value (see [`Field::enums`]).
+    pub fn define_field_enum(
+        &mut self,
+        tag: TagU32,
+        value: &str,
+        description: &str,
+    ) -> Result<&mut Self, EditDictionaryError> {
+        let values = self
+            .field_mut(tag)?
+            .value_restrictions
+            .get_or_insert_with(Vec::new);
+        match values.iter_mut().find(|data| data.value == value) {
+            Some(data) => data.description = description.to_string(),
+            None => values.push(FieldEnumData {
+                value: value.to_string(),
+                description: description.to_string(),
+                documentation: None,
+                pedigree: Pedigree::default(),
+            }),
+        }
+        Ok(self)
+    }
+
+    /// Removes `value` from the values of the field `tag`, if present. The
+    /// field accepts any value once the last one is removed.
+    pub fn remove_field_enum(
+        &mut self,
+        tag: TagU32,
+        value: &str,
+    ) -> Result<&mut Self, EditDictionaryError> {
+        let field = self.field_mut(tag)?;
+        if let Some(values) = field.value_restrictions.as_mut() {
+            values.retain(|data| data.value != value);
+            if values.is_empty() {
+                field.value_restrictions = None;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Adds an empty common block named `name`, unless a component with the
+    /// same name already exists. Use [`DictionaryBuilder::set_layout_item`]
+    /// to fill it.
+    pub fn define_component(&mut self, name: &str) -> &mut Self {
+        if self.symbol(KeyRef::ComponentByName(name)).is_none() {
+            let category_iid = self.category_iid("Common");
+            self.add_component(ComponentData {
+                id: 0,
+                component_type: FixmlComponentAttributes::Block {
+                    is_repeating: false,
+                    is_implicit: false,
+                    is_optimized: false,
+                },
+                layout_items: Vec::new(),
+                category_iid,
+                name: name.to_string(),
+                abbr_name: None,
+                description: None,
+                pedigree: Pedigree::default(),
+                scenario: BASE_SCENARIO.to_string(),
+            });
+        }
+        self
+    }
+
+    /// Adds a message named `name` with the given `msg_type` to `category`.
+    /// Its layout is empty, except for `StandardHeader` and `StandardTrailer`
+    /// if other messages include them. Does nothing if the very same message
+    /// already exists.
+    ///
+    /// Fails if either `name` or `msg_type` is already used by another
+    /// message.
+    pub fn define_message(
+        &mut self,
+        name: &str,
+        msg_type: &str,
+        category: &str,
+    ) -> Result<&mut Self, EditDictionaryError> {
+        let by_name = self.symbol(KeyRef::MessageByName(name)).copied();
+        let by_msg_type = self.symbol(KeyRef::MessageByMsgType(msg_type)).copied();
+        match (by_name, by_msg_type) {
+            (Some(a), Some(b)) if a == b => return Ok(self),
+            (Some(_), _) => {
+                return Err(EditDictionaryError::DuplicateName(name.to_string()));
+            }
+            (_, Some(_)) => {
+                return Err(EditDictionaryError::DuplicateName(msg_type.to_string()));
+            }
+            (None, None) => {}
+        }
+        // QuickFIX specs leave `StandardHeader` and `StandardTrailer` out of
+        // message layouts, while other formats don't: do as other messages do.
+        let header_and_trailer = ["StandardHeader", "StandardTrailer"]
+            .iter()
+            .filter_map(|name| self.symbol(KeyRef::ComponentByName(name)).copied())
+            .collect::<Vec<_>>();
+        let layout_items = self
+            .messages
+            .iter()
+            .find(|data| data.scenario == BASE_SCENARIO)
+            .map(|data| {
+                data.layout_items
+                    .iter()
+                    .filter(|item| match item.kind {
+                        LayoutItemKindData::Component { iid } => header_and_trailer.contains(&iid),
+                        _ => false,
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let category_iid = self.category_iid(category);
+        self.add_message(MessageData {
+            component_id: 0,
+            msg_type: msg_type.to_string(),
+            name: name.to_string(),
+            category_iid,
+            section_id: String::new(),
+            layout_items,
+            abbr_name: None,
+            required: true,
+            description: String::new(),
+            elaboration: None,
+            pedigree: Pedigree::default(),
+            scenario: BASE_SCENARIO.to_string(),
+            responses: Vec::new(),
+        });
+        Ok(self)
+    }
+
+    /// Adds the field or component named `item` to the layout at `path`, or
+    /// overrides whether it's `required` if it's already there. New items of
+    /// messages are placed right before `StandardTrailer`.
+    ///
+    /// `path` starts with the name of a message or component, followed by the
+    /// `NumInGroup` fields of nested repeating groups, separated by `/` (e.g.
+    /// `Parties/NoPartyIDs`). Using a `NumInGroup` field as `item` adds an
+    /// empty repeating group.
+    pub fn set_layout_item(
+        &mut self,
+        path: &str,
+        item: &str,
+        required: bool,
+    ) -> Result<&mut Self, EditDictionaryError> {
+        let kind = self.layout_item_kind(item)?;
+        let trailer = self
+            .symbol(KeyRef::ComponentByName("StandardTrailer"))
+            .copied();
+        let items = self.layout_mut(path)?;
+        match items.iter_mut().find(|data| same_item(&data.kind, &kind)) {
+            Some(data) => data.required = required,
+            None => {
+                let index = match items.last() {
+                    Some(LayoutItemData {
+                        kind: LayoutItemKindData::Component { iid },
+                        ..
+                    }) if Some(*iid) == trailer => items.len() - 1,
+                    _ => items.len(),
+                };
+                items.insert(
+                    index,
+                    LayoutItemData {
+                        required,
+                        kind,
+                        rules: Vec::new(),
+                    },
+                );
+            }
+        }
+        Ok(self)
+    }
+
+    /// Removes the field or component named `item` from the layout at `path`
+    /// (see [`DictionaryBuilder::set_layout_item`]).
+    pub fn remove_layout_item(
+        &mut self,
+        path: &str,
+        item: &str,
+    ) -> Result<&mut Self, EditDictionaryError> {
+        let kind = self.layout_item_kind(item)?;
+        let items = self.layout_mut(path)?;
+        let len = items.len();
+        items.retain(|data| !same_item(&data.kind, &kind));
+        if items.len() == len {
+            return Err(EditDictionaryError::UnknownName(item.to_string()));
+        }
+        Ok(self)
+    }
+
+    /// Applies all definitions of the overlay XML `input` to `self`. Overlays
+    /// use the same format as QuickFIX specs, but all sections are optional
+    /// and they only contain what's new or different:
+    ///
+    /// - `<fix version='...'>` overrides the version string.
+    /// - `<field>`-s are added or overridden. `type` and `name` can be omitted
+    ///   to only edit the values of an existing field.
+    /// - `<value>`-s are added or overridden, or removed with `remove='Y'`.
+    /// - `<component>`-s and `<message>`-s are added if missing, then all
+    ///   their `<field>`, `<component>`, and `<group>` items are added or
+    ///   overridden, or removed with `remove='Y'`. Items of `<header>` and
+    ///   `<trailer>` go to `StandardHeader` and `StandardTrailer`.
+    ///
+    /// ```
+    /// use fefix::Dictionary;
+    ///
+    /// let overlay = r#"
+    /// <fix version="FIX.4.4-Venue">
+    ///   <fields>
+    ///     <field number="5001" name="VenueAccount" type="STRING"/>
+    ///     <field number="54">
+    ///       <value enum="X" description="VENUE_CROSS"/>
+    ///     </field>
+    ///   </fields>
+    ///   <messages>
+    ///     <message name="NewOrderSingle" msgtype="D" msgcat="app">
+    ///       <field name="VenueAccount" required="Y"/>
+    ///       <field name="Price" remove="Y"/>
+    ///     </message>
+    ///   </messages>
+    /// </fix>"#;
+    /// let dict = Dictionary::fix44().with_overlay(overlay).unwrap();
+    /// assert_eq!(dict.get_version(), "FIX.4.4-Venue");
+    /// assert_eq!(dict.field_by_tag(5001).unwrap().name(), "VenueAccount");
+    /// ```
+    pub fn apply_overlay<S: AsRef<str>>(
+        &mut self,
+        input: S,
+    ) -> Result<&mut Self, ParseDictionaryError> {
+        overlay::apply_overlay(self, input.as_ref())?;
+        Ok(self)
+    }
+
+    fn field_mut(&mut self, tag: TagU32) -> Result<&mut FieldData, EditDictionaryError> {
+        let iid = self
+            .symbol(KeyRef::FieldByTag(tag.get()))
+            .copied()
+            .ok_or(EditDictionaryError::UnknownTag(tag.get()))?;
+        Ok(&mut self.fields[iid as usize])
+    }
+
+    /// Returns the kind of layout item that refers to the field or component
+    /// `name`. `NumInGroup` fields refer to (empty) repeating groups.
+    fn layout_item_kind(&self, name: &str) -> Result<LayoutItemKindData, EditDictionaryError> {
+        if let Some(iid) = self.symbol(KeyRef::FieldByName(name)) {
+            let field = &self.fields[*iid as usize];
+            if self.fix_datatype(field) == Some(FixDatatype::NumInGroup) {
+                Ok(LayoutItemKindData::Group {
+                    len_field_iid: *iid,
+                    items: Vec::new(),
+                })
+            } else {
+                Ok(LayoutItemKindData::Field { iid: *iid })
+            }
+        } else if let Some(iid) = self.symbol(KeyRef::ComponentByName(name)) {
+            Ok(LayoutItemKindData::Component { iid: *iid })
+        } else {
+            Err(EditDictionaryError::UnknownName(name.to_string()))
+        }
+    }
+
+    fn layout_mut(&mut self, path: &str) -> Result<&mut LayoutItems, EditDictionaryError> {
+        let mut segments = path.split('/');
+        let root = segments.next().unwrap_or_default();
+        let message = self.symbol(KeyRef::MessageByName(root)).copied();
+        let component = self.symbol(KeyRef::ComponentByName(root)).copied();
+        let mut items = match (message, component) {
+            (Some(iid), _) => &mut self.messages[iid as usize].layout_items,
+            (None, Some(iid)) => {
+                let component = &mut self.components[iid as usize];
+                // Repeating components of FIX Repository and FIX Orchestra
+                // dictionaries wrap a single group.
+                let wraps_group = matches!(
+                    component.component_type,
+                    FixmlComponentAttributes::Block {
+                        is_repeating: true,
+                        ..
+                    }
+                ) && matches!(
+                    component.layout_items.as_slice(),
+                    [LayoutItemData {
+                        kind: LayoutItemKindData::Group { .. },
+                        ..
+                    }]
+                );
+                if wraps_group {
+                    match &mut component.layout_items[0].kind {
+                        LayoutItemKindData::Group { items, .. } => items,
+                        _ => unreachable!(),
+                    }
+                } else {
+                    &mut component.layout_items
+                }
+            }
+            (None, None) => return Err(EditDictionaryError::UnknownName(root.to_string())),
+        };
+        for segment in segments {
+            let fields = &self.fields;
+            items = items
+                .iter_mut()
+                .find_map(|data| match &mut data.kind {
+                    LayoutItemKindData::Group {
+                        len_field_iid,
+                        items,
+                    } if fields[*len_field_iid as usize].name == segment => Some(items),
+                    _ => None,
+                })
+                .ok_or_else(|| EditDictionaryError::UnknownName(segment.to_string()))?;
+        }
+        Ok(items)
+    }
+}
+
+fn same_item(a: &LayoutItemKindData, b: &LayoutItemKindData) -> bool {
+    match (a, b) {
+        (LayoutItemKindData::Field { iid: a }, LayoutItemKindData::Field { iid: b }) => a == b,
+        (LayoutItemKindData::Component { iid: a }, LayoutItemKindData::Component { iid: b }) => {
+            a == b
+        }
+        (
+            LayoutItemKindData::Group {
+                len_field_iid: a, ..
+            },
+            LayoutItemKindData::Group {
+                len_field_iid: b, ..
+            },
+        ) => a == b,
+        _ => false,
+    }
+}
+
+/// The error type that can arise when editing a [`Dictionary`] with a
+/// [`DictionaryBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EditDictionaryError {
+    /// No field has this tag.
+    UnknownTag(u32),
+    /// No field, component, or message (as required) has this name.
+    UnknownName(String),
+    /// This name or message type is already used by another definition.
+    DuplicateName(String),
+}
+
+impl fmt::Display for EditDictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownTag(tag) => write!(f, "Unknown tag ({})", tag),
+            Self::UnknownName(name) => write!(f, "Unknown name ({})", name),
+            Self::DuplicateName(name) => write!(f, "Duplicate name ({})", name),
+        }
+    }
+}
+
+impl std::error::Error for EditDictionaryError {}
+
+/// Versioning information about an entity of a [`Dictionary`], i.e. the FIX
+/// versions and Extension Packs (EP) in which it was added, last updated, and
+/// deprecated. Only FIX Repository files carry this information, so
+/// [`Pedigree`]-s of dictionaries read from QuickFIX specs are always empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Pedigree {
+    added: Option<String>,
+    added_ep: Option<i32>,
+    updated: Option<String>,
+    updated_ep: Option<i32>,
+    deprecated: Option<String>,
+    deprecated_ep: Option<i32>,
+}
+
+impl Pedigree {
+    /// Returns the FIX version in which the entity was introduced, e.g.
+    /// `FIX.4.2`.
+    pub fn added(&self) -> Option<&str> {
+        self.added.as_deref()
+    }
+
+    /// Returns the Extension Pack in which the entity was introduced, if any.
+    pub fn added_ep(&self) -> Option<i32> {
+        self.added_ep
+    }
+
+    /// Returns the FIX version in which the entity was last updated, if any.
+    pub fn updated(&self) -> Option<&str> {
+        self.updated.as_deref()
+    }
+
+    /// Returns the Extension Pack in which the entity was last updated, if any.
+    pub fn updated_ep(&self) -> Option<i32> {
+        self.updated_ep
+    }
+
+    /// Returns the FIX version in which the entity was deprecated, if any.
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    /// Returns the Extension Pack in which the entity was deprecated, if any.
+    pub fn deprecated_ep(&self) -> Option<i32> {
+        self.deprecated_ep
+    }
+
+    /// Returns `true` if and only if the entity was deprecated at some point.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some() || self.deprecated_ep.is_some()
+    }
+}
+
 #[derive(Clone, Debug)]
 struct AbbreviationData {
+    /// **Primary key.** The full term, e.g. `Account`.
+    term: String,
+    /// The abbreviated form of `term`, e.g. `Acct`.
     abbreviation: String,
-    is_last: bool,
+    /// Where the abbreviation is used, e.g. only in FIXML.
+    usage: Option<String>,
+    pedigree: Pedigree,
 }
 
 /// An [`Abbreviation`] is a standardized abbreviated form for a specific word,
@@ -556,8 +1270,24 @@ pub struct Abbreviation<'a>(&'a Dictionary, &'a AbbreviationData);
 impl<'a> Abbreviation<'a> {
     /// Returns the full term (non-abbreviated) associated with `self`.
     pub fn term(&self) -> &str {
+        self.1.term.as_str()
+    }
+
+    /// Returns the abbreviated form of [`Abbreviation::term`], e.g. `Acct` for
+    /// `Account`.
+    pub fn abbreviation(&self) -> &str {
         self.1.abbreviation.as_str()
     }
+
+    /// Returns a human readable note about the usage of `self`, if any.
+    pub fn usage(&self) -> Option<&str> {
+        self.1.usage.as_deref()
+    }
+
+    /// Returns the [`Pedigree`] of `self`.
+    pub fn pedigree(&self) -> &Pedigree {
+        &self.1.pedigree
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -566,6 +1296,10 @@ struct CategoryData {
     name: String,
     /// The FIXML file name for a Category.
     fixml_filename: String,
+    /// Identifier of the section to which this category belongs.
+    section_id: Option<String>,
+    description: Option<String>,
+    pedigree: Pedigree,
 }
 
 /// A [`Category`] is a collection of loosely related FIX messages or components
@@ -573,6 +1307,38 @@ struct CategoryData {
 #[derive(Clone, Debug)]
 pub struct Category<'a>(&'a Dictionary, &'a CategoryData);
 
+impl<'a> Category<'a> {
+    /// Returns the name of `self`. The name of every [`Category`] is unique
+    /// across a [`Dictionary`].
+    pub fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+
+    /// Returns the FIXML file name of `self`, e.g. `order`. It's empty if
+    /// unknown.
+    pub fn fixml_filename(&self) -> &str {
+        self.1.fixml_filename.as_str()
+    }
+
+    /// Returns the description of `self`, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.1.description.as_deref()
+    }
+
+    /// Returns the [`Section`] to which `self` belongs, if known.
+    pub fn section(&self) -> Option<Section> {
+        self.1
+            .section_id
+            .as_ref()
+            .and_then(|id| self.0.section_by_id(id))
+    }
+
+    /// Returns the [`Pedigree`] of `self`.
+    pub fn pedigree(&self) -> &Pedigree {
+        &self.1.pedigree
+    }
+}
+
 #[derive(Clone, Debug)]
 struct ComponentData {
     /// **Primary key.** The unique integer identifier of this component
@@ -585,6 +1351,10 @@ struct ComponentData {
     name: String,
     /// The name for this component when used in an XML context.
     abbr_name: Option<String>,
+    description: Option<String>,
+    pedigree: Pedigree,
+    /// The FIX Orchestra scenario of this component.
+    scenario: String,
 }
 
 /// A [`Component`] is an ordered collection of fields and/or other components.
@@ -609,6 +1379,27 @@ impl<'a> Component<'a> {
         self.1.name.as_str()
     }
 
+    /// Returns the description of `self`, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.1.description.as_deref()
+    }
+
+    /// Returns the name of `self` when used in an XML context, if any.
+    pub fn abbr_name(&self) -> Option<&str> {
+        self.1.abbr_name.as_deref()
+    }
+
+    /// Returns the [`Pedigree`] of `self`.
+    pub fn pedigree(&self) -> &Pedigree {
+        &self.1.pedigree
+    }
+
+    /// Returns the FIX Orchestra scenario of `self`, i.e. [`BASE_SCENARIO`]
+    /// unless `self` is a variant of another component.
+    pub fn scenario(&self) -> &str {
+        self.1.scenario.as_str()
+    }
+
     /// Returns `true` if and only if `self` is a "group" component; `false`
     /// otherwise.
     pub fn is_group(&self) -> bool {
@@ -1115,12 +1906,17 @@ struct FieldData {
     /// Indicates whether the field is required in an XML message.
     required: bool,
     description: Option<String>,
+    pedigree: Pedigree,
 }
 
 #[derive(Clone, Debug)]
 struct FieldEnumData {
     value: String,
+    /// A symbolic name for `value`, e.g. `Buy` or `BUY`.
     description: String,
+    /// Human readable documentation, only available in FIX Repository files.
+    documentation: Option<String>,
+    pedigree: Pedigree,
 }
 
 /// A limitation imposed on the value of a specific FIX [`Field`].  Also known as
@@ -1138,6 +1934,17 @@ impl<'a> FieldEnum<'a> {
     pub fn description(&self) -> &str {
         &self.1.description[..]
     }
+
+    /// Returns the human readable documentation of `self`, if any. Unlike
+    /// [`FieldEnum::description`], this is not a symbolic name.
+    pub fn documentation(&self) -> Option<&str> {
+        self.1.documentation.as_deref()
+    }
+
+    /// Returns the [`Pedigree`] of `self`.
+    pub fn pedigree(&self) -> &Pedigree {
+        &self.1.pedigree
+    }
 }
 
 /// A field is the most granular message structure abstraction. It carries a
@@ -1174,8 +1981,29 @@ impl<'a> Field<'a> {
         }
 
         self.fix_datatype().base_type() == FixDatatype::NumInGroup
-            || self.name().ends_with("Len")
-            || (self.name().starts_with("No") && nth_char_is_uppercase(self.name(), 2))
+            || (self.fix_datatype() != FixDatatype::Length
+                && (self.name().ends_with("Len")
+                    || (self.name().starts_with("No") && nth_char_is_uppercase(self.name(), 2))))
+    }
+
+    /// Returns the tag of the data field whose length is given by `self`, if
+    /// `self` is a `Length` field with an associated data field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let raw_data_length = dict.field_by_name("RawDataLength").unwrap();
+    /// assert_eq!(raw_data_length.associated_data_tag().unwrap().get(), 96);
+    /// let max_message_size = dict.field_by_name("MaxMessageSize").unwrap();
+    /// assert!(max_message_size.associated_data_tag().is_none());
+    /// ```
+    pub fn associated_data_tag(&self) -> Option<TagU32> {
+        self.1
+            .associated_data_tag
+            .and_then(|tag| TagU32::new(tag as u32))
     }
 
     /// Returns the [`FixDatatype`] of `self`.
@@ -1195,6 +2023,21 @@ impl<'a> Field<'a> {
         TagU32::new(self.1.tag).unwrap()
     }
 
+    /// Returns the description of `self`, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.1.description.as_deref()
+    }
+
+    /// Returns the name of `self` when used in an XML context, if any.
+    pub fn abbr_name(&self) -> Option<&str> {
+        self.1.abbr_name.as_deref()
+    }
+
+    /// Returns the [`Pedigree`] of `self`.
+    pub fn pedigree(&self) -> &Pedigree {
+        &self.1.pedigree
+    }
+
     /// In case this field allows any value, it returns `None`; otherwise; it
     /// returns an [`Iterator`] of all allowed values.
     pub fn enums(&self) -> Option<impl Iterator<Item = FieldEnum>> {
@@ -1249,6 +2092,85 @@ enum LayoutItemKindData {
 struct LayoutItemData {
     required: bool,
     kind: LayoutItemKindData,
+    /// FIX Orchestra rules that override `required`.
+    rules: Vec<RuleData>,
+}
+
+/// How a field, component, or repeating group is expected to appear within
+/// its parent, according to FIX Orchestra.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Presence {
+    /// The item may or may not appear.
+    Optional,
+    /// The item must appear.
+    Required,
+    /// The item must not appear.
+    Forbidden,
+    /// The item may appear, but its value is to be ignored.
+    Ignored,
+    /// The item always has the same value.
+    Constant,
+}
+
+#[derive(Clone, Debug)]
+struct RuleData {
+    name: Option<String>,
+    presence: Presence,
+    /// A Score DSL expression, e.g. `OrdType == ^Stop`.
+    when: String,
+}
+
+/// A FIX Orchestra rule that changes the [`Presence`] of a [`LayoutItem`]
+/// when a condition is met, e.g. "`StopPx` is required when `OrdType` is
+/// `Stop`".
+#[derive(Clone, Debug)]
+pub struct ConditionalRule<'a>(LayoutItem<'a>, &'a RuleData);
+
+impl<'a> ConditionalRule<'a> {
+    /// Returns the [`LayoutItem`] to which `self` applies.
+    pub fn item(&self) -> LayoutItem<'a> {
+        self.0.clone()
+    }
+
+    /// Returns the name of `self`, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.1.name.as_deref()
+    }
+
+    /// Returns the [`Presence`] of the item when [`ConditionalRule::when`]
+    /// holds.
+    pub fn presence(&self) -> Presence {
+        self.1.presence
+    }
+
+    /// Returns the condition of `self`, written in the Score DSL of FIX
+    /// Orchestra, e.g. `OrdType == ^Stop`.
+    pub fn when(&self) -> &str {
+        self.1.when.as_str()
+    }
+}
+
+fn collect_rules<'a>(items: Vec<LayoutItem<'a>>, rules: &mut Vec<ConditionalRule<'a>>) {
+    for item in items {
+        let (dict, data) = (item.0, item.1);
+        rules.extend(
+            data.rules
+                .iter()
+                .map(|rule| ConditionalRule(item.clone(), rule)),
+        );
+        match layout_item_kind(&data.kind, dict) {
+            LayoutItemKind::Component(Component(dict, component)) => {
+                let items = component
+                    .layout_items
+                    .iter()
+                    .map(|data| LayoutItem(dict, data))
+                    .collect();
+                collect_rules(items, rules);
+            }
+            LayoutItemKind::Group(_, items) => collect_rules(items, rules),
+            LayoutItemKind::Field(_) => {}
+        }
+    }
 }
 
 pub trait IsFieldDefinition {
@@ -1262,6 +2184,23 @@ pub trait IsFieldDefinition {
     fn location(&self) -> FieldLocation;
 }
 
+impl<F> IsFieldDefinition for &F
+where
+    F: IsFieldDefinition + ?Sized,
+{
+    fn tag(&self) -> TagU32 {
+        (**self).tag()
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn location(&self) -> FieldLocation {
+        (**self).location()
+    }
+}
+
 fn layout_item_kind<'a>(item: &'a LayoutItemKindData, dict: &'a Dictionary) -> LayoutItemKind<'a> {
     match item {
         LayoutItemKindData::Component { iid } => LayoutItemKind::Component(Component(
@@ -1286,6 +2225,29 @@ fn layout_item_kind<'a>(item: &'a LayoutItemKindData, dict: &'a Dictionary) -> L
     }
 }
 
+fn group_delimiter<'a>(
+    mut items: impl Iterator<Item = LayoutItem<'a>>,
+    num_in_group_tag: TagU32,
+) -> Option<TagU32> {
+    items.find_map(|item| match item.kind() {
+        LayoutItemKind::Group(field, items) if field.tag() == num_in_group_tag => {
+            first_field_tag(items.into_iter())
+        }
+        LayoutItemKind::Group(_, items) => group_delimiter(items.into_iter(), num_in_group_tag),
+        LayoutItemKind::Component(component) => {
+            group_delimiter(component.items(), num_in_group_tag)
+        }
+        LayoutItemKind::Field(_) => None,
+    })
+}
+
+fn first_field_tag<'a>(mut items: impl Iterator<Item = LayoutItem<'a>>) -> Option<TagU32> {
+    match items.next()?.kind() {
+        LayoutItemKind::Field(field) | LayoutItemKind::Group(field, _) => Some(field.tag()),
+        LayoutItemKind::Component(component) => first_field_tag(component.items()),
+    }
+}
+
 /// An entry in a sequence of FIX field definitions.
 #[derive(Clone, Debug)]
 pub struct LayoutItem<'a>(&'a Dictionary, &'a LayoutItemData);
@@ -1370,6 +2332,10 @@ struct MessageData {
     required: bool,
     description: String,
     elaboration: Option<String>,
+    pedigree: Pedigree,
+    /// The FIX Orchestra scenario of this message.
+    scenario: String,
+    responses: Vec<ResponseData>,
 }
 
 /// A [`Message`] is a unit of information sent on the wire between
@@ -1393,24 +2359,64 @@ impl<'a> Message<'a> {
         &self.1.description
     }
 
+    /// Returns additional documentation about `self`, if any.
+    pub fn elaboration(&self) -> Option<&str> {
+        self.1.elaboration.as_deref()
+    }
+
+    /// Returns the name of `self` when used in an XML context, if any.
+    pub fn abbr_name(&self) -> Option<&str> {
+        self.1.abbr_name.as_deref()
+    }
+
+    /// Returns the [`Category`] to which `self` belongs.
+    pub fn category(&self) -> Category {
+        Category(
+            self.0,
+            &self.0.inner.categories[self.1.category_iid as usize],
+        )
+    }
+
+    /// Returns the [`Section`] to which `self` belongs, if known.
+    pub fn section(&self) -> Option<Section> {
+        self.0.section_by_id(&self.1.section_id)
+    }
+
+    /// Returns the [`Pedigree`] of `self`.
+    pub fn pedigree(&self) -> &Pedigree {
+        &self.1.pedigree
+    }
+
+    /// Returns the FIX Orchestra scenario of `self`, i.e. [`BASE_SCENARIO`]
+    /// unless `self` is a variant of another message (e.g. a
+    /// `NewOrderSingle` for a specific order type).
+    pub fn scenario(&self) -> &str {
+        self.1.scenario.as_str()
+    }
+
+    /// Returns all [`ConditionalRule`]-s within the layout of `self`,
+    /// including those of components and repeating groups.
+    pub fn conditional_rules(&self) -> impl Iterator<Item = ConditionalRule> {
+        let mut rules = Vec::new();
+        collect_rules(self.layout().collect(), &mut rules);
+        rules.into_iter()
+    }
+
+    /// Returns an [`Iterator`] over the possible [`Response`]-s to `self`, as
+    /// defined by FIX Orchestra workflows.
+    pub fn responses(&self) -> impl Iterator<Item = Response> {
+        self.1
+            .responses
+            .iter()
+            .map(move |data| Response(self.0, data))
+    }
+
+    /// Looks for the repeating group with `NumInGroup` field `num_in_group_tag`
+    /// within `self`, including groups defined by components and nested
+    /// groups, and returns the tag of its delimiter field, i.e. the first field
+    /// of every group entry.
     pub fn group_info(&self, num_in_group_tag: TagU32) -> Option<TagU32> {
-        self.layout().find_map(|layout_item| {
-            if let LayoutItemKind::Group(field, items) = layout_item.kind() {
-                if field.tag() == num_in_group_tag {
-                    if let LayoutItemKind::Field(f) = items[0].kind() {
-                        Some(f.tag())
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            } else if let LayoutItemKind::Component(_component) = layout_item.kind() {
-                None
-            } else {
-                None
-            }
-        })
+        group_delimiter(self.layout(), num_in_group_tag)
     }
 
     /// Returns the component ID of `self`.
@@ -1426,11 +2432,211 @@ impl<'a> Message<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+struct ResponseData {
+    name: Option<String>,
+    when: Option<String>,
+    /// Names and scenarios of the messages that are sent in response.
+    message_refs: Vec<(String, String)>,
+    transition_refs: Vec<TransitionRef>,
+}
+
+/// A possible reaction to a [`Message`] according to FIX Orchestra workflows,
+/// i.e. the messages to send back and the [`StateMachine`] transitions to
+/// trigger.
+#[derive(Clone, Debug)]
+pub struct Response<'a>(&'a Dictionary, &'a ResponseData);
+
+impl<'a> Response<'a> {
+    /// Returns the name of `self`, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.1.name.as_deref()
+    }
+
+    /// Returns the condition under which `self` applies, if any.
+    pub fn when(&self) -> Option<&str> {
+        self.1.when.as_deref()
+    }
+
+    /// Returns an [`Iterator`] over the messages to send in response.
+    pub fn messages(&self) -> impl Iterator<Item = Message> {
+        self.1
+            .message_refs
+            .iter()
+            .filter_map(move |(name, scenario)| {
+                self.0.message_by_scenario(name.as_str(), scenario.as_str())
+            })
+    }
+
+    /// Returns the [`StateMachine`] transitions triggered by `self`.
+    pub fn transitions(&self) -> &[TransitionRef] {
+        &self.1.transition_refs[..]
+    }
+}
+
+/// A reference to a [`Transition`] of a [`StateMachine`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionRef {
+    actor: String,
+    state_machine: String,
+    state: String,
+    transition: String,
+}
+
+impl TransitionRef {
+    /// Returns the name of the actor that owns the [`StateMachine`].
+    pub fn actor(&self) -> &str {
+        self.actor.as_str()
+    }
+
+    /// Returns the name of the [`StateMachine`].
+    pub fn state_machine(&self) -> &str {
+        self.state_machine.as_str()
+    }
+
+    /// Returns the name of the [`State`] from which the transition starts.
+    pub fn state(&self) -> &str {
+        self.state.as_str()
+    }
+
+    /// Returns the name of the [`Transition`].
+    pub fn transition(&self) -> &str {
+        self.transition.as_str()
+    }
+}
+
+/// A FIX Orchestra state machine, e.g. the life cycle of an order, owned by
+/// an actor of the workflow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateMachine {
+    name: String,
+    actor: String,
+    initial: String,
+    states: Vec<State>,
+}
+
+impl StateMachine {
+    /// Returns the name of `self`.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the name of the actor that owns `self`.
+    pub fn actor(&self) -> &str {
+        self.actor.as_str()
+    }
+
+    /// Returns the initial [`State`] of `self`.
+    pub fn initial(&self) -> Option<&State> {
+        self.state(&self.initial)
+    }
+
+    /// Returns the [`State`] named `name`, if any.
+    pub fn state(&self, name: &str) -> Option<&State> {
+        self.states.iter().find(|state| state.name == name)
+    }
+
+    /// Returns all [`State`]-s of `self`, including the initial one.
+    pub fn states(&self) -> &[State] {
+        &self.states[..]
+    }
+}
+
+/// A state of a [`StateMachine`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct State {
+    name: String,
+    transitions: Vec<Transition>,
+}
+
+impl State {
+    /// Returns the name of `self`.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns all [`Transition`]-s from `self` to other states.
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions[..]
+    }
+}
+
+/// A transition from a [`State`] to another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition {
+    name: String,
+    target: String,
+    when: Option<String>,
+}
+
+impl Transition {
+    /// Returns the name of `self`.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the name of the [`State`] reached by `self`.
+    pub fn target(&self) -> &str {
+        self.target.as_str()
+    }
+
+    /// Returns the condition that triggers `self`, if any.
+    pub fn when(&self) -> Option<&str> {
+        self.when.as_deref()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SectionData {
+    /// **Primary key.** A string uniquely identifying this section.
+    id: String,
+    name: String,
+    display_order: u32,
+    /// The FIXML file name for a Section.
+    fixml_filename: String,
+    description: String,
+}
+
 /// A [`Section`] is a collection of many [`Component`]-s. It has no practical
 /// effect on encoding and decoding of FIX data and it's only used for
 /// documentation and human readability.
-#[derive(Clone, Debug, PartialEq)]
-pub struct Section {}
+#[derive(Clone, Debug)]
+pub struct Section<'a>(&'a Dictionary, &'a SectionData);
+
+impl<'a> Section<'a> {
+    /// Returns the unique identifier of `self`, e.g. `PreTrade`.
+    pub fn id(&self) -> &str {
+        self.1.id.as_str()
+    }
+
+    /// Returns the human readable name of `self`.
+    pub fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+
+    /// Returns the position of `self` within the FIX specifications.
+    pub fn display_order(&self) -> u32 {
+        self.1.display_order
+    }
+
+    /// Returns the FIXML file name of `self`.
+    pub fn fixml_filename(&self) -> &str {
+        self.1.fixml_filename.as_str()
+    }
+
+    /// Returns the description of `self`.
+    pub fn description(&self) -> &str {
+        self.1.description.as_str()
+    }
+
+    /// Returns an [`Iterator`] over all [`Category`]-s that belong to `self`.
+    pub fn categories(&self) -> impl Iterator<Item = Category> {
+        let id = self.id();
+        self.0
+            .iter_categories()
+            .filter(move |category| category.1.section_id.as_deref() == Some(id))
+    }
+}
 
 mod symbol_table {
     use super::InternalId;
@@ -1442,7 +2648,6 @@ mod symbol_table {
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
     pub enum Key {
-        #[allow(dead_code)]
         Abbreviation(String),
         CategoryByName(String),
         ComponentByName(String),
@@ -1451,6 +2656,8 @@ mod symbol_table {
         FieldByName(String),
         MessageByName(String),
         MessageByMsgType(String),
+        MessageByScenario(String, String),
+        SectionById(String),
     }
 
     #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
@@ -1463,6 +2670,8 @@ mod symbol_table {
         FieldByName(&'a str),
         MessageByName(&'a str),
         MessageByMsgType(&'a str),
+        MessageByScenario(&'a str, &'a str),
+        SectionById(&'a str),
     }
 
     impl Key {
@@ -1476,6 +2685,8 @@ mod symbol_table {
                 Key::FieldByName(s) => KeyRef::FieldByName(s.as_str()),
                 Key::MessageByName(s) => KeyRef::MessageByName(s.as_str()),
                 Key::MessageByMsgType(s) => KeyRef::MessageByMsgType(s.as_str()),
+                Key::MessageByScenario(s, t) => KeyRef::MessageByScenario(s.as_str(), t.as_str()),
+                Key::SectionById(s) => KeyRef::SectionById(s.as_str()),
             }
         }
     }
@@ -1537,6 +2748,22 @@ mod test {
         }));
     }
 
+    #[test]
+    fn referenced_components_have_their_definition() {
+        for dict in Dictionary::all().iter() {
+            for message in dict.iter_messages() {
+                for item in message.layout() {
+                    if let LayoutItemKind::Component(component) = item.kind() {
+                        assert_ne!(component.items().count(), 0, "{}", component.name());
+                    }
+                }
+            }
+        }
+        let dict = Dictionary::fix44();
+        let instrument = dict.component_by_name("Instrument").unwrap();
+        assert!(instrument.contains_field(&dict.field_by_tag(55).unwrap()));
+    }
+
     #[test]
     fn all_datatypes_are_used_at_least_once() {
         for dict in Dictionary::all().iter() {
@@ -1565,6 +2792,23 @@ mod test {
         }
     }
 
+    #[test]
+    fn length_fields_have_associated_data_tags() {
+        let dict = Dictionary::fix44();
+        for (length_tag, data_tag) in [(90, 91), (93, 89), (95, 96), (212, 213), (354, 355)] {
+            let field = dict.field_by_tag(length_tag).unwrap();
+            assert!(!field.is_num_in_group());
+            assert_eq!(field.associated_data_tag().unwrap().get(), data_tag);
+        }
+        for tag in [9, 383] {
+            assert!(dict
+                .field_by_tag(tag)
+                .unwrap()
+                .associated_data_tag()
+                .is_none());
+        }
+    }
+
     #[test]
     fn fix44_field_28_has_three_variants() {
         let dict = Dictionary::fix44();
@@ -1601,6 +2845,121 @@ mod test {
         include_str!("test_data/quickfix_specs/root_is_not_fix.xml"),
     ];
 
+    fn repository(path: &str) -> Dictionary {
+        let dir = format!(
+            "{}/resources/repositories/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        );
+        Dictionary::from_repository(&RepositoryFiles::read_dir(dir).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn all_repositories_are_ok() {
+        // The FIX 4.3 `Fields.xml` of the 2010 edition lacks most FIX 4.3
+        // fields, so its `MsgContents.xml` can't be resolved.
+        for version in [
+            "FIX.4.0",
+            "FIX.4.1",
+            "FIX.4.2",
+            "FIX.4.4",
+            "FIX.5.0",
+            "FIX.5.0SP1",
+            "FIX.5.0SP2",
+            "FIXT.1.1",
+        ] {
+            let dict = repository(&format!(
+                "fix_repository_2010_edition_20140507/{}/Base",
+                version
+            ));
+            assert_eq!(dict.get_version(), version);
+            assert!(dict.component_by_name("StandardHeader").is_some());
+            for message in dict.iter_messages() {
+                assert_ne!(message.layout().count(), 0, "{}", message.name());
+            }
+        }
+    }
+
+    #[test]
+    fn fix44_repository_has_documentation() {
+        let dict = repository("fix_repository_2010_edition_20140507/FIX.4.4/Base");
+        let abbreviation = dict.abbreviation_for("Account").unwrap();
+        assert_eq!(abbreviation.term(), "Account");
+        assert_eq!(abbreviation.abbreviation(), "Acct");
+        let account = dict.field_by_tag(1).unwrap();
+        assert!(account
+            .description()
+            .unwrap()
+            .starts_with("Account mnemonic"));
+        assert_eq!(account.abbr_name(), Some("Acct"));
+        assert_eq!(account.pedigree().added(), Some("FIX.2.7"));
+        let side = dict.field_by_tag(54).unwrap();
+        let buy = side.enums().unwrap().find(|e| e.value() == "1").unwrap();
+        assert_eq!(buy.description(), "Buy");
+        assert_eq!(buy.documentation(), Some("Buy"));
+        let raw_data_length = dict.field_by_tag(95).unwrap();
+        assert_eq!(raw_data_length.associated_data_tag().unwrap().get(), 96);
+    }
+
+    #[test]
+    fn fix44_repository_has_sections_and_categories() {
+        let dict = repository("fix_repository_2010_edition_20140507/FIX.4.4/Base");
+        let new_order_single = dict.message_by_msgtype("D").unwrap();
+        assert_eq!(new_order_single.name(), "NewOrderSingle");
+        let category = new_order_single.category();
+        assert_eq!(category.name(), "SingleGeneralOrderHandling");
+        assert_eq!(category.fixml_filename(), "order");
+        let section = new_order_single.section().unwrap();
+        assert_eq!(section.id(), "Trade");
+        assert!(section
+            .categories()
+            .any(|c| c.name() == "SingleGeneralOrderHandling"));
+        assert!(dict.section_by_id("Session").unwrap().description().len() > 0);
+        assert!(dict.iter_sections().count() >= 4);
+    }
+
+    #[test]
+    fn fix44_repository_has_repeating_groups() {
+        let dict = repository("fix_repository_2010_edition_20140507/FIX.4.4/Base");
+        let new_order_single = dict.message_by_name("NewOrderSingle").unwrap();
+        let delimiter = new_order_single.group_info(TagU32::new(453).unwrap());
+        assert_eq!(delimiter.unwrap().get(), 448);
+        let parties = dict.component_by_name("Parties").unwrap();
+        assert!(parties.is_group());
+        assert_eq!(parties.items().count(), 1);
+        let item = parties.items().next().unwrap();
+        assert!(
+            matches!(item.kind(), LayoutItemKind::Group(field, items) if field.tag().get() == 453 && items.len() == 4)
+        );
+        assert!(dict.component_by_name("PtysSubGrp").unwrap().is_group());
+    }
+
+    #[test]
+    fn ep254_repository_has_extension_packs() {
+        let dict = repository("FIXRepository_FIX.5.0SP2_EP254/Basic");
+        assert_eq!(dict.get_version(), "FIX.5.0SP2");
+        let parties = dict.component_by_name("Parties").unwrap();
+        assert_eq!(parties.pedigree().added(), Some("FIX.4.3"));
+        assert!(dict
+            .iter_fields()
+            .any(|f| f.pedigree().added_ep() == Some(254)));
+        assert!(dict.iter_fields().any(|f| f.pedigree().is_deprecated()));
+        // `PartyRoleQualifier <2376>` is at position 4.5 within `Parties`.
+        let group = parties.items().next().unwrap();
+        let entries = match group.kind() {
+            LayoutItemKind::Group(_, items) => items,
+            _ => panic!(),
+        };
+        let tags = entries
+            .iter()
+            .filter_map(|item| match item.kind() {
+                LayoutItemKind::Field(field) => Some(field.tag().get()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec![448, 447, 452, 2376]);
+    }
+
     #[test]
     fn invalid_quickfix_specs() {
         for spec in INVALID_QUICKFIX_SPECS.iter() {
@@ -1608,4 +2967,300 @@ mod test {
             assert!(dict.is_err(), "{}", spec);
         }
     }
+
+    fn orchestra() -> Dictionary {
+        Dictionary::from_orchestra(include_str!("test_data/orchestra/venue.xml")).unwrap()
+    }
+
+    #[test]
+    fn orchestra_fields_and_code_sets() {
+        let dict = orchestra();
+        assert_eq!(dict.get_version(), "FIX.4.4");
+        let side = dict.field_by_tag(54).unwrap();
+        assert_eq!(side.fix_datatype(), FixDatatype::Char);
+        let buy = side.enums().unwrap().next().unwrap();
+        assert_eq!((buy.value(), buy.description()), ("1", "Buy"));
+        assert_eq!(buy.documentation(), Some("Buy order."));
+        let venue_account = dict.field_by_name("VenueAccount").unwrap();
+        assert_eq!(venue_account.tag().get(), 5001);
+        assert_eq!(venue_account.fix_datatype(), FixDatatype::String);
+        assert_eq!(venue_account.pedigree().added_ep(), Some(-1));
+        let raw_data_length = dict.field_by_tag(95).unwrap();
+        assert_eq!(raw_data_length.associated_data_tag().unwrap().get(), 96);
+    }
+
+    #[test]
+    fn orchestra_components_and_groups() {
+        let dict = orchestra();
+        let parties = dict.component_by_name("Parties").unwrap();
+        assert!(parties.is_group());
+        assert_eq!(parties.description(), Some("Parties of the order."));
+        let new_order_single = dict.message_by_msgtype("D").unwrap();
+        let delimiter = new_order_single.group_info(TagU32::new(453).unwrap());
+        assert_eq!(delimiter.unwrap().get(), 448);
+        assert_eq!(new_order_single.section().unwrap().id(), "Trade");
+        let std_header = dict.component_by_name("StandardHeader").unwrap();
+        assert!(std_header.contains_field(&dict.field_by_tag(35).unwrap()));
+    }
+
+    #[test]
+    fn orchestra_scenarios() {
+        let dict = orchestra();
+        assert_eq!(dict.iter_messages().count(), 2);
+        let base = dict.message_by_name("NewOrderSingle").unwrap();
+        assert_eq!(base.scenario(), BASE_SCENARIO);
+        let market = dict
+            .message_by_scenario("NewOrderSingle", "Market")
+            .unwrap();
+        assert_eq!(market.scenario(), "Market");
+        assert_eq!(market.msg_type(), "D");
+        assert!(market.layout().all(|item| item.tag_text() != "Parties"));
+    }
+
+    #[test]
+    fn orchestra_conditional_rules() {
+        let dict = orchestra();
+        let new_order_single = dict.message_by_name("NewOrderSingle").unwrap();
+        let rules = new_order_single.conditional_rules().collect::<Vec<_>>();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].item().tag_text(), "Price");
+        assert_eq!(rules[1].item().tag_text(), "StopPx");
+        assert_eq!(rules[1].name(), Some("StopOrder"));
+        assert_eq!(rules[1].presence(), Presence::Required);
+        assert_eq!(rules[1].when(), "OrdType == ^Stop");
+        assert!(!rules[1].item().required());
+    }
+
+    #[test]
+    fn orchestra_workflow() {
+        let dict = orchestra();
+        let new_order_single = dict.message_by_name("NewOrderSingle").unwrap();
+        let response = new_order_single.responses().next().unwrap();
+        assert_eq!(response.name(), Some("OrderAccepted"));
+        assert_eq!(response.when(), Some("OrderQty > 0"));
+        let execution_report = response.messages().next().unwrap();
+        assert_eq!(execution_report.msg_type(), "8");
+        assert_eq!(execution_report.scenario(), "New");
+        let transition_ref = &response.transitions()[0];
+        let state_machine = dict
+            .iter_state_machines()
+            .find(|sm| sm.name() == transition_ref.state_machine())
+            .unwrap();
+        assert_eq!(state_machine.actor(), "Venue");
+        assert_eq!(state_machine.initial().unwrap().name(), "New");
+        let accept = state_machine
+            .state(transition_ref.state())
+            .unwrap()
+            .transitions()
+            .iter()
+            .find(|t| t.name() == transition_ref.transition())
+            .unwrap();
+        assert_eq!(accept.target(), "Working");
+        let fill = &state_machine.state("Working").unwrap().transitions()[0];
+        assert_eq!(fill.when(), Some("ExecType == ^Trade"));
+    }
+
+    #[test]
+    fn builder_adds_and_overrides_fields() {
+        let fix44 = Dictionary::fix44();
+        let mut builder = fix44.to_builder();
+        let side = TagU32::new(54).unwrap();
+        let venue_account = TagU32::new(5001).unwrap();
+        builder
+            .define_field(venue_account, "VenueAccount", FixDatatype::String)
+            .unwrap()
+            .define_field(TagU32::new(58).unwrap(), "Comment", FixDatatype::String)
+            .unwrap()
+            .define_field_enum(side, "1", "BUY_ORDER")
+            .unwrap()
+            .define_field_enum(side, "X", "VENUE_CROSS")
+            .unwrap()
+            .remove_field_enum(side, "2")
+            .unwrap();
+        assert_eq!(
+            builder
+                .define_field(TagU32::new(5002).unwrap(), "Side", FixDatatype::Char)
+                .unwrap_err(),
+            EditDictionaryError::DuplicateName("Side".to_string())
+        );
+        assert_eq!(
+            builder
+                .define_field_enum(TagU32::new(5999).unwrap(), "1", "ONE")
+                .unwrap_err(),
+            EditDictionaryError::UnknownTag(5999)
+        );
+        let dict = builder.build();
+        assert_eq!(dict.field_by_tag(5001).unwrap().name(), "VenueAccount");
+        assert_eq!(dict.field_by_name("Comment").unwrap().tag().get(), 58);
+        assert!(dict.field_by_name("Text").is_none());
+        let side = dict.field_by_tag(54).unwrap();
+        let side_values = side.enums().unwrap().collect::<Vec<_>>();
+        assert_eq!(side_values[0].description(), "BUY_ORDER");
+        assert!(side_values.iter().all(|value| value.value() != "2"));
+        assert_eq!(side_values.last().unwrap().value(), "X");
+        // The original dictionary is left untouched.
+        assert_eq!(fix44.field_by_tag(58).unwrap().name(), "Text");
+        assert!(fix44.field_by_tag(5001).is_none());
+    }
+
+    #[test]
+    fn builder_edits_layouts() {
+        let mut builder = Dictionary::fix44().to_builder();
+        builder
+            .define_field(
+                TagU32::new(5001).unwrap(),
+                "VenueAccount",
+                FixDatatype::String,
+            )
+            .unwrap()
+            .define_component("VenueBlock")
+            .set_layout_item("VenueBlock", "VenueAccount", true)
+            .unwrap()
+            .set_layout_item("NewOrderSingle", "VenueBlock", false)
+            .unwrap()
+            .set_layout_item("NewOrderSingle", "Price", true)
+            .unwrap()
+            .remove_layout_item("NewOrderSingle", "StopPx")
+            .unwrap()
+            .set_layout_item("Parties/NoPartyIDs", "VenueAccount", false)
+            .unwrap()
+            .define_message("VenueStatus", "U1", "app")
+            .unwrap()
+            .set_layout_item("VenueStatus", "NoPartyIDs", false)
+            .unwrap()
+            .set_layout_item("VenueStatus/NoPartyIDs", "PartyID", true)
+            .unwrap();
+        assert_eq!(
+            builder
+                .set_layout_item("NewOrderSingle", "Unknown", true)
+                .unwrap_err(),
+            EditDictionaryError::UnknownName("Unknown".to_string())
+        );
+        assert_eq!(
+            builder
+                .remove_layout_item("NewOrderSingle", "StopPx")
+                .unwrap_err(),
+            EditDictionaryError::UnknownName("StopPx".to_string())
+        );
+        assert_eq!(
+            builder.define_message("Status", "U1", "app").unwrap_err(),
+            EditDictionaryError::DuplicateName("U1".to_string())
+        );
+        let dict = builder.build();
+        let new_order_single = dict.message_by_name("NewOrderSingle").unwrap();
+        let items = new_order_single.layout().collect::<Vec<_>>();
+        assert_eq!(items.last().unwrap().tag_text(), "VenueBlock");
+        assert!(items.iter().all(|item| item.tag_text() != "StopPx"));
+        assert!(items
+            .iter()
+            .any(|item| item.tag_text() == "Price" && item.required()));
+        let parties = dict.component_by_name("Parties").unwrap();
+        assert!(matches!(
+            parties.items().next().unwrap().kind(),
+            LayoutItemKind::Group(_, items) if items.last().unwrap().tag_text() == "VenueAccount"
+        ));
+        let venue_status = dict.message_by_msgtype("U1").unwrap();
+        assert_eq!(venue_status.name(), "VenueStatus");
+        let delimiter = venue_status.group_info(TagU32::new(453).unwrap());
+        assert_eq!(delimiter.unwrap().get(), 448);
+    }
+
+    #[test]
+    fn builder_edits_repeating_components() {
+        let mut builder = orchestra().to_builder();
+        builder
+            .set_layout_item("Parties", "Symbol", false)
+            .unwrap()
+            .define_message("VenueStatus", "U1", "Session")
+            .unwrap()
+            .set_layout_item("VenueStatus", "ClOrdID", true)
+            .unwrap();
+        let dict = builder.build();
+        let venue_status = dict.message_by_name("VenueStatus").unwrap();
+        let items = venue_status
+            .layout()
+            .map(|item| item.tag_text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(items, &["StandardHeader", "ClOrdID", "StandardTrailer"]);
+        let parties = dict.component_by_name("Parties").unwrap();
+        assert!(matches!(
+            parties.items().next().unwrap().kind(),
+            LayoutItemKind::Group(_, items) if items.last().unwrap().tag_text() == "Symbol"
+        ));
+    }
+
+    #[test]
+    fn overlay_extends_fix44() {
+        let dict = Dictionary::fix44()
+            .with_overlay(
+                r#"<fix version="FIX.4.4-Venue">
+                    <fields>
+                        <field number="5001" name="VenueAccount" type="STRING"/>
+                        <field number="54">
+                            <value enum="X" description="VENUE_CROSS"/>
+                            <value enum="2" remove="Y"/>
+                        </field>
+                    </fields>
+                    <header>
+                        <field name="VenueAccount" required="N"/>
+                    </header>
+                    <components>
+                        <component name="VenueBlock">
+                            <field name="VenueAccount" required="Y"/>
+                        </component>
+                    </components>
+                    <messages>
+                        <message name="NewOrderSingle" msgtype="D" msgcat="app">
+                            <component name="VenueBlock" required="N"/>
+                            <field name="Price" remove="Y"/>
+                            <group name="NoPartyIDs" required="N">
+                                <field name="VenueAccount" required="N"/>
+                            </group>
+                        </message>
+                    </messages>
+                </fix>"#,
+            )
+            .unwrap();
+        assert_eq!(dict.get_version(), "FIX.4.4-Venue");
+        assert_eq!(
+            dict.field_by_tag(54).unwrap().enums().unwrap().count(),
+            Dictionary::fix44()
+                .field_by_tag(54)
+                .unwrap()
+                .enums()
+                .unwrap()
+                .count()
+        );
+        let std_header = dict.component_by_name("StandardHeader").unwrap();
+        assert!(std_header.contains_field(&dict.field_by_tag(5001).unwrap()));
+        let venue_block = dict.component_by_name("VenueBlock").unwrap();
+        assert!(venue_block.items().next().unwrap().required());
+        let new_order_single = dict.message_by_name("NewOrderSingle").unwrap();
+        assert!(new_order_single
+            .layout()
+            .all(|item| item.tag_text() != "Price"));
+        assert!(new_order_single
+            .layout()
+            .any(|item| item.tag_text() == "NoPartyIDs"));
+    }
+
+    #[test]
+    fn invalid_overlays_are_errors() {
+        let fix44 = Dictionary::fix44();
+        assert!(fix44.with_overlay("<fix></fields>").is_err());
+        assert!(fix44.with_overlay("<dictionary/>").is_err());
+        assert!(fix44
+            .with_overlay(
+                r#"<fix><fields><field number="0" name="A" type="STRING"/></fields></fix>"#
+            )
+            .is_err());
+        assert!(fix44
+            .with_overlay(
+                r#"<fix><fields><field number="5001" name="A" type="FOO"/></fields></fix>"#
+            )
+            .is_err());
+        assert!(fix44
+            .with_overlay(r#"<fix><messages><message name="Foo" msgtype="D"/></messages></fix>"#)
+            .is_err());
+    }
 }
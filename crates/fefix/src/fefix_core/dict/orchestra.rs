@@ -0,0 +1,445 @@
+use super::repository::{parse, pedigree, resolve_datatype};
+use super::*;
+
+pub fn read_orchestra(input: &str) -> ParseResult<Dictionary> {
+    let document = parse(input)?;
+    let root = document.root_element();
+    if !root.has_tag_name("repository") {
+        return Err(ParseDictionaryError::InvalidData(
+            "<repository> tag not found".to_string(),
+        ));
+    }
+    let version = root
+        .attribute("version")
+        .or_else(|| root.attribute("name"))
+        .ok_or_else(|| ParseDictionaryError::InvalidData("No version attribute.".to_string()))?;
+    let mut builder = DictionaryBuilder::new(version.to_string());
+    for node in entries(root, "sections", "section") {
+        import_section(&mut builder, node)?;
+    }
+    for node in entries(root, "categories", "category") {
+        import_category(&mut builder, node)?;
+    }
+    let datatypes = entries(root, "datatypes", "datatype")
+        .filter_map(|node| Some((node.attribute("name")?, node)))
+        .collect::<FnvHashMap<_, _>>();
+    let code_sets = entries(root, "codeSets", "codeSet")
+        .filter(|node| scenario(*node) == BASE_SCENARIO)
+        .filter_map(|node| Some((node.attribute("name")?, node)))
+        .collect::<FnvHashMap<_, _>>();
+    // Only base scenarios of fields are supported, because tags must be
+    // unique.
+    for node in entries(root, "fields", "field").filter(|n| scenario(*n) == BASE_SCENARIO) {
+        import_field(&mut builder, node, &datatypes, &code_sets)?;
+    }
+    link_length_fields(&mut builder, root)?;
+    // All components and groups must exist before their layouts because
+    // they can reference each other in any order.
+    let mut components = FnvHashMap::<(u32, &str), InternalId>::default();
+    for (node, is_group) in entries(root, "components", "component")
+        .map(|node| (node, false))
+        .chain(entries(root, "groups", "group").map(|node| (node, true)))
+    {
+        let iid = import_component(&mut builder, node, is_group)?;
+        components.insert((parse_attribute(node, "id")?, scenario(node)), iid);
+    }
+    for node in entries(root, "components", "component") {
+        let iid = components[&(parse_attribute(node, "id")?, scenario(node))];
+        builder.components[iid as usize].layout_items = layout(&builder, &components, node)?;
+    }
+    for node in entries(root, "groups", "group") {
+        let iid = components[&(parse_attribute(node, "id")?, scenario(node))];
+        let num_in_group = node
+            .children()
+            .find(|n| n.has_tag_name("numInGroup"))
+            .ok_or_else(|| {
+                ParseDictionaryError::InvalidData("<numInGroup> tag not found".to_string())
+            })?;
+        let group = LayoutItemData {
+            required: false,
+            kind: LayoutItemKindData::Group {
+                len_field_iid: field_iid(&builder, num_in_group)?,
+                items: layout(&builder, &components, node)?,
+            },
+            rules: Vec::new(),
+        };
+        builder.components[iid as usize].layout_items = vec![group];
+    }
+    for node in entries(root, "messages", "message") {
+        import_message(&mut builder, &components, node)?;
+    }
+    for actor in entries(root, "actors", "actor") {
+        for node in actor
+            .descendants()
+            .filter(|n| n.has_tag_name("stateMachine"))
+        {
+            let state_machine = state_machine(actor, node)?;
+            builder.state_machines.push(state_machine);
+        }
+    }
+    Ok(builder.build())
+}
+
+fn import_section(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<()> {
+    let id = required_attribute(node, "name")?.to_string();
+    let iid = builder.sections.len() as InternalId;
+    builder
+        .symbol_table
+        .insert(Key::SectionById(id.clone()), iid);
+    builder.sections.push(SectionData {
+        name: id.clone(),
+        id,
+        display_order: node
+            .attribute("displayOrder")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default(),
+        fixml_filename: node
+            .attribute("FIXMLFileName")
+            .unwrap_or_default()
+            .to_string(),
+        description: documentation(node).unwrap_or_default(),
+    });
+    Ok(())
+}
+
+fn import_category(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<()> {
+    let name = required_attribute(node, "name")?.to_string();
+    let iid = builder.categories.len() as InternalId;
+    builder
+        .symbol_table
+        .insert(Key::CategoryByName(name.clone()), iid);
+    builder.categories.push(CategoryData {
+        name,
+        fixml_filename: node
+            .attribute("FIXMLFileName")
+            .unwrap_or_default()
+            .to_string(),
+        section_id: node.attribute("section").map(str::to_string),
+        description: documentation(node),
+        pedigree: pedigree(node),
+    });
+    Ok(())
+}
+
+fn import_field(
+    builder: &mut DictionaryBuilder,
+    node: roxmltree::Node,
+    datatypes: &FnvHashMap<&str, roxmltree::Node>,
+    code_sets: &FnvHashMap<&str, roxmltree::Node>,
+) -> ParseResult<InternalId> {
+    let type_name = required_attribute(node, "type")?;
+    // The type of a field is either a datatype or a code set.
+    let code_set = code_sets.get(type_name);
+    let datatype_name = match code_set {
+        Some(code_set) => required_attribute(*code_set, "type")?,
+        None => type_name,
+    };
+    let datatype = resolve_datatype(datatype_name, &|name| {
+        datatypes
+            .get(name)
+            .and_then(|node| node.attribute("baseType"))
+    });
+    let definition = datatypes.get(datatype.name());
+    let data_type_iid = builder.add_datatype(
+        datatype,
+        &definition
+            .and_then(|node| documentation(*node))
+            .unwrap_or_default(),
+        Vec::new(),
+    );
+    let value_restrictions = match code_set {
+        Some(code_set) => Some(
+            code_set
+                .children()
+                .filter(|n| n.has_tag_name("code"))
+                .map(field_enum)
+                .collect::<ParseResult<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+    let base_category_id = node
+        .attribute("baseCategory")
+        .and_then(|name| builder.symbol(KeyRef::CategoryByName(name)))
+        .map(|iid| *iid as usize);
+    let field = FieldData {
+        name: required_attribute(node, "name")?.to_string(),
+        tag: parse_attribute(node, "id")?,
+        data_type_iid,
+        associated_data_tag: None,
+        value_restrictions,
+        abbr_name: node.attribute("abbrName").map(str::to_string),
+        base_category_id,
+        base_category_abbr_name: node.attribute("baseCategoryAbbrName").map(str::to_string),
+        required: true,
+        description: documentation(node),
+        pedigree: pedigree(node),
+    };
+    Ok(builder.add_field(field))
+}
+
+fn field_enum(node: roxmltree::Node) -> ParseResult<FieldEnumData> {
+    Ok(FieldEnumData {
+        value: required_attribute(node, "value")?.to_string(),
+        description: required_attribute(node, "name")?.to_string(),
+        documentation: documentation(node),
+        pedigree: pedigree(node),
+    })
+}
+
+/// Data fields reference their `Length` field with `lengthId`.
+fn link_length_fields(builder: &mut DictionaryBuilder, root: roxmltree::Node) -> ParseResult<()> {
+    for node in entries(root, "fields", "field").filter(|n| scenario(*n) == BASE_SCENARIO) {
+        if let Some(length_tag) = node.attribute("lengthId") {
+            let length_tag = length_tag
+                .parse()
+                .map_err(|_| ParseDictionaryError::InvalidData("Invalid lengthId".to_string()))?;
+            if let Some(iid) = builder.symbol(KeyRef::FieldByTag(length_tag)).copied() {
+                builder.fields[iid as usize].associated_data_tag =
+                    Some(parse_attribute(node, "id")?);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn import_component(
+    builder: &mut DictionaryBuilder,
+    node: roxmltree::Node,
+    is_group: bool,
+) -> ParseResult<InternalId> {
+    let component = ComponentData {
+        id: parse_attribute(node, "id")?,
+        component_type: FixmlComponentAttributes::Block {
+            is_repeating: is_group,
+            is_implicit: false,
+            is_optimized: false,
+        },
+        layout_items: LayoutItems::new(),
+        category_iid: builder.category_iid(node.attribute("category").unwrap_or_default()),
+        name: required_attribute(node, "name")?.to_string(),
+        abbr_name: node.attribute("abbrName").map(str::to_string),
+        description: documentation(node),
+        pedigree: pedigree(node),
+        scenario: scenario(node).to_string(),
+    };
+    Ok(builder.add_component(component))
+}
+
+fn import_message(
+    builder: &mut DictionaryBuilder,
+    components: &FnvHashMap<(u32, &str), InternalId>,
+    node: roxmltree::Node,
+) -> ParseResult<InternalId> {
+    let category_iid = builder.category_iid(node.attribute("category").unwrap_or_default());
+    let layout_items = match node.children().find(|n| n.has_tag_name("structure")) {
+        Some(structure) => layout(builder, components, structure)?,
+        None => LayoutItems::new(),
+    };
+    let responses = node
+        .children()
+        .filter(|n| n.has_tag_name("responses"))
+        .flat_map(|n| n.children())
+        .filter(|n| n.has_tag_name("response"))
+        .map(response)
+        .collect::<ParseResult<Vec<_>>>()?;
+    let message = MessageData {
+        component_id: parse_attribute(node, "id")?,
+        msg_type: required_attribute(node, "msgType")?.to_string(),
+        name: required_attribute(node, "name")?.to_string(),
+        category_iid,
+        section_id: builder.categories[category_iid as usize]
+            .section_id
+            .clone()
+            .unwrap_or_default(),
+        layout_items,
+        abbr_name: node.attribute("abbrName").map(str::to_string),
+        required: true,
+        description: documentation(node).unwrap_or_default(),
+        elaboration: None,
+        pedigree: pedigree(node),
+        scenario: scenario(node).to_string(),
+        responses,
+    };
+    Ok(builder.add_message(message))
+}
+
+/// Reads the `fieldRef`, `componentRef`, and `groupRef` children of `node`.
+fn layout(
+    builder: &DictionaryBuilder,
+    components: &FnvHashMap<(u32, &str), InternalId>,
+    node: roxmltree::Node,
+) -> ParseResult<LayoutItems> {
+    let mut items = LayoutItems::new();
+    for child in node.children().filter(|n| n.is_element()) {
+        let kind = match child.tag_name().name() {
+            "fieldRef" => LayoutItemKindData::Field {
+                iid: field_iid(builder, child)?,
+            },
+            "componentRef" | "groupRef" => {
+                let id = parse_attribute(child, "id")?;
+                let iid = components.get(&(id, scenario(child))).ok_or_else(|| {
+                    ParseDictionaryError::InvalidData(format!("Unknown component {}", id))
+                })?;
+                LayoutItemKindData::Component { iid: *iid }
+            }
+            _ => continue,
+        };
+        let rules = child
+            .children()
+            .filter(|n| n.has_tag_name("rule"))
+            .map(|n| {
+                Ok(RuleData {
+                    name: n.attribute("name").map(str::to_string),
+                    presence: presence(n)?,
+                    when: when(n).unwrap_or_default(),
+                })
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        items.push(LayoutItemData {
+            required: presence(child)? == Presence::Required,
+            kind,
+            rules,
+        });
+    }
+    Ok(items)
+}
+
+fn field_iid(builder: &DictionaryBuilder, node: roxmltree::Node) -> ParseResult<InternalId> {
+    let tag = parse_attribute(node, "id")?;
+    builder
+        .symbol(KeyRef::FieldByTag(tag))
+        .copied()
+        .ok_or_else(|| ParseDictionaryError::InvalidData(format!("Unknown field {}", tag)))
+}
+
+fn response(node: roxmltree::Node) -> ParseResult<ResponseData> {
+    let mut message_refs = Vec::new();
+    let mut transition_refs = Vec::new();
+    for child in node.children().filter(|n| n.is_element()) {
+        match child.tag_name().name() {
+            "messageRef" => message_refs.push((
+                required_attribute(child, "name")?.to_string(),
+                scenario(child).to_string(),
+            )),
+            "transitionRef" => transition_refs.push(TransitionRef {
+                actor: required_attribute(child, "actor")?.to_string(),
+                state_machine: required_attribute(child, "stateMachine")?.to_string(),
+                state: required_attribute(child, "state")?.to_string(),
+                transition: child
+                    .attribute("transition")
+                    .or_else(|| child.attribute("name"))
+                    .unwrap_or_default()
+                    .to_string(),
+            }),
+            _ => {}
+        }
+    }
+    Ok(ResponseData {
+        name: node.attribute("name").map(str::to_string),
+        when: when(node),
+        message_refs,
+        transition_refs,
+    })
+}
+
+fn state_machine(actor: roxmltree::Node, node: roxmltree::Node) -> ParseResult<StateMachine> {
+    let mut initial = String::new();
+    let mut states = Vec::new();
+    for child in node.children().filter(|n| n.is_element()) {
+        if !child.has_tag_name("initial") && !child.has_tag_name("state") {
+            continue;
+        }
+        let name = required_attribute(child, "name")?.to_string();
+        if child.has_tag_name("initial") {
+            initial = name.clone();
+        }
+        let transitions = child
+            .children()
+            .filter(|n| n.has_tag_name("transition"))
+            .map(|n| {
+                Ok(Transition {
+                    name: required_attribute(n, "name")?.to_string(),
+                    target: required_attribute(n, "target")?.to_string(),
+                    when: when(n),
+                })
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        states.push(State { name, transitions });
+    }
+    Ok(StateMachine {
+        name: required_attribute(node, "name")?.to_string(),
+        actor: required_attribute(actor, "name")?.to_string(),
+        initial,
+        states,
+    })
+}
+
+fn presence(node: roxmltree::Node) -> ParseResult<Presence> {
+    Ok(match node.attribute("presence").unwrap_or("optional") {
+        "optional" => Presence::Optional,
+        "required" => Presence::Required,
+        "forbidden" => Presence::Forbidden,
+        "ignored" => Presence::Ignored,
+        "constant" => Presence::Constant,
+        s => {
+            return Err(ParseDictionaryError::InvalidData(format!(
+                "Invalid presence '{}'",
+                s
+            )))
+        }
+    })
+}
+
+fn scenario<'a>(node: roxmltree::Node<'a, '_>) -> &'a str {
+    node.attribute("scenario").unwrap_or(BASE_SCENARIO)
+}
+
+/// Conditions are either a `when` attribute or a `<when>` element.
+fn when(node: roxmltree::Node) -> Option<String> {
+    node.attribute("when")
+        .or_else(|| {
+            node.children()
+                .find(|n| n.has_tag_name("when"))
+                .and_then(|n| n.text())
+        })
+        .map(|s| s.trim().to_string())
+}
+
+fn documentation(node: roxmltree::Node) -> Option<String> {
+    node.children()
+        .find(|n| n.has_tag_name("annotation"))?
+        .children()
+        .find(|n| n.has_tag_name("documentation"))?
+        .text()
+        .map(|s| s.trim().to_string())
+}
+
+/// Returns all `name` elements within the `container` elements of `root`.
+fn entries<'a>(
+    root: roxmltree::Node<'a, 'a>,
+    container: &'static str,
+    name: &'static str,
+) -> impl Iterator<Item = roxmltree::Node<'a, 'a>> {
+    root.children()
+        .filter(move |n| n.has_tag_name(container))
+        .flat_map(|n| n.children())
+        .filter(move |n| n.has_tag_name(name))
+}
+
+pub fn required_attribute<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> ParseResult<&'a str> {
+    node.attribute(name).ok_or_else(|| {
+        ParseDictionaryError::InvalidData(format!(
+            "No {} attribute in <{}>",
+            name,
+            node.tag_name().name()
+        ))
+    })
+}
+
+fn parse_attribute<T: std::str::FromStr>(node: roxmltree::Node, name: &str) -> ParseResult<T> {
+    required_attribute(node, name)?
+        .parse()
+        .map_err(|_| ParseDictionaryError::InvalidData(format!("Invalid {} attribute", name)))
+}
+
+type ParseResult<T> = Result<T, ParseDictionaryError>;
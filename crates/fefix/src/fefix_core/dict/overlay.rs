@@ -0,0 +1,111 @@
+use super::orchestra::required_attribute;
+use super::repository::parse;
+use super::*;
+
+pub fn apply_overlay(builder: &mut DictionaryBuilder, input: &str) -> ParseResult<()> {
+    let document = parse(input)?;
+    let root = document.root_element();
+    if !root.has_tag_name("fix") {
+        return Err(ParseDictionaryError::InvalidData(
+            "<fix> tag not found".to_string(),
+        ));
+    }
+    if let Some(version) = root.attribute("version") {
+        builder.set_version(version);
+    }
+    for node in entries(root, "fields") {
+        import_field(builder, node)?;
+    }
+    // Components can reference each other in any order, so they must all
+    // exist before their layouts.
+    for node in entries(root, "components") {
+        builder.define_component(required_attribute(node, "name")?);
+    }
+    for node in entries(root, "components") {
+        import_layout(builder, required_attribute(node, "name")?, node)?;
+    }
+    for node in root.children().filter(|n| n.has_tag_name("header")) {
+        import_layout(builder, "StandardHeader", node)?;
+    }
+    for node in root.children().filter(|n| n.has_tag_name("trailer")) {
+        import_layout(builder, "StandardTrailer", node)?;
+    }
+    for node in entries(root, "messages") {
+        let name = required_attribute(node, "name")?;
+        builder
+            .define_message(
+                name,
+                required_attribute(node, "msgtype")?,
+                node.attribute("msgcat").unwrap_or("app"),
+            )
+            .map_err(invalid_data)?;
+        import_layout(builder, name, node)?;
+    }
+    Ok(())
+}
+
+fn import_field(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<()> {
+    let tag = required_attribute(node, "number")?
+        .parse()
+        .map_err(|_| ParseDictionaryError::InvalidData("Invalid number attribute".to_string()))?;
+    // Existing fields can be referenced by tag alone, e.g. to add values.
+    if let Some(quickfix_name) = node.attribute("type") {
+        let datatype = FixDatatype::from_quickfix_name(quickfix_name).ok_or_else(|| {
+            ParseDictionaryError::InvalidData(format!("Invalid type {}", quickfix_name))
+        })?;
+        builder
+            .define_field(tag, required_attribute(node, "name")?, datatype)
+            .map_err(invalid_data)?;
+    }
+    for child in node.children().filter(|n| n.has_tag_name("value")) {
+        let value = required_attribute(child, "enum")?;
+        if is_removal(child) {
+            builder.remove_field_enum(tag, value)
+        } else {
+            builder.define_field_enum(tag, value, required_attribute(child, "description")?)
+        }
+        .map_err(invalid_data)?;
+    }
+    Ok(())
+}
+
+fn import_layout(
+    builder: &mut DictionaryBuilder,
+    path: &str,
+    node: roxmltree::Node,
+) -> ParseResult<()> {
+    for child in node.children().filter(|n| n.is_element()) {
+        let name = required_attribute(child, "name")?;
+        if is_removal(child) {
+            builder.remove_layout_item(path, name)
+        } else {
+            builder.set_layout_item(path, name, child.attribute("required") == Some("Y"))
+        }
+        .map_err(invalid_data)?;
+        if child.has_tag_name("group") {
+            import_layout(builder, &format!("{}/{}", path, name), child)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns all elements within the top-level section `container`.
+fn entries<'a>(
+    root: roxmltree::Node<'a, 'a>,
+    container: &'static str,
+) -> impl Iterator<Item = roxmltree::Node<'a, 'a>> {
+    root.children()
+        .filter(move |n| n.has_tag_name(container))
+        .flat_map(|n| n.children())
+        .filter(|n| n.is_element())
+}
+
+fn is_removal(node: roxmltree::Node) -> bool {
+    node.attribute("remove") == Some("Y")
+}
+
+fn invalid_data(err: EditDictionaryError) -> ParseDictionaryError {
+    ParseDictionaryError::InvalidData(err.to_string())
+}
+
+type ParseResult<T> = Result<T, ParseDictionaryError>;
@@ -121,6 +121,7 @@ fn import_field(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> Parse
         base_category_abbr_name: None,
         base_category_id: None,
         description: None,
+        pedigree: Pedigree::default(),
     };
     Ok(builder.add_field(field))
 }
@@ -156,6 +157,9 @@ fn import_message(
         required: true,
         elaboration: None,
         description: String::new(),
+        pedigree: Pedigree::default(),
+        scenario: BASE_SCENARIO.to_string(),
+        responses: Vec::new(),
     };
     Ok(builder.add_message(message))
 }
@@ -165,6 +169,10 @@ fn import_component<S: AsRef<str>>(
     node: roxmltree::Node,
     name: S,
 ) -> ParseResult<InternalId> {
+    if let Some(iid) = builder.symbol(KeyRef::ComponentByName(name.as_ref())) {
+        // Already imported because of a previous reference.
+        return Ok(*iid);
+    }
     let mut layout_items = LayoutItems::new();
     for child in node.children() {
         if child.is_element() {
@@ -183,17 +191,25 @@ fn import_component<S: AsRef<str>>(
         category_iid: 0, // FIXME
         name: name.as_ref().to_string(),
         abbr_name: None,
+        description: None,
+        pedigree: Pedigree::default(),
+        scenario: BASE_SCENARIO.to_string(),
     };
-    let iid = builder.add_component(component);
-    match builder.symbol(KeyRef::ComponentByName(name.as_ref())) {
-        Some(x) => Ok(*x),
-        None => {
-            builder
-                .symbol_table
-                .insert(Key::ComponentByName(name.as_ref().to_string()), iid);
-            Ok(iid)
-        }
-    }
+    Ok(builder.add_component(component))
+}
+
+/// Finds the definition of the component `name` within the `<components>`
+/// section of the document of `node`.
+fn component_definition<'a>(
+    node: roxmltree::Node<'a, 'a>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'a>> {
+    node.document()
+        .root_element()
+        .children()
+        .find(|n| n.has_tag_name("components"))?
+        .children()
+        .find(|n| n.is_element() && n.attribute("name") == Some(name))
 }
 
 fn import_datatype(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> InternalId {
@@ -237,6 +253,8 @@ fn value_restrictions_from_node(
             let enum_value = FieldEnumData {
                 value: variant,
                 description,
+                documentation: None,
+                pedigree: Pedigree::default(),
             };
             values.push(enum_value);
         }
@@ -264,8 +282,16 @@ fn import_layout_item(
             LayoutItemKindData::Field { iid: *field_iid }
         }
         "component" => {
-            // Components may *not* be already present.
-            let component_iid = import_component(builder, node, name)?;
+            // Components may *not* be already present, in which case we must
+            // import their definition rather than the reference.
+            let component_iid = match builder.symbol(KeyRef::ComponentByName(name)) {
+                Some(iid) => *iid,
+                None => {
+                    let definition = component_definition(node, name)
+                        .ok_or(ParseDictionaryError::InvalidFormat)?;
+                    import_component(builder, definition, name)?
+                }
+            };
             LayoutItemKindData::Component { iid: component_iid }
         }
         "group" => {
@@ -283,7 +309,11 @@ fn import_layout_item(
             return Err(ParseDictionaryError::InvalidFormat);
         }
     };
-    let item = LayoutItemData { required, kind };
+    let item = LayoutItemData {
+        required,
+        kind,
+        rules: Vec::new(),
+    };
     Ok(item)
 }
 
@@ -300,6 +330,9 @@ fn import_category(
             builder.categories.push(CategoryData {
                 name: name.to_string(),
                 fixml_filename: String::new(),
+                section_id: None,
+                description: None,
+                pedigree: Pedigree::default(),
             });
             builder
                 .symbol_table
@@ -0,0 +1,456 @@
+use super::*;
+use std::cmp::Ordering;
+use std::io;
+use std::iter::Peekable;
+use std::path::Path;
+
+/// The contents of all "Basic" files of a FIX Repository, as distributed by
+/// the FIX Trading Community (e.g. `FIX.4.4/Base` in the 2010 edition, or
+/// `Basic` in the Unified Repository). See [`Dictionary::from_repository`].
+///
+/// Repositories for FIX 4.3 and older versions don't have abbreviations,
+/// categories, and sections.
+#[derive(Clone, Debug, Default)]
+pub struct RepositoryFiles {
+    /// Contents of `Abbreviations.xml`, if available.
+    pub abbreviations: Option<String>,
+    /// Contents of `Categories.xml`, if available.
+    pub categories: Option<String>,
+    /// Contents of `Sections.xml`, if available.
+    pub sections: Option<String>,
+    /// Contents of `Datatypes.xml`.
+    pub datatypes: String,
+    /// Contents of `Fields.xml`.
+    pub fields: String,
+    /// Contents of `Enums.xml`.
+    pub enums: String,
+    /// Contents of `Components.xml`.
+    pub components: String,
+    /// Contents of `Messages.xml`.
+    pub messages: String,
+    /// Contents of `MsgContents.xml`.
+    pub msg_contents: String,
+}
+
+impl RepositoryFiles {
+    /// Reads all FIX Repository files from the directory `dir`. Optional files
+    /// that are missing are set to `None`.
+    pub fn read_dir<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let read = |filename: &str| std::fs::read_to_string(dir.as_ref().join(filename));
+        let read_optional = |filename: &str| match read(filename) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        };
+        Ok(Self {
+            abbreviations: read_optional("Abbreviations.xml")?,
+            categories: read_optional("Categories.xml")?,
+            sections: read_optional("Sections.xml")?,
+            datatypes: read("Datatypes.xml")?,
+            fields: read("Fields.xml")?,
+            enums: read("Enums.xml")?,
+            components: read("Components.xml")?,
+            messages: read("Messages.xml")?,
+            msg_contents: read("MsgContents.xml")?,
+        })
+    }
+}
+
+pub fn read_repository(files: &RepositoryFiles) -> ParseResult<Dictionary> {
+    let fields = parse(&files.fields)?;
+    let version = fields
+        .root_element()
+        .attribute("version")
+        .ok_or_else(|| ParseDictionaryError::InvalidData("No version attribute.".to_string()))?;
+    let mut builder = DictionaryBuilder::new(version.to_string());
+    if let Some(sections) = &files.sections {
+        for node in entries(&parse(sections)?, "Section") {
+            import_section(&mut builder, node)?;
+        }
+    }
+    if let Some(categories) = &files.categories {
+        for node in entries(&parse(categories)?, "Category") {
+            import_category(&mut builder, node)?;
+        }
+    }
+    if let Some(abbreviations) = &files.abbreviations {
+        for node in entries(&parse(abbreviations)?, "Abbreviation") {
+            import_abbreviation(&mut builder, node)?;
+        }
+    }
+    let datatypes_doc = parse(&files.datatypes)?;
+    let datatypes = entries(&datatypes_doc, "Datatype")
+        .filter_map(|node| Some((child_text(node, "Name")?, node)))
+        .collect::<FnvHashMap<_, _>>();
+    let mut enums = FnvHashMap::<u32, Vec<FieldEnumData>>::default();
+    for node in entries(&parse(&files.enums)?, "Enum") {
+        let tag = parse_text(node, "Tag")?;
+        enums.entry(tag).or_default().push(field_enum(node)?);
+    }
+    for node in entries(&fields, "Field") {
+        import_field(&mut builder, node, &datatypes, &enums)?;
+    }
+    // Components and messages are created before their layouts because
+    // `MsgContents.xml` may reference components that are defined later on.
+    let mut containers = FnvHashMap::<u32, Container>::default();
+    for node in entries(&parse(&files.components)?, "Component") {
+        let id = parse_text(node, "ComponentID")?;
+        let iid = import_component(&mut builder, node)?;
+        containers.insert(id, Container::Component(iid));
+    }
+    for node in entries(&parse(&files.messages)?, "Message") {
+        let id = parse_text(node, "ComponentID")?;
+        let iid = import_message(&mut builder, node)?;
+        containers.insert(id, Container::Message(iid));
+    }
+    let mut contents = FnvHashMap::<u32, Vec<Content>>::default();
+    for node in entries(&parse(&files.msg_contents)?, "MsgContent") {
+        let id = parse_text(node, "ComponentID")?;
+        contents
+            .entry(id)
+            .or_default()
+            .push(content(&builder, node)?);
+    }
+    for (id, mut items) in contents {
+        // Positions are not necessarily integers, e.g. `4.5` for items added
+        // between two existing ones.
+        items.sort_by(|a, b| {
+            a.position
+                .partial_cmp(&b.position)
+                .unwrap_or(Ordering::Equal)
+        });
+        let layout_items = nest(&mut items.into_iter().peekable(), 0);
+        match containers.get(&id) {
+            Some(Container::Component(iid)) => {
+                builder.components[*iid as usize].layout_items = layout_items
+            }
+            Some(Container::Message(iid)) => {
+                builder.messages[*iid as usize].layout_items = layout_items
+            }
+            None => {
+                return Err(ParseDictionaryError::InvalidData(format!(
+                    "Unknown ComponentID {}",
+                    id
+                )))
+            }
+        }
+    }
+    Ok(builder.build())
+}
+
+enum Container {
+    Component(InternalId),
+    Message(InternalId),
+}
+
+/// A single `<MsgContent>` entry.
+struct Content {
+    position: f64,
+    indent: u32,
+    required: bool,
+    kind: LayoutItemKindData,
+}
+
+fn content(builder: &DictionaryBuilder, node: roxmltree::Node) -> ParseResult<Content> {
+    let tag_text = required_text(node, "TagText")?;
+    // `TagText` is either a field tag or a component name.
+    let kind = match tag_text.parse::<u32>() {
+        Ok(tag) => builder
+            .symbol(KeyRef::FieldByTag(tag))
+            .map(|iid| LayoutItemKindData::Field { iid: *iid }),
+        Err(_) => builder
+            .symbol(KeyRef::ComponentByName(tag_text))
+            .map(|iid| LayoutItemKindData::Component { iid: *iid }),
+    };
+    Ok(Content {
+        position: parse_text(node, "Position")?,
+        indent: parse_text(node, "Indent")?,
+        required: child_text(node, "Reqd") == Some("1"),
+        kind: kind.ok_or_else(|| {
+            ParseDictionaryError::InvalidData(format!("Unknown TagText '{}'", tag_text))
+        })?,
+    })
+}
+
+/// Builds the layout items at `indent` or deeper. FIX Repositories don't
+/// have explicit repeating groups: a `NumInGroup` field is instead followed
+/// by its entry items, which have a greater indentation.
+fn nest<I>(contents: &mut Peekable<I>, indent: u32) -> LayoutItems
+where
+    I: Iterator<Item = Content>,
+{
+    let mut items = LayoutItems::new();
+    while let Some(content) = contents.next_if(|c| c.indent >= indent) {
+        let is_group = contents
+            .peek()
+            .is_some_and(|next| next.indent > content.indent);
+        let kind = match content.kind {
+            LayoutItemKindData::Field { iid } if is_group => LayoutItemKindData::Group {
+                len_field_iid: iid,
+                items: nest(contents, content.indent + 1),
+            },
+            kind => kind,
+        };
+        items.push(LayoutItemData {
+            required: content.required,
+            kind,
+            rules: Vec::new(),
+        });
+    }
+    items
+}
+
+fn import_section(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<()> {
+    let id = required_text(node, "SectionID")?.to_string();
+    let iid = builder.sections.len() as InternalId;
+    builder
+        .symbol_table
+        .insert(Key::SectionById(id.clone()), iid);
+    builder.sections.push(SectionData {
+        id,
+        name: required_text(node, "Name")?.to_string(),
+        display_order: child_text(node, "DisplayOrder")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default(),
+        fixml_filename: child_text(node, "FIXMLFileName")
+            .unwrap_or_default()
+            .to_string(),
+        description: child_text(node, "Description")
+            .unwrap_or_default()
+            .to_string(),
+    });
+    Ok(())
+}
+
+fn import_category(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<()> {
+    let name = required_text(node, "CategoryID")?.to_string();
+    let iid = builder.categories.len() as InternalId;
+    builder
+        .symbol_table
+        .insert(Key::CategoryByName(name.clone()), iid);
+    builder.categories.push(CategoryData {
+        name,
+        fixml_filename: child_text(node, "FIXMLFileName")
+            .unwrap_or_default()
+            .to_string(),
+        section_id: child_text(node, "SectionID").map(str::to_string),
+        description: child_text(node, "Description").map(str::to_string),
+        pedigree: pedigree(node),
+    });
+    Ok(())
+}
+
+fn import_abbreviation(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<()> {
+    let term = required_text(node, "Term")?.to_string();
+    let iid = builder.abbreviations.len() as InternalId;
+    builder
+        .symbol_table
+        .insert(Key::Abbreviation(term.clone()), iid);
+    builder.abbreviations.push(AbbreviationData {
+        term,
+        abbreviation: required_text(node, "AbbrTerm")?.to_string(),
+        usage: child_text(node, "Usage").map(str::to_string),
+        pedigree: pedigree(node),
+    });
+    Ok(())
+}
+
+fn import_field(
+    builder: &mut DictionaryBuilder,
+    node: roxmltree::Node,
+    datatypes: &FnvHashMap<&str, roxmltree::Node>,
+    enums: &FnvHashMap<u32, Vec<FieldEnumData>>,
+) -> ParseResult<InternalId> {
+    let tag = parse_text(node, "Tag")?;
+    let data_type_iid = import_datatype(builder, required_text(node, "Type")?, datatypes);
+    // Some fields share the code set of another field.
+    let enum_tag = match child_text(node, "EnumDatatype") {
+        Some(s) => s.parse().map_err(|_| ParseDictionaryError::InvalidFormat)?,
+        None => tag,
+    };
+    let base_category_id = child_text(node, "BaseCategory")
+        .and_then(|name| builder.symbol(KeyRef::CategoryByName(name)))
+        .map(|iid| *iid as usize);
+    let field = FieldData {
+        name: required_text(node, "Name")?.to_string(),
+        tag,
+        data_type_iid,
+        associated_data_tag: child_text(node, "AssociatedDataTag").and_then(|s| s.parse().ok()),
+        value_restrictions: enums.get(&enum_tag).cloned(),
+        abbr_name: child_text(node, "AbbrName").map(str::to_string),
+        base_category_id,
+        base_category_abbr_name: child_text(node, "BaseCategoryAbbrName").map(str::to_string),
+        required: child_text(node, "NotReqXML") != Some("1"),
+        description: child_text(node, "Description").map(str::to_string),
+        pedigree: pedigree(node),
+    };
+    Ok(builder.add_field(field))
+}
+
+fn field_enum(node: roxmltree::Node) -> ParseResult<FieldEnumData> {
+    let value = required_text(node, "Value")?.to_string();
+    let documentation = child_text(node, "Description").map(str::to_string);
+    let description = child_text(node, "SymbolicName")
+        .map(str::to_string)
+        .or_else(|| documentation.clone())
+        .unwrap_or_else(|| value.clone());
+    Ok(FieldEnumData {
+        value,
+        description,
+        documentation,
+        pedigree: pedigree(node),
+    })
+}
+
+fn import_datatype(
+    builder: &mut DictionaryBuilder,
+    name: &str,
+    datatypes: &FnvHashMap<&str, roxmltree::Node>,
+) -> InternalId {
+    let datatype = resolve_datatype(name, &|name| {
+        datatypes
+            .get(name)
+            .and_then(|node| child_text(*node, "BaseType"))
+    });
+    // Get the official name of `datatype`, which might not be `name`.
+    let definition = datatypes.get(datatype.name());
+    let description = definition
+        .and_then(|node| child_text(*node, "Description"))
+        .unwrap_or_default();
+    let examples = definition
+        .into_iter()
+        .flat_map(|node| node.children())
+        .filter(|n| n.has_tag_name("Example"))
+        .filter_map(|n| n.text())
+        .map(str::to_string)
+        .collect();
+    builder.add_datatype(datatype, description, examples)
+}
+
+/// Datatypes with no [`FixDatatype`] equivalent (e.g. `Tenor`, `XID`)
+/// resolve to the [`FixDatatype`] of their base type, as given by
+/// `base_type`.
+pub fn resolve_datatype<'a>(
+    name: &'a str,
+    base_type: &dyn Fn(&'a str) -> Option<&'a str>,
+) -> FixDatatype {
+    FixDatatype::iter_all()
+        .find(|datatype| datatype.name().eq_ignore_ascii_case(name))
+        .or_else(|| FixDatatype::from_quickfix_name(name))
+        .or_else(|| {
+            base_type(name)
+                .filter(|base_type| *base_type != name)
+                .map(|name| resolve_datatype(name, base_type))
+        })
+        .unwrap_or(FixDatatype::String)
+}
+
+fn import_component(
+    builder: &mut DictionaryBuilder,
+    node: roxmltree::Node,
+) -> ParseResult<InternalId> {
+    let component_type = match required_text(node, "ComponentType")? {
+        "XMLDataBlock" => FixmlComponentAttributes::Xml,
+        "Message" => FixmlComponentAttributes::Message,
+        s => FixmlComponentAttributes::Block {
+            is_repeating: s.ends_with("Repeating"),
+            is_implicit: s.starts_with("Implicit"),
+            is_optimized: false,
+        },
+    };
+    let component = ComponentData {
+        id: parse_text(node, "ComponentID")?,
+        component_type,
+        layout_items: LayoutItems::new(),
+        category_iid: category_iid(builder, node)?,
+        name: required_text(node, "Name")?.to_string(),
+        abbr_name: child_text(node, "AbbrName").map(str::to_string),
+        description: child_text(node, "Description").map(str::to_string),
+        pedigree: pedigree(node),
+        scenario: BASE_SCENARIO.to_string(),
+    };
+    Ok(builder.add_component(component))
+}
+
+fn import_message(
+    builder: &mut DictionaryBuilder,
+    node: roxmltree::Node,
+) -> ParseResult<InternalId> {
+    let message = MessageData {
+        component_id: parse_text(node, "ComponentID")?,
+        msg_type: required_text(node, "MsgType")?.to_string(),
+        name: required_text(node, "Name")?.to_string(),
+        category_iid: category_iid(builder, node)?,
+        section_id: child_text(node, "SectionID")
+            .unwrap_or_default()
+            .to_string(),
+        layout_items: LayoutItems::new(),
+        abbr_name: child_text(node, "AbbrName").map(str::to_string),
+        required: child_text(node, "NotReqXML") != Some("1"),
+        description: child_text(node, "Description")
+            .unwrap_or_default()
+            .to_string(),
+        elaboration: child_text(node, "Elaboration").map(str::to_string),
+        pedigree: pedigree(node),
+        scenario: BASE_SCENARIO.to_string(),
+        responses: Vec::new(),
+    };
+    Ok(builder.add_message(message))
+}
+
+fn category_iid(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> ParseResult<InternalId> {
+    // Old FIX versions have no `Categories.xml`, so categories might be
+    // missing.
+    Ok(builder.category_iid(required_text(node, "CategoryID")?))
+}
+
+pub fn pedigree(node: roxmltree::Node) -> Pedigree {
+    let version = |name: &str| node.attribute(name).map(str::to_string);
+    let ep = |name: &str| node.attribute(name).and_then(|s| s.parse().ok());
+    Pedigree {
+        added: version("added"),
+        added_ep: ep("addedEP"),
+        updated: version("updated"),
+        updated_ep: ep("updatedEP"),
+        deprecated: version("deprecated"),
+        deprecated_ep: ep("deprecatedEP"),
+    }
+}
+
+pub fn parse(input: &str) -> ParseResult<roxmltree::Document> {
+    roxmltree::Document::parse(input).map_err(|_| ParseDictionaryError::InvalidFormat)
+}
+
+/// Returns all elements named `name` at the top level of `document`.
+fn entries<'a>(
+    document: &'a roxmltree::Document<'a>,
+    name: &'static str,
+) -> impl Iterator<Item = roxmltree::Node<'a, 'a>> {
+    document
+        .root_element()
+        .children()
+        .filter(move |n| n.has_tag_name(name))
+}
+
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children().find(|n| n.has_tag_name(name))?.text()
+}
+
+fn required_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> ParseResult<&'a str> {
+    child_text(node, name).ok_or_else(|| {
+        ParseDictionaryError::InvalidData(format!(
+            "<{}> not found in <{}>",
+            name,
+            node.tag_name().name()
+        ))
+    })
+}
+
+fn parse_text<T: std::str::FromStr>(node: roxmltree::Node, name: &str) -> ParseResult<T> {
+    required_text(node, name)?
+        .trim()
+        .parse()
+        .map_err(|_| ParseDictionaryError::InvalidData(format!("Invalid <{}>", name)))
+}
+
+type ParseResult<T> = Result<T, ParseDictionaryError>;
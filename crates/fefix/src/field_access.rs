@@ -1,4 +1,7 @@
+use crate::definitions::IsTypedFieldDefinition;
+use crate::field_types::MessageEncoding;
 use crate::{FieldType, FieldValueError};
+use std::borrow::Cow;
 use std::iter::FusedIterator;
 use std::ops::Range;
 
@@ -101,6 +104,88 @@ pub trait FieldMap<F> {
     {
         self.fv_raw(field).map(V::deserialize_lossy).transpose()
     }
+
+    /// Like [`FieldMap::fv`], but the value type is determined by `field`
+    /// itself, e.g. [`fix44::Side`](crate::definitions::fix44::Side) for
+    /// [`fix44::SIDE`](crate::definitions::fix44::SIDE). Reading a field as
+    /// the wrong type is thus a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::prelude::*;
+    /// use fefix::tagvalue::{Config, Decoder};
+    ///
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+    /// decoder.config_mut().set_separator(b'|');
+    /// let msg = decoder
+    ///     .decode(b"8=FIX.4.4|9=15|35=D|54=2|40=1|10=097|")
+    ///     .unwrap();
+    /// assert_eq!(msg.get(fix44::SIDE), Ok(fix44::Side::Sell));
+    /// assert_eq!(msg.get(fix44::ORD_TYPE), Ok(fix44::OrdType::Market));
+    /// ```
+    ///
+    /// Fields without dictionary-defined values have no such type:
+    ///
+    /// ```compile_fail
+    /// use fefix::prelude::*;
+    /// use fefix::tagvalue::{Config, Decoder};
+    ///
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+    /// let msg = decoder.decode(b"8=FIX.4.4\x019=5\x0135=D\x0110=000\x01").unwrap();
+    /// msg.get(fix44::SYMBOL);
+    /// ```
+    #[inline]
+    fn get<'a>(
+        &'a self,
+        field: F,
+    ) -> Result<F::Value, FieldValueError<<F::Value as FieldType<'a>>::Error>>
+    where
+        F: IsTypedFieldDefinition,
+    {
+        self.fv(field)
+    }
+
+    /// Like [`FieldMap::get`], but doesn't return an [`Err`] if `field` is
+    /// missing.
+    #[inline]
+    fn get_opt<'a>(
+        &'a self,
+        field: F,
+    ) -> Result<Option<F::Value>, <F::Value as FieldType<'a>>::Error>
+    where
+        F: IsTypedFieldDefinition,
+    {
+        self.fv_opt(field)
+    }
+
+    /// Returns the [`MessageEncoding`] of the text fields within `self`, as
+    /// specified by `MessageEncoding <347>` in the message header. Defaults
+    /// to [`MessageEncoding::Iso8859_1`], and fails if `MessageEncoding
+    /// <347>` is not supported.
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        Ok(MessageEncoding::default())
+    }
+
+    /// Looks for a `field` within `self` and then decodes its raw byte
+    /// contents as text according to [`FieldMap::message_encoding`], e.g.
+    /// Shift_JIS for `EncodedText <355>`.
+    #[inline]
+    fn fv_text(&self, field: F) -> Result<Cow<'_, str>, FieldValueError<&'static str>> {
+        self.fv_text_opt(field)
+            .map_err(FieldValueError::Invalid)
+            .and_then(|opt| opt.ok_or(FieldValueError::Missing))
+    }
+
+    /// Like [`FieldMap::fv_text`], but doesn't return an [`Err`] if `field`
+    /// is missing.
+    #[inline]
+    fn fv_text_opt(&self, field: F) -> Result<Option<Cow<'_, str>>, &'static str> {
+        let encoding = self.message_encoding()?;
+        self.fv_raw(field)
+            .map(|bytes| encoding.decode(bytes))
+            .transpose()
+    }
 }
 
 /// Provides access to entries within a FIX repeating group.
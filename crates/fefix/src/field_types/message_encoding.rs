@@ -0,0 +1,242 @@
+use super::jis0208::{JIS0208, JIS0208_BY_CODE_POINT};
+use crate::{Buffer, FieldType};
+use std::borrow::Cow;
+
+const ERR_MESSAGE_ENCODING: &str = "Unsupported MessageEncoding <347>.";
+const ERR_INVALID_TEXT: &str = "Invalid byte sequence for the message encoding.";
+const ERR_UNMAPPABLE_TEXT: &str = "Text can't be represented in the message encoding.";
+
+// Half-width katakana, i.e. JIS X 0201 (`0xA1..=0xDF`), start at `U+FF61`.
+const HALF_WIDTH_KATAKANA: std::ops::RangeInclusive<u32> = 0xFF61..=0xFF9F;
+const HALF_WIDTH_KATAKANA_OFFSET: u32 = 0xFF61 - 0xA1;
+
+/// The character encoding of a FIX message, as specified by `MessageEncoding
+/// <347>`.
+///
+/// FIX mandates ISO-8859-1 (i.e. Latin alphabet No. 1) for text fields by
+/// default, but `MessageEncoding <347>` allows venues to use other encodings,
+/// e.g. for Japanese `EncodedText <355>`. [`MessageEncoding`] converts
+/// between such encodings and Rust strings.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::field_types::MessageEncoding;
+/// use fefix::FieldType;
+///
+/// let encoding = MessageEncoding::deserialize(b"Shift_JIS").unwrap();
+/// assert_eq!(encoding, MessageEncoding::ShiftJis);
+/// let bytes = encoding.encode("東京").unwrap();
+/// assert_eq!(&bytes[..], b"\x93\x8c\x8b\x9e");
+/// assert_eq!(encoding.decode(&bytes).unwrap(), "東京");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum MessageEncoding {
+    /// ISO-8859-1, the default encoding of FIX text fields.
+    #[default]
+    Iso8859_1,
+    /// UTF-8.
+    Utf8,
+    /// Shift_JIS, i.e. JIS X 0201 and JIS X 0208.
+    ShiftJis,
+    /// EUC-JP, i.e. JIS X 0201 and JIS X 0208. JIS X 0212 is not supported.
+    EucJp,
+}
+
+impl MessageEncoding {
+    /// Returns the `MessageEncoding <347>` value of `self`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Iso8859_1 => "ISO-8859-1",
+            Self::Utf8 => "UTF-8",
+            Self::ShiftJis => "Shift_JIS",
+            Self::EucJp => "EUC-JP",
+        }
+    }
+
+    /// Decodes `bytes` from `self` into a Rust string. Only UTF-8 and ASCII
+    /// text can be borrowed.
+    pub fn decode<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, str>, &'static str> {
+        if *self == Self::Utf8 || bytes.is_ascii() {
+            return std::str::from_utf8(bytes)
+                .map(Cow::Borrowed)
+                .map_err(|_| ERR_INVALID_TEXT);
+        }
+        let mut text = String::with_capacity(bytes.len());
+        let mut iter = bytes.iter().copied();
+        while let Some(byte) = iter.next() {
+            let c = match (self, byte) {
+                (_, 0..=0x7F) => Some(byte as u32),
+                (Self::Iso8859_1, _) => Some(byte as u32),
+                (Self::ShiftJis, 0xA1..=0xDF) => Some(byte as u32 + HALF_WIDTH_KATAKANA_OFFSET),
+                (Self::ShiftJis, 0x81..=0x9F | 0xE0..=0xEF) => {
+                    let lead_offset = if byte < 0xA0 { 0x81 } else { 0xC1 };
+                    iter.next().and_then(|trail| {
+                        let trail_offset = match trail {
+                            0x40..=0x7E => 0x40,
+                            0x80..=0xFC => 0x41,
+                            _ => return None,
+                        };
+                        let i =
+                            (byte - lead_offset) as usize * 188 + (trail - trail_offset) as usize;
+                        jis0208(i)
+                    })
+                }
+                (Self::EucJp, 0x8E) => iter
+                    .next()
+                    .filter(|trail| (0xA1..=0xDF).contains(trail))
+                    .map(|trail| trail as u32 + HALF_WIDTH_KATAKANA_OFFSET),
+                (Self::EucJp, 0xA1..=0xFE) => iter
+                    .next()
+                    .filter(|trail| (0xA1..=0xFE).contains(trail))
+                    .and_then(|trail| {
+                        jis0208((byte - 0xA1) as usize * 94 + (trail - 0xA1) as usize)
+                    }),
+                _ => None,
+            };
+            text.push(c.and_then(char::from_u32).ok_or(ERR_INVALID_TEXT)?);
+        }
+        Ok(Cow::Owned(text))
+    }
+
+    /// Encodes `text` into `self`. Fails if `text` contains characters that
+    /// can't be represented by `self`.
+    pub fn encode<'a>(&self, text: &'a str) -> Result<Cow<'a, [u8]>, &'static str> {
+        if *self == Self::Utf8 || text.is_ascii() {
+            return Ok(Cow::Borrowed(text.as_bytes()));
+        }
+        let mut bytes = Vec::with_capacity(text.len());
+        for c in text.chars() {
+            let c = c as u32;
+            match self {
+                _ if c <= 0x7F => bytes.push(c as u8),
+                Self::Iso8859_1 if c <= 0xFF => bytes.push(c as u8),
+                Self::ShiftJis if HALF_WIDTH_KATAKANA.contains(&c) => {
+                    bytes.push((c - HALF_WIDTH_KATAKANA_OFFSET) as u8)
+                }
+                Self::EucJp if HALF_WIDTH_KATAKANA.contains(&c) => {
+                    bytes.extend_from_slice(&[0x8E, (c - HALF_WIDTH_KATAKANA_OFFSET) as u8])
+                }
+                Self::ShiftJis => {
+                    let i = jis0208_index(c).ok_or(ERR_UNMAPPABLE_TEXT)?;
+                    let (lead, trail) = ((i / 188) as u8, (i % 188) as u8);
+                    let lead_offset = if lead < 0x1F { 0x81 } else { 0xC1 };
+                    let trail_offset = if trail < 0x3F { 0x40 } else { 0x41 };
+                    bytes.extend_from_slice(&[lead + lead_offset, trail + trail_offset]);
+                }
+                Self::EucJp => {
+                    let i = jis0208_index(c).ok_or(ERR_UNMAPPABLE_TEXT)?;
+                    bytes.extend_from_slice(&[(i / 94) as u8 + 0xA1, (i % 94) as u8 + 0xA1]);
+                }
+                _ => return Err(ERR_UNMAPPABLE_TEXT),
+            }
+        }
+        Ok(Cow::Owned(bytes))
+    }
+}
+
+fn jis0208(i: usize) -> Option<u32> {
+    JIS0208.get(i).copied().filter(|c| *c != 0).map(u32::from)
+}
+
+fn jis0208_index(c: u32) -> Option<u16> {
+    let c = u16::try_from(c).ok()?;
+    JIS0208_BY_CODE_POINT
+        .binary_search_by_key(&c, |(code_point, _)| *code_point)
+        .ok()
+        .map(|i| JIS0208_BY_CODE_POINT[i].1)
+}
+
+impl<'a> FieldType<'a> for MessageEncoding {
+    type Error = &'static str;
+    type SerializeSettings = ();
+
+    fn serialize_with<B>(&self, buffer: &mut B, _settings: ()) -> usize
+    where
+        B: Buffer,
+    {
+        buffer.extend_from_slice(self.as_str().as_bytes());
+        self.as_str().len()
+    }
+
+    fn deserialize(data: &'a [u8]) -> Result<Self, Self::Error> {
+        // Spellings differ between FIX versions, e.g. `SHIFT_JIS` in FIXT.1.1.
+        [Self::Iso8859_1, Self::Utf8, Self::ShiftJis, Self::EucJp]
+            .iter()
+            .copied()
+            .find(|encoding| encoding.as_str().as_bytes().eq_ignore_ascii_case(data))
+            .ok_or(ERR_MESSAGE_ENCODING)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    const ENCODINGS: &[MessageEncoding] = &[
+        MessageEncoding::Iso8859_1,
+        MessageEncoding::Utf8,
+        MessageEncoding::ShiftJis,
+        MessageEncoding::EucJp,
+    ];
+
+    #[test]
+    fn japanese_text() {
+        let text = "株式会社ｶﾌﾞｼｷ、ＡＢＣ";
+        let shift_jis =
+            b"\x8a\x94\x8e\xae\x89\xef\x8e\xd0\xb6\xcc\xde\xbc\xb7\x81\x41\x82\x60\x82\x61\x82\x62";
+        let euc_jp = b"\xb3\xf4\xbc\xb0\xb2\xf1\xbc\xd2\x8e\xb6\x8e\xcc\x8e\xde\x8e\xbc\x8e\xb7\xa1\xa2\xa3\xc1\xa3\xc2\xa3\xc3";
+        assert_eq!(
+            &MessageEncoding::ShiftJis.encode(text).unwrap()[..],
+            shift_jis
+        );
+        assert_eq!(MessageEncoding::ShiftJis.decode(shift_jis).unwrap(), text);
+        assert_eq!(&MessageEncoding::EucJp.encode(text).unwrap()[..], euc_jp);
+        assert_eq!(MessageEncoding::EucJp.decode(euc_jp).unwrap(), text);
+    }
+
+    #[test]
+    fn latin1_text() {
+        let encoding = MessageEncoding::Iso8859_1;
+        assert_eq!(&encoding.encode("Zürich").unwrap()[..], b"Z\xfcrich");
+        assert_eq!(encoding.decode(b"Z\xfcrich").unwrap(), "Zürich");
+        assert!(encoding.encode("€").is_err());
+    }
+
+    #[test]
+    fn invalid_byte_sequences() {
+        for bytes in [&b"\x82"[..], b"\x82\x20", b"\xf0\x40", b"\x85\x40"] {
+            assert!(MessageEncoding::ShiftJis.decode(bytes).is_err());
+        }
+        for bytes in [&b"\xa4"[..], b"\x8e\xa0", b"\x8f\xa1\xa1"] {
+            assert!(MessageEncoding::EucJp.decode(bytes).is_err());
+        }
+        assert!(MessageEncoding::Utf8.decode(b"\xff").is_err());
+    }
+
+    #[test]
+    fn message_encoding_values() {
+        for encoding in ENCODINGS {
+            assert_eq!(
+                MessageEncoding::deserialize(&encoding.to_bytes()),
+                Ok(*encoding)
+            );
+        }
+        assert_eq!(
+            MessageEncoding::deserialize(b"SHIFT_JIS"),
+            Ok(MessageEncoding::ShiftJis)
+        );
+        assert!(MessageEncoding::deserialize(b"ISO-2022-JP").is_err());
+    }
+
+    #[quickcheck]
+    fn encoded_text_round_trips(text: String) -> bool {
+        ENCODINGS
+            .iter()
+            .all(|encoding| match encoding.encode(&text) {
+                Ok(bytes) => encoding.decode(&bytes).ok().as_deref() == Some(text.as_str()),
+                Err(_) => true,
+            })
+    }
+}
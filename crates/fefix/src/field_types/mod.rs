@@ -55,9 +55,14 @@
 //! [^1]: With the exception of datatype `data`, FIX mandates a single-byte
 //! encoding (Latin alphabet No. 1 by default), while Rust strings are UTF-8,
 //! which is a multibyte encoding. These are *not* compatible. Watch out!
+//! [`FieldMap::fv_text`](crate::FieldMap::fv_text) and
+//! [`SetField::set_text`](crate::SetField::set_text) convert text according
+//! to [`MessageEncoding`].
 
 mod checksum;
 mod date;
+mod jis0208;
+mod message_encoding;
 mod monthyear;
 mod multiple_chars;
 mod multiple_strings;
@@ -78,10 +83,11 @@ mod utils_rust_decimal;
 
 pub use checksum::CheckSum;
 pub use date::Date;
+pub use message_encoding::MessageEncoding;
 pub use monthyear::MonthYear;
 pub use multiple_chars::MultipleChars;
 pub use multiple_strings::MultipleStrings;
-pub use time::Time;
+pub use time::{Time, TimePrecision};
 pub use timestamp::Timestamp;
 pub use tz::Tz;
 pub use tz_time::TzTime;
@@ -102,6 +108,36 @@ pub(crate) const ERR_INT_INVALID: &str = "Invalid integer digits.";
 pub(crate) const ERR_TIME: &str = "Invalid time.";
 pub(crate) const ERR_DECIMAL: &str = "Invalid decimal number.";
 
+/// Implements [`serde::Serialize`] and [`serde::Deserialize`] on top of the
+/// FIX representation of `$ty`, e.g. `"20210510-12:30:00.000"`.
+macro_rules! impl_serde_via_field_type {
+    ($ty:ty) => {
+        impl serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&FieldType::to_string(self))
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                <$ty as FieldType>::deserialize(s.as_bytes()).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+impl_serde_via_field_type!(Date);
+impl_serde_via_field_type!(MonthYear);
+impl_serde_via_field_type!(Time);
+impl_serde_via_field_type!(Timestamp);
+
 /// Zero-padding for integers; see [`FieldType::SerializeSettings`].
 #[derive(Debug, Copy, Clone, Default)]
 pub struct ZeroPadding(pub usize);
@@ -123,6 +159,7 @@ where
 
 #[cfg(test)]
 mod test {
+    use super::{Date, Timestamp};
     use crate::FieldType;
     use quickcheck_macros::quickcheck;
 
@@ -159,6 +196,15 @@ mod test {
         assert_eq!(&buffer[..], b"IT" as &[u8]);
     }
 
+    #[test]
+    fn serde_uses_fix_representation() {
+        let timestamp = Timestamp::deserialize(b"20210510-12:30:00.123456").unwrap();
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(json, r#""20210510-12:30:00.123456""#);
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), timestamp);
+        assert!(serde_json::from_str::<Date>(r#""2021-05-10""#).is_err());
+    }
+
     #[test]
     fn serialize_currency() {
         let mut buffer = Vec::new();
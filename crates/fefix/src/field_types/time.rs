@@ -2,18 +2,55 @@ use crate::{Buffer, FieldType};
 
 const ERR_INVALID: &str = "Invalid time.";
 
-const LEN_IN_BYTES_NO_MILLI: usize = 8;
+const LEN_IN_BYTES_NO_FRACTION: usize = 8;
 const LEN_IN_BYTES_WITH_MILLI: usize = 12;
 
 const MAX_HOUR: u32 = 23;
 const MAX_MINUTE: u32 = 59;
 const MAX_SECOND: u32 = 60; // Leap seconds.
-const MAX_MILLISECOND: u32 = 999;
+const MAX_NANOSECOND: u32 = 999_999_999;
 
 const MIN_HOUR: u32 = 0;
 const MIN_MINUTE: u32 = 0;
 const MIN_SECOND: u32 = 0;
-const MIN_MILLISECOND: u32 = 0;
+const MIN_NANOSECOND: u32 = 0;
+
+const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
+
+/// The number of fractional digits of serialized seconds; see
+/// [`FieldType::SerializeSettings`].
+///
+/// [`TimePrecision::Auto`] is the [`Default`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum TimePrecision {
+    /// As many digits as needed to represent the value without loss of
+    /// precision, but no less than milliseconds.
+    #[default]
+    Auto,
+    /// No fractional digits, e.g. `12:30:00`.
+    Seconds,
+    /// Three fractional digits, e.g. `12:30:00.123`.
+    Millis,
+    /// Six fractional digits, e.g. `12:30:00.123456`.
+    Micros,
+    /// Nine fractional digits, e.g. `12:30:00.123456789`.
+    Nanos,
+}
+
+impl TimePrecision {
+    fn num_digits(self, nano: u32) -> usize {
+        match self {
+            Self::Auto if nano.is_multiple_of(NANOS_PER_MILLI) => 3,
+            Self::Auto if nano.is_multiple_of(NANOS_PER_MICRO) => 6,
+            Self::Auto => 9,
+            Self::Seconds => 0,
+            Self::Millis => 3,
+            Self::Micros => 6,
+            Self::Nanos => 9,
+        }
+    }
+}
 
 /// Canonical data field (DTF) for
 /// [`FixDatatype::UtcTimeOnly`](crate::dict::FixDatatype::UtcTimeOnly).
@@ -22,32 +59,60 @@ pub struct Time {
     hour: u32,
     minute: u32,
     second: u32,
-    milli: u32,
+    nano: u32,
 }
 
 impl Time {
     /// Creates a new time value from its components, with milliseconds.
     pub fn from_hmsm(hour: u32, minute: u32, second: u32, milli: u32) -> Option<Self> {
+        if milli > MAX_NANOSECOND / NANOS_PER_MILLI {
+            return None;
+        }
+        Self::from_hms_nano(hour, minute, second, milli * NANOS_PER_MILLI)
+    }
+
+    /// Creates a new time value from its components, with microseconds.
+    pub fn from_hms_micro(hour: u32, minute: u32, second: u32, micro: u32) -> Option<Self> {
+        if micro > MAX_NANOSECOND / NANOS_PER_MICRO {
+            return None;
+        }
+        Self::from_hms_nano(hour, minute, second, micro * NANOS_PER_MICRO)
+    }
+
+    /// Creates a new time value from its components, with nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::prelude::*;
+    /// use fefix::field_types::{Time, TimePrecision};
+    ///
+    /// let time = Time::from_hms_nano(12, 30, 0, 123_456_789).unwrap();
+    /// assert_eq!(time.to_string(), "12:30:00.123456789");
+    ///
+    /// let mut buffer = Vec::new();
+    /// time.serialize_with(&mut buffer, TimePrecision::Micros);
+    /// assert_eq!(&buffer[..], b"12:30:00.123456");
+    /// ```
+    pub fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
         if (MIN_HOUR..=MAX_HOUR).contains(&hour)
-            && minute >= MIN_MINUTE
-            && minute <= MAX_MINUTE
-            && second >= MIN_SECOND
-            && second <= MAX_SECOND
-            && milli >= MIN_MILLISECOND
-            && milli <= MAX_MILLISECOND
+            && (MIN_MINUTE..=MAX_MINUTE).contains(&minute)
+            && (MIN_SECOND..=MAX_SECOND).contains(&second)
+            && (MIN_NANOSECOND..=MAX_NANOSECOND).contains(&nano)
         {
             Some(Self {
                 hour,
                 minute,
                 second,
-                milli,
+                nano,
             })
         } else {
             None
         }
     }
 
-    /// Encodes `self` as a FIX field value in byte array.
+    /// Encodes `self` as a FIX field value in byte array, with millisecond
+    /// precision. Use [`FieldType::serialize_with`] for other precisions.
     pub const fn to_bytes(&self) -> [u8; LEN_IN_BYTES_WITH_MILLI] {
         [
             (self.hour() / 10) as u8 + b'0',
@@ -120,7 +185,7 @@ impl Time {
         self.second
     }
 
-    /// Returns the milliecond of `self`.
+    /// Returns the millisecond of `self`.
     ///
     /// # Examples
     ///
@@ -132,7 +197,37 @@ impl Time {
     /// assert_eq!(dtf.milli(), 328)
     /// ```
     pub const fn milli(&self) -> u32 {
-        self.milli
+        self.nano / NANOS_PER_MILLI
+    }
+
+    /// Returns the microsecond of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::prelude::*;
+    /// use fefix::field_types::Time;
+    ///
+    /// let dtf = Time::deserialize(b"12:45:00.328").unwrap();
+    /// assert_eq!(dtf.micro(), 328_000)
+    /// ```
+    pub const fn micro(&self) -> u32 {
+        self.nano / NANOS_PER_MICRO
+    }
+
+    /// Returns the nanosecond of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::prelude::*;
+    /// use fefix::field_types::Time;
+    ///
+    /// let dtf = Time::deserialize(b"12:45:00.328123456").unwrap();
+    /// assert_eq!(dtf.nano(), 328_123_456)
+    /// ```
+    pub const fn nano(&self) -> u32 {
+        self.nano
     }
 
     /// Converts `self` to a [`chrono::NaiveTime`]. `chrono` might perform
@@ -141,38 +236,49 @@ impl Time {
     #[cfg(feature = "utils-chrono")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "utils-chrono")))]
     pub fn to_chrono_naive(&self) -> Option<chrono::NaiveTime> {
-        chrono::NaiveTime::from_hms_milli_opt(
-            self.hour(),
-            self.minute(),
-            self.second(),
-            self.milli(),
-        )
+        chrono::NaiveTime::from_hms_nano_opt(self.hour(), self.minute(), self.second(), self.nano())
     }
 }
 
 impl<'a> FieldType<'a> for Time {
     type Error = &'static str;
-    type SerializeSettings = ();
+    type SerializeSettings = TimePrecision;
 
-    fn serialize_with<B>(&self, buffer: &mut B, _settings: ()) -> usize
+    fn serialize_with<B>(&self, buffer: &mut B, precision: TimePrecision) -> usize
     where
         B: Buffer,
     {
         let bytes = self.to_bytes();
-        buffer.extend_from_slice(&bytes[..]);
-        bytes.len()
+        buffer.extend_from_slice(&bytes[..LEN_IN_BYTES_NO_FRACTION]);
+        let num_digits = precision.num_digits(self.nano());
+        if num_digits == 0 {
+            return LEN_IN_BYTES_NO_FRACTION;
+        }
+        let mut fraction = [b'.', 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut nano = self.nano();
+        for digit in fraction[1..].iter_mut().rev() {
+            *digit = (nano % 10) as u8 + b'0';
+            nano /= 10;
+        }
+        buffer.extend_from_slice(&fraction[..=num_digits]);
+        LEN_IN_BYTES_NO_FRACTION + 1 + num_digits
     }
 
     fn deserialize(data: &'a [u8]) -> Result<Self, Self::Error> {
-        let mut milli = 0;
-        if data.len() == LEN_IN_BYTES_WITH_MILLI {
-            milli = ascii_digit_to_u32(data[9], 100)
-                + ascii_digit_to_u32(data[10], 10)
-                + ascii_digit_to_u32(data[11], 1);
-            if data[8] != b'.' {
+        let mut nano = 0;
+        if data.len() > LEN_IN_BYTES_NO_FRACTION {
+            let digits = &data[LEN_IN_BYTES_NO_FRACTION + 1..];
+            if data[LEN_IN_BYTES_NO_FRACTION] != b'.'
+                || !matches!(digits.len(), 3 | 6 | 9)
+                || !digits.iter().all(|byte| is_ascii_digit(*byte))
+            {
                 return Err(ERR_INVALID);
             }
-        } else if data.len() != LEN_IN_BYTES_NO_MILLI {
+            for digit in digits {
+                nano = nano * 10 + ascii_digit_to_u32(*digit, 1);
+            }
+            nano *= 10u32.pow(9 - digits.len() as u32);
+        } else if data.len() != LEN_IN_BYTES_NO_FRACTION {
             return Err(ERR_INVALID);
         }
         let digits_are_ok = data[2] == b':'
@@ -189,7 +295,7 @@ impl<'a> FieldType<'a> for Time {
         let hour = ascii_digit_to_u32(data[0], 10) + ascii_digit_to_u32(data[1], 1);
         let minute = ascii_digit_to_u32(data[3], 10) + ascii_digit_to_u32(data[4], 1);
         let second = ascii_digit_to_u32(data[6], 10) + ascii_digit_to_u32(data[7], 1);
-        Self::from_hmsm(hour, minute, second, milli).ok_or(ERR_INVALID)
+        Self::from_hms_nano(hour, minute, second, nano).ok_or(ERR_INVALID)
     }
 }
 
@@ -212,12 +318,13 @@ mod test {
             let hour = u32::arbitrary(g) % 24;
             let minute = u32::arbitrary(g) % 60;
             let second = u32::arbitrary(g) % 60;
-            let millisecond = if bool::arbitrary(g) {
-                format!(".{:03}", u32::arbitrary(g) % 1000)
-            } else {
-                String::new()
+            let fraction = match u8::arbitrary(g) % 4 {
+                0 => String::new(),
+                1 => format!(".{:03}", u32::arbitrary(g) % 1000),
+                2 => format!(".{:06}", u32::arbitrary(g) % 1_000_000),
+                _ => format!(".{:09}", u32::arbitrary(g) % 1_000_000_000),
             };
-            let s = format!("{:02}:{:02}:{:02}{}", hour, minute, second, millisecond);
+            let s = format!("{:02}:{:02}:{:02}{}", hour, minute, second, fraction);
             Self::deserialize(s.as_bytes()).unwrap()
         }
     }
@@ -227,30 +334,26 @@ mod test {
         hour: u32,
         minute: u32,
         second: u32,
-        milli: u32,
+        nano: u32,
     }
 
     impl TestCase {
-        const fn new(
-            bytes: &'static [u8],
-            hour: u32,
-            minute: u32,
-            second: u32,
-            milli: u32,
-        ) -> Self {
+        const fn new(bytes: &'static [u8], hour: u32, minute: u32, second: u32, nano: u32) -> Self {
             Self {
                 bytes,
                 hour,
                 minute,
                 second,
-                milli,
+                nano,
             }
         }
     }
 
     const VALID_TEST_CASES: &[TestCase] = &[
         TestCase::new(b"00:00:00", 0, 0, 0, 0),
-        TestCase::new(b"00:00:00.123", 0, 0, 0, 123),
+        TestCase::new(b"00:00:00.123", 0, 0, 0, 123_000_000),
+        TestCase::new(b"00:00:00.123456", 0, 0, 0, 123_456_000),
+        TestCase::new(b"00:00:00.000000001", 0, 0, 0, 1),
         TestCase::new(b"12:00:00", 12, 0, 0, 0),
         TestCase::new(b"23:59:60", 23, 59, 60, 0),
     ];
@@ -262,7 +365,54 @@ mod test {
             assert_eq!(dtf.hour(), test_case.hour);
             assert_eq!(dtf.minute(), test_case.minute);
             assert_eq!(dtf.second(), test_case.second);
-            assert_eq!(dtf.milli(), test_case.milli);
+            assert_eq!(dtf.nano(), test_case.nano);
+        }
+    }
+
+    #[test]
+    fn invalid_fractions() {
+        for bytes in [
+            &b"00:00:00."[..],
+            b"00:00:00.1",
+            b"00:00:00.1234",
+            b"00:00:00,123",
+            b"00:00:00.12a",
+            b"00:00:00.1234567890",
+        ] {
+            assert!(Time::deserialize(bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn serialization_precision() {
+        let time = Time::from_hms_nano(9, 5, 1, 20_300_400).unwrap();
+        for (precision, expected) in [
+            (TimePrecision::Auto, &b"09:05:01.020300400"[..]),
+            (TimePrecision::Seconds, b"09:05:01"),
+            (TimePrecision::Millis, b"09:05:01.020"),
+            (TimePrecision::Micros, b"09:05:01.020300"),
+            (TimePrecision::Nanos, b"09:05:01.020300400"),
+        ] {
+            let mut buffer = Vec::new();
+            let len = time.serialize_with(&mut buffer, precision);
+            assert_eq!(&buffer[..], expected);
+            assert_eq!(len, expected.len());
+        }
+        let time = Time::from_hms_micro(9, 5, 1, 20_300).unwrap();
+        assert_eq!(time.to_string(), "09:05:01.020300");
+        let time = Time::from_hmsm(9, 5, 1, 0).unwrap();
+        assert_eq!(time.to_string(), "09:05:01.000");
+        assert!(Time::from_hmsm(9, 5, 1, 1000).is_none());
+        assert!(Time::from_hms_micro(9, 5, 1, 1_000_000).is_none());
+    }
+
+    #[cfg(feature = "utils-chrono")]
+    #[quickcheck]
+    fn chrono_conversion_is_lossless(time: Time) -> bool {
+        use chrono::Timelike;
+        match time.to_chrono_naive() {
+            Some(naive) => naive.nanosecond() == time.nano() && naive.second() == time.second(),
+            None => time.second() == 60,
         }
     }
 
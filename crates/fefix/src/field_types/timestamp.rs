@@ -1,4 +1,5 @@
-use crate::{field_types::Date, field_types::Time, Buffer, FieldType};
+use crate::field_types::{Date, Time, TimePrecision};
+use crate::{Buffer, FieldType};
 
 /// Representation for `UtcTimestamp`.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -13,9 +14,10 @@ impl Timestamp {
         Self { date, time }
     }
 
-    /// Parses from a `"YYYYMMDD"` format.
+    /// Parses from a `"YYYYMMDD-HH:MM:SS"` format, with 0, 3, 6 or 9
+    /// fractional digits.
     pub fn parse(data: &[u8]) -> Option<Self> {
-        if data.len() < 12 || data[8] != b'-' {
+        if data.len() < 17 || data[8] != b'-' {
             return None;
         }
         let date = Date::deserialize(&data[0..8]).ok()?;
@@ -23,18 +25,29 @@ impl Timestamp {
         Some(Self::new(date, time))
     }
 
-    /// Returns the current UTC system time with millisecond precision.
+    /// Returns the current UTC system time with nanosecond precision, or
+    /// whatever the system clock provides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::prelude::*;
+    /// use fefix::field_types::{TimePrecision, Timestamp};
+    ///
+    /// let mut buffer = Vec::new();
+    /// Timestamp::utc_now().serialize_with(&mut buffer, TimePrecision::Micros);
+    /// assert_eq!(buffer.len(), "YYYYMMDD-HH:MM:SS.ssssss".len());
+    /// ```
     pub fn utc_now() -> Self {
         use chrono::{Datelike, Timelike};
         let utc: chrono::DateTime<chrono::Utc> = chrono::Utc::now();
-        let date = Date::new(utc.year() as u32, utc.month() as u32, utc.day() as u32);
-        let time = Time::from_hmsm(
-            utc.hour() as u32,
-            utc.minute() as u32,
-            utc.second() as u32,
-            utc.nanosecond() as u32 / 1_000_000,
-        )
-        .unwrap();
+        let date = Date::new(utc.year() as u32, utc.month(), utc.day());
+        // `chrono` represents leap seconds as nanoseconds overflowing second 59.
+        let (second, nano) = match utc.nanosecond().checked_sub(1_000_000_000) {
+            Some(nano) => (utc.second() + 1, nano),
+            None => (utc.second(), utc.nanosecond()),
+        };
+        let time = Time::from_hms_nano(utc.hour(), utc.minute(), second, nano).unwrap();
         Self::new(date.unwrap(), time)
     }
 
@@ -72,13 +85,15 @@ impl Timestamp {
 
 impl<'a> FieldType<'a> for Timestamp {
     type Error = &'static str;
-    type SerializeSettings = ();
+    type SerializeSettings = TimePrecision;
 
-    fn serialize_with<B>(&self, buffer: &mut B, _settings: ()) -> usize
+    fn serialize_with<B>(&self, buffer: &mut B, precision: TimePrecision) -> usize
     where
         B: Buffer,
     {
-        self.date().serialize(buffer) + b"-".serialize(buffer) + self.time().serialize(buffer)
+        self.date().serialize(buffer)
+            + b"-".serialize(buffer)
+            + self.time().serialize_with(buffer, precision)
     }
 
     fn deserialize(data: &'a [u8]) -> Result<Self, Self::Error> {
@@ -105,4 +120,25 @@ mod test {
     fn verify_serialization_behavior(timestamp: Timestamp) -> bool {
         crate::field_types::test_utility_verify_serialization_behavior(timestamp)
     }
+
+    #[test]
+    fn fractional_seconds() {
+        let timestamp = Timestamp::deserialize(b"20210301-08:30:00.123456789").unwrap();
+        assert_eq!(timestamp.time().nano(), 123_456_789);
+        let mut buffer = Vec::new();
+        timestamp.serialize_with(&mut buffer, TimePrecision::Micros);
+        assert_eq!(&buffer[..], b"20210301-08:30:00.123456");
+        assert!(Timestamp::deserialize(b"20210301-08:30").is_err());
+        assert!(Timestamp::deserialize(b"20210301-08:30:00.1234").is_err());
+    }
+
+    #[cfg(feature = "utils-chrono")]
+    #[quickcheck]
+    fn chrono_conversion_is_lossless(timestamp: Timestamp) -> bool {
+        use chrono::Timelike;
+        match timestamp.to_chrono_utc() {
+            Some(utc) => utc.nanosecond() == timestamp.time().nano(),
+            None => true,
+        }
+    }
 }
@@ -1,7 +1,25 @@
-use super::{Timestamp, Tz};
+use super::{TimePrecision, Timestamp, Tz};
 use crate::{Buffer, FieldType};
 
+const ERR_INVALID: &str = "Invalid timestamp with timezone.";
+
+// `YYYYMMDD-HH:MM:SS`; the timezone indicator comes after the fractional
+// digits (if any).
+const LEN_IN_BYTES_NO_FRACTION: usize = 17;
+
 /// A time and date combination representing local time with an offset from UTC.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::FieldType;
+/// use fefix::field_types::{Tz, TzTimestamp};
+///
+/// let tz_timestamp = TzTimestamp::deserialize(b"20060901-07:39:20.123456-05").unwrap();
+/// assert_eq!(tz_timestamp.timestamp().time().micro(), 123_456);
+/// assert_eq!(tz_timestamp.timezone().offset_as_secs(), -5 * 3600);
+/// assert_eq!(tz_timestamp.to_string(), "20060901-07:39:20.123456-05");
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TzTimestamp {
     timestamp: Timestamp,
@@ -9,6 +27,11 @@ pub struct TzTimestamp {
 }
 
 impl TzTimestamp {
+    /// Combines `timestamp` and `tz` into a [`TzTimestamp`].
+    pub fn new(timestamp: Timestamp, tz: Tz) -> Self {
+        Self { timestamp, tz }
+    }
+
     /// Returns the [`Timestamp`] (without timezone information) of `self`.
     pub fn timestamp(&self) -> Timestamp {
         self.timestamp.clone()
@@ -22,16 +45,60 @@ impl TzTimestamp {
 
 impl<'a> FieldType<'a> for TzTimestamp {
     type Error = &'static str;
-    type SerializeSettings = ();
+    type SerializeSettings = TimePrecision;
 
-    fn serialize_with<B>(&self, buffer: &mut B, _settings: ()) -> usize
+    fn serialize_with<B>(&self, buffer: &mut B, precision: TimePrecision) -> usize
     where
         B: Buffer,
     {
-        self.timestamp().serialize(buffer)
+        self.timestamp.serialize_with(buffer, precision) + self.timezone().serialize(buffer)
+    }
+
+    fn deserialize(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let tz_start_i = data
+            .iter()
+            .skip(LEN_IN_BYTES_NO_FRACTION)
+            .position(|byte| matches!(byte, b'Z' | b'+' | b'-'))
+            .ok_or(ERR_INVALID)?
+            + LEN_IN_BYTES_NO_FRACTION;
+        let timestamp = Timestamp::deserialize(&data[..tz_start_i]).map_err(|_| ERR_INVALID)?;
+        let tz = Tz::deserialize(&data[tz_start_i..]).map_err(|_| ERR_INVALID)?;
+        Ok(Self::new(timestamp, tz))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck::{Arbitrary, Gen};
+    use quickcheck_macros::quickcheck;
+
+    impl Arbitrary for TzTimestamp {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let timestamp = Timestamp::arbitrary(g).to_string();
+            let tz = g
+                .choose(&["Z", "+01", "-05", "+05:30", "-11:45"])
+                .copied()
+                .unwrap_or("Z");
+            let s = format!("{}{}", timestamp, tz);
+            Self::deserialize(s.as_bytes()).unwrap()
+        }
+    }
+
+    #[quickcheck]
+    fn verify_serialization_behavior(tz_timestamp: TzTimestamp) -> bool {
+        crate::field_types::test_utility_verify_serialization_behavior(tz_timestamp)
     }
 
-    fn deserialize(_data: &'a [u8]) -> Result<Self, Self::Error> {
-        unimplemented!()
+    #[test]
+    fn invalid_timestamps_with_timezone() {
+        for bytes in [
+            &b"20060901-07:39:20"[..],
+            b"20060901-07:39Z",
+            b"20060901-07:39:20.1234Z",
+            b"20060901-07:39:20.123+",
+        ] {
+            assert!(TzTimestamp::deserialize(bytes).is_err());
+        }
     }
 }
@@ -117,10 +117,11 @@ impl<'a> FieldType<'a> for chrono::NaiveTime {
     #[inline]
     fn deserialize(data: &'a [u8]) -> Result<Self, Self::Error> {
         let s = std::str::from_utf8(data).map_err(|_| ERR_UTF8)?;
-        if data.len() == 8 {
-            Self::parse_from_str(s, "%H:%M:%S").map_err(|_| ERR_TIME)
-        } else {
-            Self::parse_from_str(s, "%H:%M:%S.%3f").map_err(|_| ERR_TIME)
+        match data.len() {
+            8 => Self::parse_from_str(s, "%H:%M:%S").map_err(|_| ERR_TIME),
+            12 => Self::parse_from_str(s, "%H:%M:%S.%3f").map_err(|_| ERR_TIME),
+            15 => Self::parse_from_str(s, "%H:%M:%S.%6f").map_err(|_| ERR_TIME),
+            _ => Self::parse_from_str(s, "%H:%M:%S.%9f").map_err(|_| ERR_TIME),
         }
     }
 }
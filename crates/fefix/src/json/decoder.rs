@@ -1,6 +1,7 @@
 use super::{Config, Configure, DecodeError};
 use crate::dict::FieldLocation;
 use crate::dict::IsFieldDefinition;
+use crate::field_types::MessageEncoding;
 use crate::FieldValueError;
 use crate::{Dictionary, FieldType, GetConfig};
 use crate::{FieldMap, RepeatingGroup};
@@ -11,6 +12,7 @@ use std::collections::HashMap;
 /// A read-only JSON FIX message as parsed by [`Decoder`].
 #[derive(Debug, Copy, Clone)]
 pub struct Message<'a> {
+    dict: &'a Dictionary,
     internal: &'a MessageInternal<'a>,
     group_map: Option<&'a Fields<'a>>,
 }
@@ -37,6 +39,50 @@ impl<'a> Message<'a> {
             }
         }
     }
+
+    /// Looks for the field with tag `tag` in all sections of `self`, as JSON
+    /// messages are keyed by field names.
+    fn field_or_group(&self, tag: u32) -> Option<&'a FieldOrGroup<'a>> {
+        let field = self.dict.field_by_tag(tag)?;
+        let name = field.name();
+        if let Some(context) = self.group_map {
+            context.get(name)
+        } else {
+            [
+                &self.internal.std_header,
+                &self.internal.body,
+                &self.internal.std_trailer,
+            ]
+            .into_iter()
+            .find_map(|fields| fields.get(name))
+        }
+    }
+
+    fn group_of(
+        &self,
+        field_or_group: Option<&'a FieldOrGroup<'a>>,
+    ) -> Result<MessageGroup<'a>, FieldValueError<<usize as FieldType>::Error>> {
+        match field_or_group {
+            Some(FieldOrGroup::Group(entries)) => Ok(MessageGroup {
+                message: Message {
+                    dict: self.dict,
+                    internal: self.internal,
+                    group_map: None,
+                },
+                entries,
+            }),
+            _ => Err(FieldValueError::Missing),
+        }
+    }
+}
+
+fn field_value<'a>(field_or_group: Option<&'a FieldOrGroup<'a>>) -> Option<&'a [u8]> {
+    if let Some(FieldOrGroup::Field(value)) = field_or_group {
+        let s: &str = value.borrow();
+        Some(s.as_bytes())
+    } else {
+        None
+    }
 }
 
 impl<'a, F> FieldMap<&F> for Message<'a>
@@ -49,35 +95,32 @@ where
         &self,
         field: &F,
     ) -> Result<Self::Group, FieldValueError<<usize as FieldType>::Error>> {
-        self.field_map(field)
-            .get(field.name())
-            .ok_or(FieldValueError::Missing)
-            .and_then(|field_or_group| {
-                if let FieldOrGroup::Group(ref entries) = field_or_group {
-                    Ok(MessageGroup {
-                        message: Message {
-                            internal: self.internal,
-                            group_map: None,
-                        },
-                        entries,
-                    })
-                } else {
-                    Err(FieldValueError::Missing)
-                }
-            })
+        self.group_of(self.field_map(field).get(field.name()))
     }
 
     fn fv_raw(&self, field: &F) -> Option<&[u8]> {
-        self.field_map(field)
-            .get(field.name())
-            .and_then(|field_or_group| {
-                if let FieldOrGroup::Field(value) = field_or_group {
-                    let s: &str = value.borrow();
-                    Some(s.as_bytes())
-                } else {
-                    None
-                }
-            })
+        field_value(self.field_map(field).get(field.name()))
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        // JSON text is always UTF-8.
+        Ok(MessageEncoding::Utf8)
+    }
+}
+
+impl<'a> FieldMap<u32> for Message<'a> {
+    type Group = MessageGroup<'a>;
+
+    fn group(&self, tag: u32) -> Result<Self::Group, FieldValueError<<usize as FieldType>::Error>> {
+        self.group_of(self.field_or_group(tag))
+    }
+
+    fn fv_raw(&self, tag: u32) -> Option<&[u8]> {
+        field_value(self.field_or_group(tag))
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        Ok(MessageEncoding::Utf8)
     }
 }
 
@@ -97,6 +140,7 @@ impl<'a> RepeatingGroup for MessageGroup<'a> {
 
     fn get(&self, i: usize) -> Option<Self::Entry> {
         self.entries.get(i).map(|context| Message {
+            dict: self.message.dict,
             internal: self.message.internal,
             group_map: Some(context),
         })
@@ -119,7 +163,7 @@ impl<'a> Iterator for MessageFieldsIter<'a> {
 /// A codec for the JSON encoding type.
 #[derive(Debug, Clone)]
 pub struct Decoder<C = Config> {
-    dictionaries: HashMap<String, Dictionary>,
+    dict: Dictionary,
     message_builder: MessageInternal<'static>,
     config: C,
 }
@@ -131,10 +175,8 @@ where
     /// Creates a new JSON [`Decoder`]. `dict` serves as a reference for data type inference
     /// of incoming messages' fields. Configuration options are initialized via [`Default`].
     pub fn new(dict: Dictionary) -> Self {
-        let mut dictionaries = HashMap::new();
-        dictionaries.insert(dict.get_version().to_string(), dict);
         Self {
-            dictionaries,
+            dict,
             message_builder: MessageInternal::default(),
             config: C::default(),
         }
@@ -142,7 +184,7 @@ where
 
     pub fn decode<'a>(&'a mut self, data: &'a [u8]) -> Result<Message<'a>, DecodeError> {
         let mut deserilizer = serde_json::Deserializer::from_slice(data);
-        let msg = self.message_builder();
+        let msg = message_builder(&mut self.message_builder);
         MessageInternal::deserialize_in_place(&mut deserilizer, msg).map_err(|err| {
             if err.is_syntax() || err.is_eof() || err.is_io() {
                 DecodeError::Syntax
@@ -151,18 +193,19 @@ where
             }
         })?;
         Ok(Message {
+            dict: &self.dict,
             internal: msg,
             group_map: None,
         })
     }
+}
 
-    fn message_builder<'a>(&'a mut self) -> &'a mut MessageInternal<'a> {
-        self.message_builder.clear();
-        unsafe {
-            std::mem::transmute::<&'a mut MessageInternal<'static>, &'a mut MessageInternal<'a>>(
-                &mut self.message_builder,
-            )
-        }
+fn message_builder<'a>(builder: &'a mut MessageInternal<'static>) -> &'a mut MessageInternal<'a> {
+    builder.clear();
+    unsafe {
+        std::mem::transmute::<&'a mut MessageInternal<'static>, &'a mut MessageInternal<'a>>(
+            builder,
+        )
     }
 }
 
@@ -178,7 +221,7 @@ impl<C> GetConfig for Decoder<C> {
     }
 }
 
-type Fields<'a> = HashMap<Cow<'a, str>, FieldOrGroup<'a>>;
+pub(crate) type Fields<'a> = HashMap<Cow<'a, str>, FieldOrGroup<'a>>;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -189,13 +232,13 @@ pub enum FieldOrGroup<'a> {
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
-struct MessageInternal<'a> {
+pub(crate) struct MessageInternal<'a> {
     #[serde(borrow, rename = "Header")]
-    std_header: Fields<'a>,
+    pub(crate) std_header: Fields<'a>,
     #[serde(borrow, rename = "Body")]
-    body: Fields<'a>,
+    pub(crate) body: Fields<'a>,
     #[serde(borrow, rename = "Trailer")]
-    std_trailer: Fields<'a>,
+    pub(crate) std_trailer: Fields<'a>,
 }
 
 impl<'a> std::ops::Drop for MessageInternal<'a> {
@@ -1,5 +1,11 @@
-use crate::dict::IsFieldDefinition;
-use crate::FieldType;
+use super::decoder::{FieldOrGroup, Fields, MessageInternal};
+use super::EncodeError;
+use crate::dict::{IsFieldDefinition, LayoutItem, LayoutItemKind};
+use crate::field_types::MessageEncoding;
+use crate::tagvalue::{MessageTree, MessageTreeItem};
+use crate::{Dictionary, FieldMap, FieldType};
+use std::borrow::Cow;
+use std::collections::HashSet;
 
 /// A codec for the JSON encoding type.
 #[derive(Debug, Clone)]
@@ -21,6 +27,114 @@ impl Encoder {
         self.has_message = true;
         encoder_states::Initial { encoder: self }
     }
+
+    /// Encodes `tree` into a JSON message, using the field names of `dict`.
+    /// Text is decoded according to the `MessageEncoding <347>` of `tree`,
+    /// while `BodyLength <9>` and `CheckSum <10>` are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::definitions::fix44;
+    /// use fefix::json::Encoder;
+    /// use fefix::tagvalue::MessageTree;
+    /// use fefix::prelude::*;
+    ///
+    /// let mut tree = MessageTree::new();
+    /// tree.set(fix44::BEGIN_STRING, "FIX.4.4");
+    /// tree.set(fix44::MSG_TYPE, "0");
+    /// tree.set(fix44::TEST_REQ_ID, "foobar");
+    ///
+    /// let mut encoder = Encoder::new();
+    /// let json = encoder.encode_tree(&tree, &Dictionary::fix44()).unwrap();
+    /// let value = serde_json::from_str::<serde_json::Value>(json).unwrap();
+    /// assert_eq!(value["Header"]["MsgType"], "0");
+    /// assert_eq!(value["Body"]["TestReqID"], "foobar");
+    /// ```
+    pub fn encode_tree(
+        &mut self,
+        tree: &MessageTree,
+        dict: &Dictionary,
+    ) -> Result<&str, EncodeError> {
+        let encoding = FieldMap::<u32>::message_encoding(tree).map_err(|_| EncodeError::Text)?;
+        let header = component_tags(dict, "StandardHeader");
+        let trailer = component_tags(dict, "StandardTrailer");
+        let mut message = MessageInternal::default();
+        for item in tree.items() {
+            let tag = item.tag().get();
+            let fields = if matches!(tag, BODY_LENGTH | CHECK_SUM) {
+                continue;
+            } else if header.contains(&tag) {
+                &mut message.std_header
+            } else if trailer.contains(&tag) {
+                &mut message.std_trailer
+            } else {
+                &mut message.body
+            };
+            insert_item(fields, item, dict, encoding)?;
+        }
+        self.buffer.clear();
+        serde_json::to_writer(&mut self.buffer, &message).map_err(|_| EncodeError::Text)?;
+        self.has_message = true;
+        Ok(std::str::from_utf8(&self.buffer[..]).unwrap())
+    }
+}
+
+const BODY_LENGTH: u32 = 9;
+const CHECK_SUM: u32 = 10;
+
+/// Returns the tags of all fields within the component `name`, including
+/// nested components but not repeating group entries.
+fn component_tags(dict: &Dictionary, name: &str) -> HashSet<u32> {
+    let mut tags = HashSet::new();
+    if let Some(component) = dict.component_by_name(name) {
+        add_tags(&mut tags, component.items());
+    }
+    tags
+}
+
+fn add_tags<'a>(tags: &mut HashSet<u32>, items: impl Iterator<Item = LayoutItem<'a>>) {
+    for item in items {
+        match item.kind() {
+            LayoutItemKind::Field(field) => {
+                tags.insert(field.tag().get());
+            }
+            LayoutItemKind::Component(component) => add_tags(tags, component.items()),
+            LayoutItemKind::Group(len_field, _) => {
+                tags.insert(len_field.tag().get());
+            }
+        }
+    }
+}
+
+fn insert_item<'a>(
+    fields: &mut Fields<'a>,
+    item: &'a MessageTreeItem,
+    dict: &Dictionary,
+    encoding: MessageEncoding,
+) -> Result<(), EncodeError> {
+    let field = dict
+        .field_by_tag(item.tag().get())
+        .ok_or(EncodeError::Dictionary)?;
+    let value = match item {
+        MessageTreeItem::Field(_, value) => {
+            FieldOrGroup::Field(encoding.decode(value).map_err(|_| EncodeError::Text)?)
+        }
+        MessageTreeItem::Group(_, entries) => FieldOrGroup::Group(
+            entries
+                .iter()
+                .map(|entry| {
+                    let mut fields = Fields::default();
+                    for item in entry.items() {
+                        insert_item(&mut fields, item, dict, encoding)?;
+                    }
+                    Ok(fields)
+                })
+                .collect::<Result<_, EncodeError>>()?,
+        ),
+    };
+    fields.insert(Cow::Owned(field.name().to_string()), value);
+    Ok(())
 }
 
 /// Typestates for the JSON [`Encoder`].
@@ -19,6 +19,10 @@ pub enum EncodeError {
     /// establised by the dictionary.
     #[error("Inconsistency between the FIX message and encoding rules as established by the dictionary.")]
     Dictionary,
+    /// Text that can't be decoded according to the `MessageEncoding <347>`
+    /// of the message.
+    #[error("Text can't be decoded according to the message encoding.")]
+    Text,
 }
 
 /// The type returned in the event of an error when decoding a FIX JSON message.
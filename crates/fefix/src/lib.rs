@@ -49,6 +49,12 @@
 //!
 //! Decode and encode FIX messages with JSON.
 //!
+//! ### `owned-messages`
+//!
+//! Owned, `serde`-compatible structs for all messages and components within
+//! [`definitions`], e.g. `definitions::fix44::messages::NewOrderSingle`. See
+//! `codegen::Settings::owned_messages`.
+//!
 //! ### `codegen`
 //!
 //! This feature it intended to be used within Cargo's `[build-dependencies]`, like this:
@@ -73,7 +79,9 @@
 //!
 //! - **Q.** What about `serde` integration?  
 //!   **A.** FIX semantics don't map well to `serde` and there are subtle
-//!   performance implications. It's not a good idea.
+//!   performance implications, so decoders and encoders don't use it. If you
+//!   need to store FIX messages or send them to non-FIX services, enable
+//!   `owned-messages` and convert from and to the generated owned structs.
 //!
 //! # External resources
 //!
@@ -265,6 +273,28 @@ pub trait SetField<F> {
     fn set_with<'a, V>(&'a mut self, field: F, value: V, setting: V::SerializeSettings)
     where
         V: FieldType<'a>;
+
+    /// Returns the [`MessageEncoding`](field_types::MessageEncoding) of the
+    /// text fields written by [`SetField::set_text`], i.e. the value of
+    /// `MessageEncoding <347>` if it was already set. Defaults to
+    /// [`MessageEncoding::Iso8859_1`](field_types::MessageEncoding::Iso8859_1).
+    fn message_encoding(&self) -> Result<field_types::MessageEncoding, &'static str> {
+        Ok(field_types::MessageEncoding::default())
+    }
+
+    /// Writes `text` to a field after encoding it according to
+    /// [`SetField::message_encoding`]. Fails if `text` can't be represented
+    /// in such an encoding.
+    ///
+    /// Data fields, e.g. `EncodedText <355>`, must be preceded by their
+    /// `Length` field: use
+    /// [`MessageEncoding::encode`](field_types::MessageEncoding::encode) to
+    /// know their length in advance.
+    fn set_text(&mut self, field: F, text: &str) -> Result<(), &'static str> {
+        let bytes = self.message_encoding()?.encode(text)?;
+        self.set(field, &bytes[..]);
+        Ok(())
+    }
 }
 
 /// Either a field that is missing or has an invalid value.
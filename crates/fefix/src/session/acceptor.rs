@@ -0,0 +1,583 @@
+use super::{Backend, Config, Configure, FixConnection, MemoryMessageStore, MessageStore};
+use crate::field_types::{TimePrecision, Timestamp};
+use crate::tagvalue::{Config as DecoderConfig, Decoder, DecoderStreaming, Encoder};
+use crate::{Dictionary, FieldMap, SetField, StreamingDecoder};
+use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+use futures::{AsyncRead, AsyncWrite};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::marker::Unpin;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+const BEGIN_STRING: u32 = 8;
+const MSG_SEQ_NUM: u32 = 34;
+const MSG_TYPE: u32 = 35;
+const SENDER_COMP_ID: u32 = 49;
+const SENDER_SUB_ID: u32 = 50;
+const SENDING_TIME: u32 = 52;
+const TARGET_COMP_ID: u32 = 56;
+const TEXT: u32 = 58;
+
+// `None` while the FIX session is active.
+type SessionMap<B, C, S> = HashMap<SessionId, Option<FixConnection<B, C, S>>>;
+type Sessions<B, C, S> = Arc<Mutex<SessionMap<B, C, S>>>;
+
+/// The identity of a FIX session on the acceptor side, as found in the
+/// initiator's `Logon <A>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionId {
+    /// The initiator's `SenderCompID <49>`.
+    pub sender_comp_id: String,
+    /// The initiator's `TargetCompID <56>`, i.e. our own `SenderCompID <49>`.
+    pub target_comp_id: String,
+    /// The initiator's `SenderSubID <50>`, if relevant.
+    pub sender_sub_id: Option<String>,
+}
+
+impl SessionId {
+    /// Returns the [`SessionId`] of the FIX sessions that use `config` on the
+    /// acceptor side.
+    pub fn from_config(config: &impl Configure) -> Self {
+        Self {
+            sender_comp_id: String::from_utf8_lossy(config.target_comp_id()).into_owned(),
+            target_comp_id: String::from_utf8_lossy(config.sender_comp_id()).into_owned(),
+            sender_sub_id: config
+                .target_sub_id()
+                .map(|sub_id| String::from_utf8_lossy(sub_id).into_owned()),
+        }
+    }
+}
+
+impl fmt::Display for SessionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}->{}", self.sender_comp_id, self.target_comp_id)?;
+        if let Some(sender_sub_id) = &self.sender_sub_id {
+            write!(f, " ({})", sender_sub_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// The type returned in the event of an error while accepting a connection
+/// with [`Acceptor::accept`].
+#[derive(Debug, thiserror::Error)]
+pub enum AcceptorError {
+    /// The first inbound message is not a valid `Logon <A>`.
+    #[error("The first message is not a valid Logon <A>.")]
+    InvalidLogon,
+    /// No FIX session was registered with this [`SessionId`].
+    #[error("Unknown FIX session {0}.")]
+    UnknownSession(SessionId),
+    /// The FIX session is already active on another connection.
+    #[error("FIX session {0} is already active.")]
+    DuplicateSession(SessionId),
+    /// I/O error.
+    #[error("I/O error: {0}")]
+    IO(#[from] io::Error),
+}
+
+/// Accepts connections from many counterparties, e.g. on a single port, and
+/// routes each of them to its own [`FixConnection`] according to the
+/// [`SessionId`] found in the initiator's `Logon <A>`.
+///
+/// Every [`FixConnection`] is kept by the [`Acceptor`] in between connections,
+/// so [`SeqNumbers`](super::SeqNumbers) and [`MessageStore`] contents survive
+/// reconnections. [`Acceptor`] is cheap to clone and all clones share the same
+/// FIX sessions.
+#[derive(Debug)]
+pub struct Acceptor<B, C = Config, S = MemoryMessageStore> {
+    dictionary: Dictionary,
+    appl_ver_ids: Vec<(Vec<u8>, Dictionary)>,
+    sessions: Sessions<B, C, S>,
+}
+
+impl<B, C, S> Clone for Acceptor<B, C, S> {
+    fn clone(&self) -> Self {
+        Self {
+            dictionary: self.dictionary.clone(),
+            appl_ver_ids: self.appl_ver_ids.clone(),
+            sessions: self.sessions.clone(),
+        }
+    }
+}
+
+impl<B, C, S> Acceptor<B, C, S>
+where
+    B: Backend,
+    C: Configure,
+    S: MessageStore,
+{
+    /// Creates a new [`Acceptor`] without any FIX session. Inbound messages
+    /// are decoded according to `dictionary`.
+    pub fn new(dictionary: Dictionary) -> Self {
+        Self {
+            dictionary,
+            appl_ver_ids: Vec::new(),
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Decodes application messages with `ApplVerID <1128>` `appl_ver_id`
+    /// according to `dict`. See [`Decoder::add_appl_ver_id`].
+    pub fn add_appl_ver_id(&mut self, appl_ver_id: &[u8], dict: Dictionary) {
+        self.appl_ver_ids.push((appl_ver_id.to_vec(), dict));
+    }
+
+    /// Registers `connection` under the [`SessionId`] of its
+    /// [`Configure`] implementor, replacing any previous FIX session with the
+    /// same [`SessionId`].
+    pub fn add_session(&self, connection: FixConnection<B, C, S>) -> SessionId {
+        let session_id = SessionId::from_config(connection.config());
+        self.lock_sessions()
+            .insert(session_id.clone(), Some(connection));
+        session_id
+    }
+
+    /// Unregisters the FIX session with `session_id` and returns its
+    /// [`FixConnection`], unless it's currently active.
+    pub fn remove_session(&self, session_id: &SessionId) -> Option<FixConnection<B, C, S>> {
+        let mut sessions = self.lock_sessions();
+        match sessions.get(session_id) {
+            Some(Some(_)) => sessions.remove(session_id).flatten(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if and only if the FIX session with `session_id` is
+    /// currently active.
+    pub fn is_active(&self, session_id: &SessionId) -> bool {
+        matches!(self.lock_sessions().get(session_id), Some(None))
+    }
+
+    /// Reads the initiator's `Logon <A>` from `input`, finds the matching FIX
+    /// session and runs it until it's over. Unknown and duplicate FIX
+    /// sessions are refused with `Logout <5>`.
+    ///
+    /// Each connection is meant to be handled by its own task, e.g.:
+    ///
+    /// ```no_run
+    /// use fefix::session::{Acceptor, Backend};
+    /// use fefix::tagvalue::Message;
+    /// use tokio::net::TcpListener;
+    /// use tokio_util::compat::*;
+    ///
+    /// #[derive(Clone)]
+    /// struct MyBackend;
+    ///
+    /// impl Backend for MyBackend {
+    ///     type Error = String;
+    ///
+    ///     fn sender_comp_id(&self) -> &[u8] {
+    ///         b"GATEWAY"
+    ///     }
+    ///
+    ///     fn target_comp_id(&self) -> &[u8] {
+    ///         b"CLIENT"
+    ///     }
+    ///
+    ///     fn on_inbound_app_message(&mut self, _msg: Message<&[u8]>) -> Result<(), String> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn on_outbound_message(&mut self, _msg: &[u8]) -> Result<(), String> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn on_successful_handshake(&mut self) -> Result<(), String> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn pending_message(&mut self) -> Option<&[u8]> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// # async fn run(acceptor: Acceptor<MyBackend>) -> std::io::Result<()> {
+    /// let listener = TcpListener::bind("0.0.0.0:9876").await?;
+    /// loop {
+    ///     let (stream, _) = listener.accept().await?;
+    ///     let acceptor = acceptor.clone();
+    ///     tokio::spawn(async move {
+    ///         let (input, output) = stream.into_split();
+    ///         acceptor.accept(input.compat(), output.compat_write()).await
+    ///     });
+    /// }
+    /// # }
+    /// ```
+    pub async fn accept<I, O>(
+        &self,
+        mut input: I,
+        mut output: O,
+    ) -> Result<SessionId, AcceptorError>
+    where
+        I: AsyncRead + Unpin,
+        O: AsyncWrite + Unpin,
+    {
+        let mut decoder = self.decoder();
+        let logon = read_first_message(&mut decoder, &mut input).await?;
+        let (session_id, begin_string) = {
+            let msg = decoder.message();
+            if msg.fv_raw(MSG_TYPE) != Some(b"A") {
+                return Err(AcceptorError::InvalidLogon);
+            }
+            let session_id = match (
+                msg.fv::<&str>(SENDER_COMP_ID),
+                msg.fv::<&str>(TARGET_COMP_ID),
+            ) {
+                (Ok(sender_comp_id), Ok(target_comp_id)) => SessionId {
+                    sender_comp_id: sender_comp_id.to_string(),
+                    target_comp_id: target_comp_id.to_string(),
+                    sender_sub_id: msg.fv::<&str>(SENDER_SUB_ID).ok().map(str::to_string),
+                },
+                _ => return Err(AcceptorError::InvalidLogon),
+            };
+            (
+                session_id,
+                msg.fv_raw(BEGIN_STRING).unwrap_or_default().to_vec(),
+            )
+        };
+
+        let route = {
+            let mut sessions = self.lock_sessions();
+            // Sessions without `SenderSubID <50>` also match Logons that
+            // have one.
+            let key = if sessions.contains_key(&session_id) {
+                session_id.clone()
+            } else {
+                SessionId {
+                    sender_sub_id: None,
+                    ..session_id.clone()
+                }
+            };
+            // Active FIX sessions are left with `None`.
+            match sessions.get_mut(&key).map(Option::take) {
+                Some(Some(connection)) => Ok((key, connection)),
+                Some(None) => Err(AcceptorError::DuplicateSession(session_id.clone())),
+                None => Err(AcceptorError::UnknownSession(session_id.clone())),
+            }
+        };
+        let (session_id, connection) = match route {
+            Ok(route) => route,
+            Err(err) => {
+                let text = match err {
+                    AcceptorError::DuplicateSession(_) => "Session already active",
+                    _ => "Unknown session",
+                };
+                refuse_logon(&mut output, &begin_string, &session_id, text).await?;
+                return Err(err);
+            }
+        };
+
+        let mut checkout = Checkout {
+            session_id: session_id.clone(),
+            connection: Some(connection),
+            sessions: self.sessions.clone(),
+        };
+        decoder.clear();
+        // The `Logon <A>` must go through the usual validation.
+        let input = Cursor::new(logon).chain(input);
+        if let Some(connection) = checkout.connection.as_mut() {
+            connection.accept(input, output, decoder).await?;
+        }
+        Ok(session_id)
+    }
+
+    /// Accepts TCP connections from `listener` forever and handles each of them
+    /// with [`Acceptor::accept`] on a new [`tokio`] task. Errors of individual
+    /// connections are discarded.
+    #[cfg(feature = "utils-tokio")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "utils-tokio")))]
+    pub async fn serve(&self, listener: tokio::net::TcpListener) -> io::Result<()>
+    where
+        B: Send + 'static,
+        C: Send + 'static,
+        S: Send + 'static,
+    {
+        use tokio_util::compat::{TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let acceptor = self.clone();
+            tokio::spawn(async move {
+                let (input, output) = stream.into_split();
+                acceptor.accept(input.compat(), output.compat_write()).await
+            });
+        }
+    }
+
+    fn decoder(&self) -> DecoderStreaming<Vec<u8>> {
+        new_decoder(&self.dictionary, &self.appl_ver_ids)
+    }
+
+    fn lock_sessions(&self) -> MutexGuard<'_, SessionMap<B, C, S>> {
+        // Poisoning can't leave the map in an inconsistent state.
+        self.sessions.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Gives the [`FixConnection`] back to its [`Acceptor`] when the FIX session
+/// is over, even if the future driving it is dropped.
+struct Checkout<B, C, S> {
+    session_id: SessionId,
+    connection: Option<FixConnection<B, C, S>>,
+    sessions: Sessions<B, C, S>,
+}
+
+impl<B, C, S> Drop for Checkout<B, C, S> {
+    fn drop(&mut self) {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|err| err.into_inner());
+        // The FIX session may have been replaced in the meantime.
+        if let Some(slot @ None) = sessions.get_mut(&self.session_id) {
+            *slot = self.connection.take();
+        }
+    }
+}
+
+/// Reads a single message from `input` and returns its raw bytes.
+/// Creates a [`DecoderStreaming`] for `dictionary`, which also knows about
+/// the application-level dictionaries of `appl_ver_ids`.
+pub(crate) fn new_decoder(
+    dictionary: &Dictionary,
+    appl_ver_ids: &[(Vec<u8>, Dictionary)],
+) -> DecoderStreaming<Vec<u8>> {
+    let mut decoder = Decoder::<DecoderConfig>::new(dictionary.clone());
+    for (appl_ver_id, dict) in appl_ver_ids {
+        decoder.add_appl_ver_id(appl_ver_id, dict.clone());
+    }
+    decoder.streaming(vec![])
+}
+
+async fn read_first_message<I>(
+    decoder: &mut DecoderStreaming<Vec<u8>>,
+    input: &mut I,
+) -> Result<Vec<u8>, AcceptorError>
+where
+    I: AsyncRead + Unpin,
+{
+    let mut num_bytes_filled = 0;
+    loop {
+        let num_bytes_required = decoder.num_bytes_required();
+        if num_bytes_filled >= num_bytes_required {
+            match decoder.try_parse() {
+                Ok(Some(())) => return Ok(decoder.buffer()[..num_bytes_filled].to_vec()),
+                Ok(None) => continue,
+                Err(_) => return Err(AcceptorError::InvalidLogon),
+            }
+        }
+        let buffer = decoder.buffer();
+        if buffer.len() < num_bytes_required {
+            buffer.resize(num_bytes_required, 0);
+        }
+        let num_bytes = input
+            .read(&mut buffer[num_bytes_filled..num_bytes_required])
+            .await?;
+        if num_bytes == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        num_bytes_filled += num_bytes;
+    }
+}
+
+/// Answers a `Logon <A>` that can't be routed to any FIX session with a
+/// `Logout <5>`. The latter is outside of any FIX session, so it always has
+/// `MsgSeqNum <34>` equal to 1.
+async fn refuse_logon<O>(
+    output: &mut O,
+    begin_string: &[u8],
+    session_id: &SessionId,
+    text: &str,
+) -> io::Result<()>
+where
+    O: AsyncWrite + Unpin,
+{
+    let mut encoder = Encoder::<DecoderConfig>::default();
+    let mut buffer = Vec::new();
+    let mut msg = encoder.start_message(begin_string, &mut buffer, b"5");
+    msg.set(SENDER_COMP_ID, session_id.target_comp_id.as_str());
+    msg.set(TARGET_COMP_ID, session_id.sender_comp_id.as_str());
+    msg.set(MSG_SEQ_NUM, 1u64);
+    msg.set_with(SENDING_TIME, Timestamp::utc_now(), TimePrecision::Millis);
+    msg.set(TEXT, text);
+    output.write_all(msg.done().0).await?;
+    output.flush().await?;
+    output.close().await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::Message;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+    use tokio::net::{TcpListener, TcpStream};
+
+    #[derive(Debug, Clone, Default)]
+    struct TestBackend;
+
+    impl Backend for TestBackend {
+        type Error = String;
+
+        fn sender_comp_id(&self) -> &[u8] {
+            b"SENDER"
+        }
+
+        fn target_comp_id(&self) -> &[u8] {
+            b"TARGET"
+        }
+
+        fn on_inbound_app_message(&mut self, _message: Message<&[u8]>) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_outbound_message(&mut self, _message: &[u8]) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_successful_handshake(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn pending_message(&mut self) -> Option<&[u8]> {
+            None
+        }
+    }
+
+    fn connection(target_comp_id: &str, target_sub_id: Option<&str>) -> FixConnection<TestBackend> {
+        let mut config = Config::default();
+        config.sender_comp_id = "GATEWAY".to_string();
+        config.target_comp_id = target_comp_id.to_string();
+        config.target_sub_id = target_sub_id.map(str::to_string);
+        FixConnection::new(config, TestBackend::default())
+    }
+
+    fn encode_message(
+        msg_type: &[u8],
+        msg_seq_num: u64,
+        sender_comp_id: &str,
+        sender_sub_id: Option<&str>,
+    ) -> Vec<u8> {
+        let mut encoder = Encoder::<DecoderConfig>::default();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, msg_type);
+        msg.set(SENDER_COMP_ID, sender_comp_id);
+        msg.set(TARGET_COMP_ID, "GATEWAY");
+        if let Some(sender_sub_id) = sender_sub_id {
+            msg.set(SENDER_SUB_ID, sender_sub_id);
+        }
+        msg.set(MSG_SEQ_NUM, msg_seq_num);
+        msg.set_with(SENDING_TIME, Timestamp::utc_now(), TimePrecision::Millis);
+        if msg_type == b"A" {
+            msg.set(98, 0u32);
+            msg.set(108, 30u32);
+        }
+        msg.done().0.to_vec()
+    }
+
+    async fn serve(acceptor: &Acceptor<TestBackend>) -> std::net::SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let acceptor = acceptor.clone();
+        tokio::spawn(async move { acceptor.serve(listener).await });
+        addr
+    }
+
+    async fn logon(
+        addr: std::net::SocketAddr,
+        sender_comp_id: &str,
+        sender_sub_id: Option<&str>,
+    ) -> (TcpStream, String) {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, sender_comp_id, sender_sub_id))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x0110=").await;
+        (stream, response)
+    }
+
+    async fn read_until(stream: &mut TcpStream, pattern: &str) -> String {
+        let mut response = Vec::new();
+        while !String::from_utf8_lossy(&response[..]).contains(pattern) {
+            let mut buffer = [0; 1024];
+            let n = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buffer))
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(n > 0);
+            response.extend_from_slice(&buffer[..n]);
+        }
+        String::from_utf8_lossy(&response[..]).to_string()
+    }
+
+    #[tokio::test]
+    async fn logons_are_routed_by_comp_ids() {
+        let acceptor = Acceptor::new(Dictionary::fix44());
+        let session_a = acceptor.add_session(connection("CLIENT_A", None));
+        let session_b = acceptor.add_session(connection("CLIENT_B", None));
+        let addr = serve(&acceptor).await;
+
+        let (mut stream_a, response_a) = logon(addr, "CLIENT_A", None).await;
+        let (_stream_b, response_b) = logon(addr, "CLIENT_B", None).await;
+        assert!(response_a.contains("\x0135=A\x01"));
+        assert!(response_a.contains("\x0156=CLIENT_A\x01"));
+        assert!(response_b.contains("\x0135=A\x01"));
+        assert!(response_b.contains("\x0156=CLIENT_B\x01"));
+        assert!(acceptor.is_active(&session_a));
+        assert!(acceptor.is_active(&session_b));
+
+        stream_a
+            .write_all(&encode_message(b"5", 2, "CLIENT_A", None))
+            .await
+            .unwrap();
+        read_until(&mut stream_a, "\x0135=5\x01").await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!acceptor.is_active(&session_a));
+        let connection = acceptor.remove_session(&session_a).unwrap();
+        assert_eq!(connection.seq_numbers().next_inbound(), 3);
+    }
+
+    #[tokio::test]
+    async fn logons_are_routed_by_sender_sub_id() {
+        let acceptor = Acceptor::new(Dictionary::fix44());
+        acceptor.add_session(connection("CLIENT", Some("DESK_1")));
+        let desk_2 = acceptor.add_session(connection("CLIENT", Some("DESK_2")));
+        let addr = serve(&acceptor).await;
+
+        let (_stream, response) = logon(addr, "CLIENT", Some("DESK_2")).await;
+        assert!(response.contains("\x0135=A\x01"));
+        assert!(acceptor.is_active(&desk_2));
+        let (_stream, response) = logon(addr, "CLIENT", Some("DESK_3")).await;
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains("\x0158=Unknown session\x01"));
+    }
+
+    #[tokio::test]
+    async fn unknown_and_duplicate_sessions_are_refused() {
+        let acceptor = Acceptor::new(Dictionary::fix44());
+        acceptor.add_session(connection("CLIENT", None));
+        let addr = serve(&acceptor).await;
+
+        let (_stream, response) = logon(addr, "SOMEONE_ELSE", None).await;
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains("\x0156=SOMEONE_ELSE\x01"));
+        assert!(response.contains("\x0158=Unknown session\x01"));
+
+        let (_stream, response) = logon(addr, "CLIENT", None).await;
+        assert!(response.contains("\x0135=A\x01"));
+        let (_stream, response) = logon(addr, "CLIENT", None).await;
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains("\x0158=Session already active\x01"));
+    }
+
+    #[tokio::test]
+    async fn first_message_must_be_a_logon() {
+        let acceptor = Acceptor::<TestBackend>::new(Dictionary::fix44());
+        acceptor.add_session(connection("CLIENT", None));
+        let input = encode_message(b"0", 1, "CLIENT", None);
+        let result = acceptor
+            .accept(Cursor::new(input), futures::io::sink())
+            .await;
+        assert!(matches!(result, Err(AcceptorError::InvalidLogon)));
+    }
+}
@@ -1,4 +1,6 @@
-use super::{Environment, MsgSeqNumCounter, SeqNumbers};
+use super::{
+    Environment, HeartbeatRule, MsgSeqNumCounter, ResetSeqNumPolicy, SeqNumbers, SessionSchedule,
+};
 use std::marker::PhantomData;
 use std::num::NonZeroU64;
 use std::time::Duration;
@@ -28,6 +30,14 @@ pub trait Configure: Clone + Default {
         b"FIX.4.4"
     }
 
+    /// The `DefaultApplVerID <1137>` to send via `Logon <A>` in FIXT.1.1
+    /// sessions, e.g. `9` for FIX 5.0 SP2. Acceptors use the counterparty's
+    /// `DefaultApplVerID <1137>` instead, and fall back to this one only if
+    /// the former is missing. `None` by default.
+    fn default_appl_ver_id(&self) -> Option<&[u8]> {
+        None
+    }
+
     fn sender_comp_id(&self) -> &[u8] {
         b"SENDER_COMP"
     }
@@ -36,6 +46,13 @@ pub trait Configure: Clone + Default {
         b"TARGET_COMP"
     }
 
+    /// The counterparty's `SenderSubID <50>`, if it's part of the identity of
+    /// the FIX session. Used by [`Acceptor`](super::Acceptor) to tell apart
+    /// FIX sessions with the same CompIDs. `None` by default.
+    fn target_sub_id(&self) -> Option<&[u8]> {
+        None
+    }
+
     fn environment(&self) -> Environment {
         Environment::Production { allow_test: true }
     }
@@ -43,6 +60,37 @@ pub trait Configure: Clone + Default {
     fn heartbeat(&self) -> Duration {
         Duration::from_secs(30)
     }
+
+    /// The [`HeartbeatRule`] used by acceptors to validate the `HeartBtInt
+    /// <108>` proposed by initiators. [`HeartbeatRule::Any`] by default.
+    fn heartbeat_rule(&self) -> HeartbeatRule {
+        HeartbeatRule::Any
+    }
+
+    /// The maximum number of messages to ask for with a single `ResendRequest
+    /// <2>`. Bigger gaps are filled by several consecutive requests. No limit
+    /// by default.
+    fn max_resend_range(&self) -> Option<u64> {
+        None
+    }
+
+    /// The [`SeqNumbers`] to start the FIX session with. Both 1 by default.
+    fn seq_numbers(&self) -> SeqNumbers {
+        SeqNumbers::default()
+    }
+
+    /// The [`SessionSchedule`] outside of which Logons are refused. Active FIX
+    /// sessions are logged out at the end of it. `None` by default, i.e. FIX
+    /// sessions can take place at any time.
+    fn schedule(&self) -> Option<&SessionSchedule> {
+        None
+    }
+
+    /// When to reset [`SeqNumbers`] during the Logon handshake.
+    /// [`ResetSeqNumPolicy::Never`] by default.
+    fn reset_seq_num_policy(&self) -> ResetSeqNumPolicy {
+        ResetSeqNumPolicy::Never
+    }
 }
 
 /// The canonical implementor of [`Configure`]. Every setting can be changed.
@@ -55,13 +103,19 @@ pub struct Config {
     pub verify_test_indicator: bool,
     pub max_allowed_latency: Duration,
     pub begin_string: String,
+    pub default_appl_ver_id: Option<String>,
     pub environment: Environment,
     pub heartbeat: Duration,
+    pub heartbeat_rule: HeartbeatRule,
+    pub max_resend_range: Option<u64>,
     pub seq_numbers: SeqNumbers,
+    pub schedule: Option<SessionSchedule>,
+    pub reset_seq_num_policy: ResetSeqNumPolicy,
     pub msg_seq_num_inbound: MsgSeqNumCounter,
     pub msg_seq_num_outbound: MsgSeqNumCounter,
     pub sender_comp_id: String,
     pub target_comp_id: String,
+    pub target_sub_id: Option<String>,
 }
 
 impl Configure for Config {
@@ -81,10 +135,18 @@ impl Configure for Config {
         self.target_comp_id.as_bytes()
     }
 
+    fn target_sub_id(&self) -> Option<&[u8]> {
+        self.target_sub_id.as_ref().map(|sub_id| sub_id.as_bytes())
+    }
+
     fn begin_string(&self) -> &[u8] {
         self.begin_string.as_bytes()
     }
 
+    fn default_appl_ver_id(&self) -> Option<&[u8]> {
+        self.default_appl_ver_id.as_ref().map(|id| id.as_bytes())
+    }
+
     fn environment(&self) -> Environment {
         self.environment
     }
@@ -92,6 +154,26 @@ impl Configure for Config {
     fn heartbeat(&self) -> Duration {
         self.heartbeat
     }
+
+    fn heartbeat_rule(&self) -> HeartbeatRule {
+        self.heartbeat_rule.clone()
+    }
+
+    fn max_resend_range(&self) -> Option<u64> {
+        self.max_resend_range
+    }
+
+    fn seq_numbers(&self) -> SeqNumbers {
+        self.seq_numbers
+    }
+
+    fn schedule(&self) -> Option<&SessionSchedule> {
+        self.schedule.as_ref()
+    }
+
+    fn reset_seq_num_policy(&self) -> ResetSeqNumPolicy {
+        self.reset_seq_num_policy
+    }
 }
 
 impl Default for Config {
@@ -101,13 +183,19 @@ impl Default for Config {
             verify_test_indicator: true,
             max_allowed_latency: Duration::from_secs(3),
             begin_string: "FIX.4.4".to_string(),
+            default_appl_ver_id: None,
             environment: Environment::Production { allow_test: true },
             heartbeat: Duration::from_secs(30),
+            heartbeat_rule: HeartbeatRule::Any,
+            max_resend_range: None,
             seq_numbers: SeqNumbers::new(NonZeroU64::new(1).unwrap(), NonZeroU64::new(1).unwrap()),
+            schedule: None,
+            reset_seq_num_policy: ResetSeqNumPolicy::Never,
             msg_seq_num_inbound: MsgSeqNumCounter::START,
             msg_seq_num_outbound: MsgSeqNumCounter::START,
             sender_comp_id: "SENDER_COMP".to_string(),
             target_comp_id: "TARGET_COMP".to_string(),
+            target_sub_id: None,
         }
     }
 }
@@ -133,6 +221,16 @@ mod test {
             config.verify_test_indicator(),
             ConfigDefault.verify_test_indicator()
         );
+        assert_eq!(config.max_resend_range(), ConfigDefault.max_resend_range());
+        assert_eq!(config.schedule(), ConfigDefault.schedule());
+        assert_eq!(
+            config.default_appl_ver_id(),
+            ConfigDefault.default_appl_ver_id()
+        );
+        assert_eq!(
+            config.reset_seq_num_policy(),
+            ConfigDefault.reset_seq_num_policy()
+        );
     }
 
     #[quickcheck]
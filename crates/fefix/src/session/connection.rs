@@ -1,15 +1,22 @@
-use super::{errs, Backend, Config, Configure, LlEvent, LlEventLoop};
-use crate::session::{Environment, SeqNumbers};
-use crate::tagvalue::FvWrite;
-use crate::tagvalue::Message;
-use crate::tagvalue::FieldMap;
-use crate::tagvalue::{DecoderStreaming, Encoder, EncoderHandle};
-use crate::Buffer;
-use crate::FieldType;
-use futures::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
-use std::marker::{PhantomData, Unpin};
-use std::pin::Pin;
-use std::time::Duration;
+use super::{
+    errs, Backend, Config, Configure, LlEvent, LlEventLoop, LogonRejection, MemoryMessageStore,
+    MessageStore, ResetSeqNumPolicy, SeqNumberError, SeqNumbers,
+};
+use crate::field_types::{TimePrecision, Timestamp};
+use crate::tagvalue::{Config as DecoderConfig, Configure as _};
+use crate::tagvalue::{DecoderStreaming, Encoder, EncoderHandle, Message, RawDecoder};
+use crate::tagvalue::{ValidationError, Validator};
+use crate::{FieldMap, GetConfig, SetField};
+use futures::channel::mpsc;
+use futures::future::Fuse;
+use futures::stream::StreamExt;
+use futures::{pin_mut, select, AsyncRead, AsyncWrite, AsyncWriteExt, FutureExt};
+use futures_timer::Delay;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::marker::Unpin;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 const BEGIN_SEQ_NO: u32 = 7;
@@ -17,568 +24,2000 @@ const BEGIN_STRING: u32 = 8;
 const END_SEQ_NO: u32 = 16;
 const MSG_SEQ_NUM: u32 = 34;
 const MSG_TYPE: u32 = 35;
+const NEW_SEQ_NO: u32 = 36;
+const POSS_DUP_FLAG: u32 = 43;
+const REF_SEQ_NUM: u32 = 45;
 const SENDER_COMP_ID: u32 = 49;
 const SENDING_TIME: u32 = 52;
 const TARGET_COMP_ID: u32 = 56;
 const TEXT: u32 = 58;
+const ORIG_SENDING_TIME: u32 = 122;
 const ENCRYPT_METHOD: u32 = 98;
+const HEART_BT_INT: u32 = 108;
 const TEST_REQ_ID: u32 = 112;
+const GAP_FILL_FLAG: u32 = 123;
+const RESET_SEQ_NUM_FLAG: u32 = 141;
+const NEW_PASSWORD: u32 = 925;
+const SESSION_STATUS: u32 = 1409;
+const DEFAULT_APPL_VER_ID: u32 = 1137;
 const REF_TAG_ID: u32 = 371;
 const REF_MSG_TYPE: u32 = 372;
 const SESSION_REJECT_REASON: u32 = 373;
+const BUSINESS_REJECT_REASON: u32 = 380;
 const TEST_MESSAGE_INDICATOR: u32 = 464;
 
+// Valid values of `SessionRejectReason <373>`.
+const REQUIRED_TAG_MISSING: u32 = 1;
+const VALUE_IS_INCORRECT: u32 = 5;
+const COMP_ID_PROBLEM: u32 = 9;
 const SENDING_TIME_ACCURACY_PROBLEM: u32 = 10;
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(enum_as_inner::EnumAsInner))]
-pub enum Response<'a> {
+// Valid values of `SessionStatus <1409>`.
+const SESSION_ACTIVE: u32 = 0;
+const SESSION_PASSWORD_CHANGED: u32 = 1;
+
+// Valid values of `BusinessRejectReason <380>`.
+const BUSINESS_REJECT_OTHER: u32 = 0;
+const UNSUPPORTED_MESSAGE_TYPE: u32 = 3;
+
+/// What a [`FixConnection`] should do after processing an event.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Response {
+    /// Nothing to do besides sending the pending outbound messages, if any.
     None,
+    /// The counterparty has shown signs of life and `Heartbeat <0>` -related
+    /// timers must be reset.
     ResetHeartbeat,
+    /// The FIX session is over and the transport layer must be closed.
     TerminateTransport,
-    Application(Message<'a, &'a [u8]>),
-    Session(&'a [u8]),
-    Inbound(Message<'a, &'a [u8]>),
-    Outbound(Message<'a, &'a [u8]>),
-    OutboundBytes(&'a [u8]),
-    Resend {
-        range: (),
-    },
     /// The FIX session processor should log each encountered garbled message to
     /// assist in problem detection and diagnosis.
     LogGarbled,
 }
 
-/// A FIX connection message processor.
+/// The state of a [`FixConnection`] within the FIX session lifecycle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SessionState {
+    /// We are the acceptor and we're waiting for the initiator's `Logon <A>`.
+    AwaitingLogon,
+    /// We are the initiator and we've already sent our `Logon <A>`.
+    LogonSent,
+    /// Logon handshake completed.
+    Active,
+    /// We've sent `Logout <5>` and we're waiting for the counterparty's.
+    LogoutSent,
+    /// The FIX session is over.
+    Disconnected,
+}
+
+enum Command {
+    Send {
+        msg_type: Vec<u8>,
+        #[allow(clippy::type_complexity)]
+        fill: Box<dyn FnOnce(&mut EncoderHandle<Vec<u8>>) + Send>,
+    },
+    Logout {
+        text: Option<String>,
+    },
+}
+
+impl fmt::Debug for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Send { msg_type, .. } => f
+                .debug_struct("Send")
+                .field("msg_type", &String::from_utf8_lossy(msg_type))
+                .finish_non_exhaustive(),
+            Command::Logout { text } => f.debug_struct("Logout").field("text", text).finish(),
+        }
+    }
+}
+
+/// A cheaply cloneable handle to a [`FixConnection`], which allows to send
+/// application messages and to request a `Logout <5>` from other tasks.
+///
+/// Commands are only processed after the Logon handshake has completed; until
+/// then, they are queued.
+#[derive(Debug, Clone)]
+pub struct FixConnectionHandle {
+    sender: mpsc::UnboundedSender<Command>,
+}
+
+impl FixConnectionHandle {
+    /// Queues a new outbound application message of type `msg_type`. Its
+    /// body is written by `f`, while `BeginString <8>`, `BodyLength <9>`,
+    /// `MsgType <35>`, `SenderCompID <49>`, `TargetCompID <56>`,
+    /// `MsgSeqNum <34>`, `SendingTime <52>` and `CheckSum <10>` are taken care
+    /// of by the [`FixConnection`].
+    ///
+    /// Fails with [`io::ErrorKind::NotConnected`] if the [`FixConnection`]
+    /// doesn't exist anymore.
+    pub fn send<F>(&self, msg_type: &[u8], f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut EncoderHandle<Vec<u8>>) + Send + 'static,
+    {
+        self.command(Command::Send {
+            msg_type: msg_type.to_vec(),
+            fill: Box::new(f),
+        })
+    }
+
+    /// Asks the [`FixConnection`] to start the Logout handshake, with an
+    /// optional `Text <58>`.
+    ///
+    /// Fails with [`io::ErrorKind::NotConnected`] if the [`FixConnection`]
+    /// doesn't exist anymore.
+    pub fn logout(&self, text: Option<String>) -> io::Result<()> {
+        self.command(Command::Logout { text })
+    }
+
+    fn command(&self, command: Command) -> io::Result<()> {
+        self.sender
+            .unbounded_send(command)
+            .map_err(|_| io::ErrorKind::NotConnected.into())
+    }
+}
+
+/// A FIX connection message processor, i.e. the FIX session layer state
+/// machine.
+///
+/// [`FixConnection`] takes care of the Logon and Logout handshakes,
+/// `Heartbeat <0>` and `TestRequest <1>` exchanges, sequence number validation
+/// and `Reject <3>` generation. Application messages are forwarded to a
+/// [`Backend`].
+///
+/// Outbound messages are saved to a [`MessageStore`], which [`FixConnection`]
+/// then uses to answer `ResendRequest <2>` messages and to persist
+/// [`SeqNumbers`].
 #[derive(Debug)]
-pub struct FixConnection<B, C = Config> {
+pub struct FixConnection<B, C = Config, S = MemoryMessageStore> {
     uuid: Uuid,
     config: C,
     backend: B,
     encoder: Encoder,
     buffer: Vec<u8>,
-    msg_seq_num_inbound: MsgSeqNumCounter,
-    msg_seq_num_outbound: MsgSeqNumCounter,
+    store: S,
+    // Errors from `store` can't be reported right away.
+    store_error: Option<io::Error>,
+    seq_numbers: SeqNumbers,
+    validator: Option<Validator>,
+    // Inbound messages with a `MsgSeqNum <34>` that was too high, waiting for
+    // the gap to be filled.
+    inbound_queue: BTreeMap<u64, Vec<u8>>,
+    // The last `MsgSeqNum <34>` we've asked for via `ResendRequest <2>`.
+    resend_end: u64,
+    // Whether the next inbound message comes from `inbound_queue`.
+    is_replaying: bool,
+    // FIXT.1.1 only: the `DefaultApplVerID <1137>` of the Logon handshake.
+    default_appl_ver_id: Option<Vec<u8>>,
+    // Whether the Logon handshake resets `seq_numbers`.
+    reset_seq_num_flag: bool,
+    // The end of the current session of `Configure::schedule`, if any.
+    session_end: Option<Instant>,
+    // Whether the last Logon handshake was successful.
+    has_logged_on: bool,
+    // Whether the Logout was initiated via `FixConnectionHandle::logout`.
+    is_logout_requested: bool,
+    heartbeat: Duration,
+    state: SessionState,
+    commands: mpsc::UnboundedReceiver<Command>,
+    handle: FixConnectionHandle,
+}
+
+enum Input<'a> {
+    Event(Option<LlEvent<'a>>),
+    Command(Option<Command>),
+    EndOfSession,
 }
 
-#[allow(dead_code)]
-impl<C, B> FixConnection<B, C>
+impl<B, C> FixConnection<B, C, MemoryMessageStore>
 where
+    B: Backend,
     C: Configure,
+{
+    /// Creates a new [`FixConnection`] with the settings of `config` and a
+    /// [`MemoryMessageStore`].
+    pub fn new(config: C, backend: B) -> Self {
+        Self::with_store(config, backend, MemoryMessageStore::default())
+    }
+}
+
+impl<B, C, S> FixConnection<B, C, S>
+where
     B: Backend,
+    C: Configure,
+    S: MessageStore,
 {
-    /// The entry point for a [`FixConnection`].
-    async fn start<I, O>(&mut self, mut input: I, mut output: O, mut decoder: DecoderStreaming)
+    /// Creates a new [`FixConnection`] with the settings of `config` and a
+    /// custom [`MessageStore`]. The FIX session resumes from the
+    /// [`SeqNumbers`] saved in `store`, if any.
+    pub fn with_store(config: C, backend: B, store: S) -> Self {
+        let (sender, commands) = mpsc::unbounded();
+        Self {
+            uuid: Uuid::new_v4(),
+            seq_numbers: store.seq_numbers().unwrap_or_else(|| config.seq_numbers()),
+            validator: None,
+            inbound_queue: BTreeMap::new(),
+            resend_end: 0,
+            is_replaying: false,
+            default_appl_ver_id: config.default_appl_ver_id().map(|id| id.to_vec()),
+            reset_seq_num_flag: false,
+            session_end: None,
+            has_logged_on: false,
+            is_logout_requested: false,
+            heartbeat: config.heartbeat(),
+            config,
+            backend,
+            encoder: Encoder::default(),
+            buffer: Vec::new(),
+            store,
+            store_error: None,
+            state: SessionState::AwaitingLogon,
+            commands,
+            handle: FixConnectionHandle { sender },
+        }
+    }
+
+    /// Returns the unique identifier of `self`.
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns an immutable reference to the configuration options of `self`.
+    pub fn config(&self) -> &C {
+        &self.config
+    }
+
+    /// Returns an immutable reference to the [`Backend`] of `self`.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the [`Backend`] of `self`.
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// Returns an immutable reference to the [`MessageStore`] of `self`.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the current [`SeqNumbers`] of `self`.
+    pub fn seq_numbers(&self) -> SeqNumbers {
+        self.seq_numbers
+    }
+
+    /// Returns the [`Validator`] of `self`, if any.
+    pub fn validator(&self) -> Option<&Validator> {
+        self.validator.as_ref()
+    }
+
+    /// Validates inbound messages with `validator`, or not at all if `None`,
+    /// which is the default. Invalid messages are refused with a `Reject <3>`,
+    /// or with a `BusinessMessageReject <j>` if their `MsgType <35>` is
+    /// unsupported, and never reach the [`Backend`].
+    pub fn set_validator(&mut self, validator: Option<Validator>) {
+        self.validator = validator;
+    }
+
+    /// Returns the `DefaultApplVerID <1137>` of the current FIX session, if
+    /// any. Before the Logon handshake, this is
+    /// [`Configure::default_appl_ver_id`]; afterwards, it's the value agreed
+    /// upon with the counterparty.
+    pub fn default_appl_ver_id(&self) -> Option<&[u8]> {
+        self.default_appl_ver_id.as_deref()
+    }
+
+    /// Returns a new [`FixConnectionHandle`] to `self`.
+    pub fn handle(&self) -> FixConnectionHandle {
+        self.handle.clone()
+    }
+
+    /// Runs the FIX session as the initiator: `self` sends `Logon <A>` and then
+    /// processes events until the FIX session is over.
+    ///
+    /// Fails right away if the current time is outside of
+    /// [`Configure::schedule`].
+    pub async fn initiate<I, O>(
+        &mut self,
+        input: I,
+        output: O,
+        decoder: DecoderStreaming<Vec<u8>>,
+    ) -> io::Result<()>
     where
         I: AsyncRead + Unpin,
         O: AsyncWrite + Unpin,
     {
-        self.establish_connection(&mut input, &mut output, &mut decoder)
-            .await;
-        self.event_loop(input, output, decoder).await;
+        self.start(input, output, decoder, true).await
     }
 
-    async fn establish_connection<I, O>(
+    /// Runs the FIX session as the acceptor: `self` waits for the initiator's
+    /// `Logon <A>` and then processes events until the FIX session is over.
+    pub async fn accept<I, O>(
         &mut self,
-        mut input: &mut I,
-        output: &mut O,
-        decoder: &mut DecoderStreaming,
-    ) where
+        input: I,
+        output: O,
+        decoder: DecoderStreaming<Vec<u8>>,
+    ) -> io::Result<()>
+    where
+        I: AsyncRead + Unpin,
+        O: AsyncWrite + Unpin,
+    {
+        self.start(input, output, decoder, false).await
+    }
+
+    async fn start<I, O>(
+        &mut self,
+        input: I,
+        mut output: O,
+        decoder: DecoderStreaming<Vec<u8>>,
+        is_initiator: bool,
+    ) -> io::Result<()>
+    where
         I: AsyncRead + Unpin,
         O: AsyncWrite + Unpin,
     {
-        let logon = {
-            let begin_string = self.config.begin_string();
-            let sender_comp_id = self.config.sender_comp_id();
-            let target_comp_id = self.config.target_comp_id();
-            let heartbeat = self.config.heartbeat().as_secs();
-            let msg_seq_num = self.msg_seq_num_outbound.next();
-            let mut msg = self
-                .encoder
-                .start_message(begin_string, &mut self.buffer, b"A");
-            msg.set_fv_with_key(&SENDER_COMP_ID, sender_comp_id);
-            msg.set_fv_with_key(&TARGET_COMP_ID, target_comp_id);
-            msg.set_fv_with_key(&SENDING_TIME, chrono::Utc::now());
-            msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-            msg.set_fv_with_key(&ENCRYPT_METHOD, 0);
-            msg.set_fv_with_key(&108, heartbeat);
-            msg.done()
+        if is_initiator && !self.is_within_schedule() {
+            return Err(io::Error::other(errs::outside_schedule()));
+        }
+        let mut event_loop = LlEventLoop::new(decoder, input, self.heartbeat);
+        self.establish_connection(is_initiator);
+        let result = match self.flush(&mut output).await {
+            Ok(_) => self.event_loop(&mut event_loop, &mut output).await,
+            Err(err) => Err(err),
         };
-        output.write(logon).await.unwrap();
-        self.backend.on_outbound_message(logon).ok();
-        let logon;
-        loop {
-            let mut input = Pin::new(&mut input);
-            let buffer = decoder.supply_buffer();
-            input.read_exact(buffer).await.unwrap();
-            if let Ok(Some(())) = decoder.parse() {
-                logon = decoder.message();
-                break;
-            }
-        }
-        self.on_logon(logon);
-        self.backend.on_inbound_message(logon, true).ok();
-        decoder.clear();
-        self.msg_seq_num_inbound.next();
-        self.backend.on_successful_handshake().ok();
+        self.state = SessionState::Disconnected;
+        output.close().await.ok();
+        self.backend.on_disconnected(result.as_ref().err()).ok();
+        result
     }
 
-    async fn event_loop<I, O>(&mut self, input: I, mut output: O, decoder: DecoderStreaming)
+    /// Returns `true` if and only if the last Logon handshake of `self` was
+    /// successful.
+    pub(crate) fn has_logged_on(&self) -> bool {
+        self.has_logged_on
+    }
+
+    /// Returns `true` if and only if the last FIX session of `self` was
+    /// terminated via [`FixConnectionHandle::logout`].
+    pub(crate) fn is_logout_requested(&self) -> bool {
+        self.is_logout_requested
+    }
+
+    fn establish_connection(&mut self, is_initiator: bool) {
+        self.session_end = None;
+        self.reset_seq_num_flag = false;
+        self.default_appl_ver_id = self.config.default_appl_ver_id().map(|id| id.to_vec());
+        self.has_logged_on = false;
+        self.is_logout_requested = false;
+        self.heartbeat = self.config.heartbeat();
+        // Leftovers from previous connections, if any.
+        self.inbound_queue.clear();
+        self.resend_end = 0;
+        self.is_replaying = false;
+        if let Some(seq_numbers) = self.store.seq_numbers() {
+            self.seq_numbers = seq_numbers;
+        }
+        if is_initiator {
+            self.state = SessionState::LogonSent;
+            if self.should_reset_seq_numbers() {
+                self.reset_seq_numbers();
+                self.reset_seq_num_flag = true;
+            }
+            self.send_logon(None);
+        } else {
+            self.state = SessionState::AwaitingLogon;
+        }
+    }
+
+    async fn event_loop<I, O>(
+        &mut self,
+        event_loop: &mut LlEventLoop<I>,
+        output: &mut O,
+    ) -> io::Result<()>
     where
         I: AsyncRead + Unpin,
         O: AsyncWrite + Unpin,
     {
-        let event_loop = &mut LlEventLoop::new(decoder, input, self.heartbeat());
         loop {
-            let event = event_loop
-                .next_event()
-                .await
-                .expect("The connection died unexpectedly.");
-            match event {
-                LlEvent::Message(msg) => {
-                    let response = self.on_inbound_message(msg, unimplemented!());
-                    match response {
-                        Response::OutboundBytes(bytes) => {
-                            output.write_all(bytes).await.unwrap();
-                            self.on_outbound_message(bytes).ok();
-                        }
-                        Response::ResetHeartbeat => {
-                            event_loop.ping_heartbeat();
+            let was_active = self.state == SessionState::Active;
+            let is_message;
+            let response = {
+                let input = {
+                    // Outbound application messages must wait for the Logon
+                    // handshake.
+                    let mut next_command = if was_active {
+                        self.commands.next().fuse()
+                    } else {
+                        Fuse::terminated()
+                    };
+                    let mut end_of_session = match self.session_end {
+                        Some(end) => {
+                            Delay::new(end.saturating_duration_since(Instant::now())).fuse()
                         }
-                        _ => {}
+                        None => Fuse::terminated(),
+                    };
+                    let next_event = event_loop.next_event().fuse();
+                    pin_mut!(next_event);
+                    select! {
+                        event = next_event => Input::Event(event),
+                        command = next_command => Input::Command(command),
+                        () = end_of_session => Input::EndOfSession,
                     }
+                };
+                is_message = matches!(input, Input::Event(Some(LlEvent::Message(_))));
+                match input {
+                    Input::Event(Some(event)) => self.on_event(event)?,
+                    Input::Event(None) => Response::TerminateTransport,
+                    Input::Command(Some(command)) => self.on_command(command),
+                    Input::Command(None) => Response::None,
+                    Input::EndOfSession => self.on_end_of_session(),
                 }
-                LlEvent::BadMessage(_err) => {}
-                LlEvent::IoError(_) => {
-                    return;
+            };
+            // Replaying is only possible at message boundaries.
+            if is_message {
+                if let Some(message) = self.next_queued_message() {
+                    event_loop.replay(&message[..]);
                 }
-                LlEvent::Heartbeat => {
-                    let heartbeat = self.on_heartbeat_is_due();
-                    output.write_all(heartbeat).await.unwrap();
-                    self.on_outbound_message(heartbeat).ok();
+            }
+            if self.flush(output).await? {
+                event_loop.ping_outbound();
+            }
+            if !was_active && self.state == SessionState::Active {
+                event_loop.set_heartbeat(self.heartbeat);
+                event_loop
+                    .decoder_mut()
+                    .decoder_mut()
+                    .set_default_appl_ver_id(self.default_appl_ver_id.as_deref());
+                self.session_end = self.current_session_end();
+            }
+            match response {
+                Response::ResetHeartbeat => event_loop.ping_heartbeat(),
+                Response::TerminateTransport => return Ok(()),
+                Response::None | Response::LogGarbled => {}
+            }
+            if self.state == SessionState::Disconnected {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Persists the current [`SeqNumbers`] and then writes all pending
+    /// outbound messages to `output`. Returns `true` if there was something to
+    /// write.
+    async fn flush<O>(&mut self, output: &mut O) -> io::Result<bool>
+    where
+        O: AsyncWrite + Unpin,
+    {
+        if let Some(err) = self.store_error.take() {
+            return Err(err);
+        }
+        if self.store.seq_numbers() != Some(self.seq_numbers) {
+            self.store.store_seq_numbers(self.seq_numbers)?;
+        }
+        if self.buffer.is_empty() {
+            return Ok(false);
+        }
+        output.write_all(&self.buffer[..]).await?;
+        output.flush().await?;
+        self.buffer.clear();
+        Ok(true)
+    }
+
+    fn on_event(&mut self, event: LlEvent) -> io::Result<Response> {
+        Ok(match event {
+            LlEvent::Message(msg) => self.on_inbound_message(msg),
+            LlEvent::BadMessage(err, garbled) => {
+                self.backend.on_garbled_message(garbled, &err).ok();
+                Response::LogGarbled
+            }
+            LlEvent::IoError(err) => {
+                if self.state == SessionState::LogoutSent
+                    && err.kind() == io::ErrorKind::UnexpectedEof
+                {
+                    // The counterparty didn't bother to answer our `Logout
+                    // <5>`.
+                    Response::TerminateTransport
+                } else {
+                    return Err(err);
                 }
-                LlEvent::Logout => {}
-                LlEvent::TestRequest => {}
+            }
+            LlEvent::Heartbeat => {
+                self.backend.on_heartbeat_is_due().ok();
+                self.send_message(b"0", |_| {});
+                Response::None
+            }
+            LlEvent::TestRequest => {
+                let test_req_id = self.seq_numbers.next_outbound();
+                self.send_message(b"1", |msg| {
+                    msg.set(TEST_REQ_ID, test_req_id);
+                });
+                Response::None
+            }
+            LlEvent::Logout => {
+                self.make_logout(Some("Heartbeat timeout"));
+                Response::TerminateTransport
+            }
+        })
+    }
+
+    fn on_command(&mut self, command: Command) -> Response {
+        match command {
+            Command::Send { msg_type, fill } => {
+                self.send_message(&msg_type[..], |msg| fill(msg));
+            }
+            Command::Logout { text } => {
+                self.is_logout_requested = true;
+                self.make_logout(text.as_deref());
+                self.state = SessionState::LogoutSent;
             }
         }
+        Response::None
     }
-}
 
-pub trait Verify {
-    type Error;
+    fn on_end_of_session(&mut self) -> Response {
+        self.session_end = None;
+        if self.state == SessionState::Active {
+            self.make_logout(Some(errs::end_of_session().as_str()));
+            self.state = SessionState::LogoutSent;
+        }
+        Response::None
+    }
 
-    fn verify_begin_string(&self, begin_string: &[u8]) -> Result<(), Self::Error>;
+    /// Validates an inbound FIX message and then dispatches it according to
+    /// its `MsgType <35>`.
+    pub fn on_inbound_message(&mut self, msg: Message<&[u8]>) -> Response {
+        let is_replay = std::mem::take(&mut self.is_replaying);
+        let msg_type = if let Some(msg_type) = msg.fv_raw(MSG_TYPE) {
+            msg_type
+        } else {
+            return Response::LogGarbled;
+        };
 
-    fn verify_test_message_indicator(
-        &self,
-        msg: &impl FieldMap<u32>,
-    ) -> Result<(), Self::Error>;
+        if matches!(
+            self.state,
+            SessionState::AwaitingLogon | SessionState::LogonSent
+        ) && msg_type != b"A"
+        {
+            // The first message must always be `Logon <A>`. See §4.1.
+            return Response::TerminateTransport;
+        }
 
-    fn verify_sending_time(&self, msg: &impl FieldMap<u32>) -> Result<(), Self::Error>;
-}
+        if msg.fv_raw(BEGIN_STRING) != Some(self.config.begin_string()) {
+            self.make_logout(Some("Invalid BeginString(8)"));
+            return Response::TerminateTransport;
+        }
 
-impl<'a, B, C, V> FixConnector<'a, B, C, V> for FixConnection<B, C>
-where
-    B: Backend,
-    C: Configure,
-    V: Verify,
-{
-    type Error = &'a [u8];
-    type Msg = EncoderHandle<'a, Vec<u8>>;
+        if !self.verify_comp_ids(&msg) {
+            let ref_seq_num = msg.fv(MSG_SEQ_NUM).unwrap_or(0);
+            self.make_reject(
+                ref_seq_num,
+                Some(SENDER_COMP_ID),
+                Some(msg_type),
+                COMP_ID_PROBLEM,
+                "Invalid SenderCompID(49) or TargetCompID(56)",
+            );
+            self.make_logout(Some("Invalid SenderCompID(49) or TargetCompID(56)"));
+            return Response::TerminateTransport;
+        }
+
+        if self.config.verify_test_indicator()
+            && msg.fv::<bool>(TEST_MESSAGE_INDICATOR) == Ok(true)
+            && !self.config.environment().allows_testing()
+        {
+            self.make_logout(Some(errs::production_env().as_str()));
+            return Response::TerminateTransport;
+        }
+
+        if msg_type == b"A" && self.state == SessionState::AwaitingLogon {
+            if !self.is_within_schedule() {
+                self.make_logout(Some(errs::outside_schedule().as_str()));
+                return Response::TerminateTransport;
+            }
+            // Seq. numbers must be reset before validating `MsgSeqNum <34>`.
+            // See §4.7.
+            self.reset_seq_num_flag = msg.fv::<bool>(RESET_SEQ_NUM_FLAG) == Ok(true);
+            if self.reset_seq_num_flag || self.should_reset_seq_numbers() {
+                self.reset_seq_numbers();
+            }
+        }
+
+        if msg_type == b"4" && msg.fv::<bool>(GAP_FILL_FLAG) != Ok(true) {
+            // `MsgSeqNum <34>` must be ignored in Reset mode. See §4.8.
+            return self.on_sequence_reset(msg);
+        }
 
-    fn on_inbound_app_message(&mut self, message: Message<&[u8]>) -> Result<(), Self::Error> {
-        Ok(())
+        let msg_seq_num = match msg.fv::<u64>(MSG_SEQ_NUM) {
+            Ok(n) => n,
+            Err(_) => return self.on_missing_seqnum(),
+        };
+        match self.seq_numbers.validate_inbound(msg_seq_num) {
+            Ok(()) => {}
+            Err(SeqNumberError::TooLow) => return self.on_low_seqnum(msg),
+            Err(SeqNumberError::Recover) => return self.on_high_seqnum(msg, msg_seq_num),
+            Err(SeqNumberError::NoSeqNum) => return self.on_missing_seqnum(),
+        }
+        self.seq_numbers.incr_inbound();
+
+        match msg.fv::<Timestamp>(SENDING_TIME) {
+            Ok(sending_time) => {
+                // Queued messages have been waiting for a while, so their
+                // `SendingTime <52>` is most likely stale.
+                if !is_replay && !self.verify_sending_time(sending_time) {
+                    self.make_reject(
+                        msg_seq_num,
+                        Some(SENDING_TIME),
+                        Some(msg_type),
+                        SENDING_TIME_ACCURACY_PROBLEM,
+                        "Bad SendingTime(52)",
+                    );
+                    self.make_logout(Some("Bad SendingTime(52)"));
+                    return Response::TerminateTransport;
+                }
+            }
+            Err(_) => {
+                self.make_reject(
+                    msg_seq_num,
+                    Some(SENDING_TIME),
+                    Some(msg_type),
+                    REQUIRED_TAG_MISSING,
+                    errs::missing_field("SendingTime", SENDING_TIME).as_str(),
+                );
+                return Response::ResetHeartbeat;
+            }
+        }
+
+        if let Some(validator) = &self.validator {
+            if let Err(err) = validator.validate(&msg) {
+                return self.on_invalid_message(msg_type, msg_seq_num, err);
+            }
+        }
+
+        self.dispatch_by_msg_type(msg_type, msg)
     }
 
-    fn on_outbound_message(&mut self, message: &[u8]) -> Result<(), Self::Error> {
-        Ok(())
+    fn dispatch_by_msg_type(&mut self, msg_type: &[u8], msg: Message<&[u8]>) -> Response {
+        let is_app = !is_admin_msg_type(msg_type);
+        if !is_app {
+            self.backend.on_inbound_message(msg, false).ok();
+        }
+        match msg_type {
+            b"A" => self.on_logon(msg),
+            b"0" => Response::ResetHeartbeat,
+            b"1" => self.on_test_request(msg),
+            b"2" => self.on_resend_request(msg),
+            b"3" => Response::ResetHeartbeat,
+            b"4" => self.on_sequence_reset(msg),
+            b"5" => self.on_logout(),
+            _ => self.on_application_message(msg_type, msg),
+        }
     }
 
-    fn verifier(&self) -> V {
-        unimplemented!()
+    fn verify_comp_ids(&self, msg: &Message<&[u8]>) -> bool {
+        // The counterparty's `SenderCompID <49>` is our `TargetCompID <56>`
+        // and vice versa.
+        msg.fv_raw(SENDER_COMP_ID) == Some(self.config.target_comp_id())
+            && msg.fv_raw(TARGET_COMP_ID) == Some(self.config.sender_comp_id())
     }
 
-    fn environment(&self) -> Environment {
-        self.config.environment()
+    fn verify_sending_time(&self, sending_time: Timestamp) -> bool {
+        let max_allowed_latency = self.config.max_allowed_latency().as_millis();
+        if let Some(sending_time) = timestamp_to_chrono_utc(sending_time) {
+            let latency = chrono::Utc::now() - sending_time;
+            (latency.num_milliseconds().unsigned_abs() as u128) <= max_allowed_latency
+        } else {
+            false
+        }
     }
 
-    fn sender_comp_id(&self) -> &[u8] {
-        self.config.sender_comp_id()
+    fn on_logon(&mut self, msg: Message<&[u8]>) -> Response {
+        let heartbeat = match msg.fv::<u64>(HEART_BT_INT) {
+            Ok(secs) => Duration::from_secs(secs),
+            Err(_) => {
+                self.make_logout(Some(
+                    errs::missing_field("HeartBtInt", HEART_BT_INT).as_str(),
+                ));
+                return Response::TerminateTransport;
+            }
+        };
+        if self.is_fixt() {
+            match msg.fv_raw(DEFAULT_APPL_VER_ID) {
+                Some(appl_ver_id) => self.default_appl_ver_id = Some(appl_ver_id.to_vec()),
+                None if self.default_appl_ver_id.is_none() => {
+                    self.make_logout(Some(
+                        errs::missing_field("DefaultApplVerID", DEFAULT_APPL_VER_ID).as_str(),
+                    ));
+                    return Response::TerminateTransport;
+                }
+                None => {}
+            }
+        }
+        match self.state {
+            SessionState::AwaitingLogon => {
+                if let Err(text) = self.config.heartbeat_rule().validate(&heartbeat) {
+                    self.make_logout(Some(text.as_str()));
+                    return Response::TerminateTransport;
+                }
+                if let Err(rejection) = self.backend.on_inbound_logon(msg) {
+                    self.reject_logon(rejection);
+                    return Response::TerminateTransport;
+                }
+                self.heartbeat = heartbeat;
+                let session_status = if msg.fv_raw(NEW_PASSWORD).is_some() {
+                    SESSION_PASSWORD_CHANGED
+                } else {
+                    SESSION_ACTIVE
+                };
+                self.send_logon(Some(session_status));
+            }
+            SessionState::LogonSent => {
+                if let Err(rejection) = self.backend.on_inbound_logon(msg) {
+                    self.reject_logon(rejection);
+                    return Response::TerminateTransport;
+                }
+            }
+            _ => {
+                self.make_logout(Some("Unexpected Logon(A)"));
+                return Response::TerminateTransport;
+            }
+        }
+        self.state = SessionState::Active;
+        self.has_logged_on = true;
+        self.backend.on_successful_handshake().ok();
+        Response::ResetHeartbeat
     }
 
-    fn target_comp_id(&self) -> &[u8] {
-        self.config.target_comp_id()
+    fn on_test_request(&mut self, msg: Message<&[u8]>) -> Response {
+        if let Some(test_req_id) = msg.fv_raw(TEST_REQ_ID) {
+            self.send_message(b"0", |heartbeat| {
+                heartbeat.set(TEST_REQ_ID, test_req_id);
+            });
+        } else {
+            let msg_seq_num = msg.fv(MSG_SEQ_NUM).unwrap_or(0);
+            self.make_reject(
+                msg_seq_num,
+                Some(TEST_REQ_ID),
+                Some(b"1"),
+                REQUIRED_TAG_MISSING,
+                errs::missing_field("TestReqID", TEST_REQ_ID).as_str(),
+            );
+        }
+        Response::ResetHeartbeat
     }
 
-    fn heartbeat(&self) -> Duration {
-        self.config.heartbeat()
+    fn on_resend_request(&mut self, msg: Message<&[u8]>) -> Response {
+        let msg_seq_num = msg.fv(MSG_SEQ_NUM).unwrap_or(0);
+        let begin_seq_no = if let Ok(n) = msg.fv::<u64>(BEGIN_SEQ_NO) {
+            n
+        } else {
+            self.make_reject(
+                msg_seq_num,
+                Some(BEGIN_SEQ_NO),
+                Some(b"2"),
+                REQUIRED_TAG_MISSING,
+                errs::missing_field("BeginSeqNo", BEGIN_SEQ_NO).as_str(),
+            );
+            return Response::ResetHeartbeat;
+        };
+        let next_outbound = self.seq_numbers.next_outbound();
+        let end_seq_no = match msg.fv::<u64>(END_SEQ_NO) {
+            // Zero means "infinity". See §4.6.
+            Ok(0) => next_outbound - 1,
+            Ok(n) => n.min(next_outbound - 1),
+            Err(_) => {
+                self.make_reject(
+                    msg_seq_num,
+                    Some(END_SEQ_NO),
+                    Some(b"2"),
+                    REQUIRED_TAG_MISSING,
+                    errs::missing_field("EndSeqNo", END_SEQ_NO).as_str(),
+                );
+                return Response::ResetHeartbeat;
+            }
+        };
+        if begin_seq_no == 0 || begin_seq_no > end_seq_no {
+            return Response::ResetHeartbeat;
+        }
+        self.backend
+            .on_resend_request(begin_seq_no..end_seq_no + 1)
+            .ok();
+        let messages = match self.store.fetch(begin_seq_no..end_seq_no + 1) {
+            Ok(messages) => messages,
+            Err(err) => {
+                self.store_error = Some(err);
+                Vec::new()
+            }
+        };
+        // Admin messages and messages missing from the store are replaced by
+        // `SequenceReset <4>` with `GapFillFlag <123>`. See §4.6.
+        let separator = self.encoder.config().separator();
+        let mut gap_start = None;
+        let mut expected = begin_seq_no;
+        for (msg_seq_num, message) in messages {
+            if msg_seq_num > expected {
+                gap_start.get_or_insert(expected);
+            }
+            if let Some(stored) = StoredMessage::parse(&message[..], separator) {
+                if let Some(gap_start) = gap_start.take() {
+                    self.make_gap_fill(gap_start, msg_seq_num);
+                }
+                self.resend_message(msg_seq_num, stored);
+            } else {
+                gap_start.get_or_insert(msg_seq_num);
+            }
+            expected = msg_seq_num + 1;
+        }
+        if expected <= end_seq_no {
+            gap_start.get_or_insert(expected);
+        }
+        if let Some(gap_start) = gap_start {
+            self.make_gap_fill(gap_start, end_seq_no + 1);
+        }
+        Response::ResetHeartbeat
     }
-}
 
-pub struct MessageBuilder {}
+    /// Processes `SequenceReset <4>` in both modes. GapFill messages have
+    /// already gone through `MsgSeqNum <34>` validation, Reset ones skip it.
+    fn on_sequence_reset(&mut self, msg: Message<&[u8]>) -> Response {
+        let msg_seq_num = msg.fv(MSG_SEQ_NUM).unwrap_or(0);
+        match msg.fv::<u64>(NEW_SEQ_NO) {
+            Ok(new_seq_no) if new_seq_no >= self.seq_numbers.next_inbound() => {
+                self.seq_numbers.next_inbound = new_seq_no;
+            }
+            Ok(_) => {
+                // See §4.8.
+                self.make_reject(
+                    msg_seq_num,
+                    Some(NEW_SEQ_NO),
+                    Some(b"4"),
+                    VALUE_IS_INCORRECT,
+                    "NewSeqNo(36) is lower than the expected MsgSeqNum(34)",
+                );
+            }
+            Err(_) => {
+                self.make_reject(
+                    msg_seq_num,
+                    Some(NEW_SEQ_NO),
+                    Some(b"4"),
+                    REQUIRED_TAG_MISSING,
+                    errs::missing_field("NewSeqNo", NEW_SEQ_NO).as_str(),
+                );
+            }
+        }
+        Response::ResetHeartbeat
+    }
 
-pub struct MessageBuiderTuple<'a> {
-    phantom: PhantomData<&'a ()>,
-}
+    fn on_logout(&mut self) -> Response {
+        if self.state != SessionState::LogoutSent {
+            // The counterparty has initiated the Logout handshake and we must
+            // confirm.
+            self.make_logout(None);
+        }
+        self.state = SessionState::Disconnected;
+        Response::TerminateTransport
+    }
 
-impl<'a> MessageBuiderTuple<'a> {
-    pub fn get(self) -> (EncoderHandle<'a, Vec<u8>>, &'a mut MessageBuilder) {
-        unimplemented!()
+    fn on_application_message(&mut self, msg_type: &[u8], msg: Message<&[u8]>) -> Response {
+        if let Err(err) = self.backend.on_inbound_message(msg, true) {
+            let msg_seq_num = msg.fv(MSG_SEQ_NUM).unwrap_or(0);
+            let text = err.to_string();
+            self.send_message(b"j", |reject| {
+                reject.set(REF_SEQ_NUM, msg_seq_num);
+                reject.set(REF_MSG_TYPE, msg_type);
+                reject.set(BUSINESS_REJECT_REASON, BUSINESS_REJECT_OTHER);
+                reject.set(TEXT, text.as_str());
+            });
+        }
+        Response::ResetHeartbeat
     }
-}
 
-impl MessageBuilder {
-    pub fn start_message(&mut self, begin_string: &[u8], msg_type: &[u8]) -> MessageBuiderTuple {
-        unimplemented!()
+    fn on_invalid_message(
+        &mut self,
+        msg_type: &[u8],
+        msg_seq_num: u64,
+        err: ValidationError,
+    ) -> Response {
+        let text = err.to_string();
+        if msg_type == b"A" && self.state != SessionState::Active {
+            self.make_logout(Some(text.as_str()));
+            return Response::TerminateTransport;
+        }
+        if err == ValidationError::InvalidMsgType && !is_admin_msg_type(msg_type) {
+            self.send_message(b"j", |reject| {
+                reject.set(REF_SEQ_NUM, msg_seq_num);
+                reject.set(REF_MSG_TYPE, msg_type);
+                reject.set(BUSINESS_REJECT_REASON, UNSUPPORTED_MESSAGE_TYPE);
+                reject.set(TEXT, text.as_str());
+            });
+        } else {
+            self.make_reject(
+                msg_seq_num,
+                err.ref_tag_id(),
+                Some(msg_type),
+                err.session_reject_reason(),
+                text.as_str(),
+            );
+        }
+        Response::ResetHeartbeat
     }
-}
 
-struct ResponseData<'a> {
-    pub begin_stringt: &'a [u8],
-    pub msg_type: &'a [u8],
-    pub msg_seq_num: u32,
-}
+    fn on_missing_seqnum(&mut self) -> Response {
+        // See §4.5.3.
+        self.make_logout(Some(errs::missing_field("MsgSeqNum", MSG_SEQ_NUM).as_str()));
+        Response::TerminateTransport
+    }
 
-pub trait FixConnector<'a, B, C, Z>
-where
-    B: Backend,
-    C: Configure,
-    Z: Verify,
-{
-    type Error: FieldType<'a>;
-    type Msg: FvWrite<'a>;
+    fn on_low_seqnum(&mut self, msg: Message<&[u8]>) -> Response {
+        if msg.fv::<bool>(POSS_DUP_FLAG) == Ok(true) {
+            // A duplicate that we've already processed. Just ignore it.
+            Response::ResetHeartbeat
+        } else {
+            let text = errs::msg_seq_num(self.seq_numbers.next_inbound());
+            self.make_logout(Some(text.as_str()));
+            Response::TerminateTransport
+        }
+    }
 
-    fn target_comp_id(&self) -> &[u8];
+    fn on_high_seqnum(&mut self, msg: Message<&[u8]>, msg_seq_num: u64) -> Response {
+        if msg.fv_raw(MSG_TYPE) == Some(b"A") {
+            // The Logon must be processed before asking for the missing
+            // messages, itself included. See §4.8.
+            let response = self.on_logon(msg);
+            if response != Response::TerminateTransport {
+                self.request_missing_messages(msg_seq_num);
+            }
+            response
+        } else {
+            // Out-of-order messages are processed later, once the gap is
+            // filled. See `FixConnection::next_queued_message`.
+            self.inbound_queue
+                .insert(msg_seq_num, msg.as_bytes().to_vec());
+            Response::ResetHeartbeat
+        }
+    }
 
-    fn sender_comp_id(&self) -> &[u8];
+    /// Returns the queued inbound message that must be processed next, if
+    /// any. Otherwise, asks for the messages that are still missing.
+    fn next_queued_message(&mut self) -> Option<Vec<u8>> {
+        let next_inbound = self.seq_numbers.next_inbound();
+        // Messages that were skipped by `SequenceReset <4>` are obsolete.
+        self.inbound_queue = self.inbound_queue.split_off(&next_inbound);
+        if let Some(message) = self.inbound_queue.remove(&next_inbound) {
+            self.is_replaying = true;
+            return Some(message);
+        }
+        if let Some(first_queued) = self.inbound_queue.keys().next().copied() {
+            self.request_missing_messages(first_queued - 1);
+        }
+        None
+    }
 
-    fn verifier(&self) -> &Z;
+    /// Sends a `ResendRequest <2>` for the inbound messages up to
+    /// `last_missing`, unless there's one still pending. Requests are split
+    /// into chunks according to [`Configure::max_resend_range`].
+    fn request_missing_messages(&mut self, last_missing: u64) {
+        let next_inbound = self.seq_numbers.next_inbound();
+        if next_inbound <= self.resend_end || next_inbound > last_missing {
+            return;
+        }
+        let end = match self.config.max_resend_range() {
+            Some(max) => last_missing.min(next_inbound + max.max(1) - 1),
+            None => last_missing,
+        };
+        self.resend_end = end;
+        self.make_resend_request(next_inbound, end);
+    }
 
-    fn dispatch_by_msg_type(&self, msg_type: &[u8], msg: Message<&[u8]>) -> Response {
-        match msg_type {
-            b"A" => {
-                self.on_logon(msg);
-                return Response::None;
+    /// Sends `Logon <A>`. `session_status` only makes it into FIXT.1.1
+    /// messages.
+    fn send_logon(&mut self, session_status: Option<u32>) {
+        let heartbeat = self.heartbeat.as_secs();
+        let reset_seq_num_flag = self.reset_seq_num_flag;
+        let session_status = session_status.filter(|_| self.is_fixt());
+        let default_appl_ver_id = self.default_appl_ver_id.clone().filter(|_| self.is_fixt());
+        self.send_message_with(b"A", |logon, backend| {
+            logon.set(ENCRYPT_METHOD, 0u32);
+            logon.set(HEART_BT_INT, heartbeat);
+            if reset_seq_num_flag {
+                logon.set(RESET_SEQ_NUM_FLAG, true);
             }
-            b"1" => {
-                let msg = self.on_test_request(msg);
-                return Response::OutboundBytes(msg);
+            if let Some(default_appl_ver_id) = default_appl_ver_id {
+                logon.set(DEFAULT_APPL_VER_ID, default_appl_ver_id.as_slice());
             }
-            b"2" => {
-                return Response::None;
+            if let Some(session_status) = session_status {
+                logon.set(SESSION_STATUS, session_status);
             }
-            b"5" => {
-                return Response::OutboundBytes(self.on_logout(&msg));
+            backend.on_outbound_logon(logon);
+        });
+    }
+
+    fn reject_logon(&mut self, rejection: LogonRejection) {
+        let text = rejection.to_string();
+        let session_status = rejection.session_status().filter(|_| self.is_fixt());
+        self.send_message(b"5", |logout| {
+            logout.set(TEXT, text.as_str());
+            if let Some(session_status) = session_status {
+                logout.set(SESSION_STATUS, session_status);
             }
-            b"0" => {
-                self.on_heartbeat(msg);
-                return Response::ResetHeartbeat;
+        });
+    }
+
+    fn is_fixt(&self) -> bool {
+        self.config.begin_string() == b"FIXT.1.1"
+    }
+
+    fn is_within_schedule(&self) -> bool {
+        match self.config.schedule() {
+            Some(schedule) => schedule.contains(chrono::Utc::now()),
+            None => true,
+        }
+    }
+
+    fn current_session_end(&self) -> Option<Instant> {
+        let now = chrono::Utc::now();
+        let session = self.config.schedule()?.current_session(now)?;
+        Some(Instant::now() + (session.end - now).to_std().unwrap_or_default())
+    }
+
+    /// Applies [`Configure::reset_seq_num_policy`].
+    fn should_reset_seq_numbers(&self) -> bool {
+        match self.config.reset_seq_num_policy() {
+            ResetSeqNumPolicy::Never => false,
+            ResetSeqNumPolicy::Always => true,
+            ResetSeqNumPolicy::NewSession => {
+                let now = chrono::Utc::now();
+                let session_start = match self.config.schedule() {
+                    Some(schedule) => schedule.current_session(now).map(|s| s.start),
+                    None => Some(now.date().and_hms(0, 0, 0)),
+                };
+                match (session_start, self.store.creation_time()) {
+                    (Some(start), Some(creation_time)) => {
+                        chrono::DateTime::<chrono::Utc>::from(creation_time) < start
+                    }
+                    _ => false,
+                }
             }
-            _ => {
-                return self.on_application_message(msg);
+        }
+    }
+
+    fn reset_seq_numbers(&mut self) {
+        if let Err(err) = self.store.reset() {
+            self.store_error = Some(err);
+        }
+        self.seq_numbers = SeqNumbers::default();
+        self.inbound_queue.clear();
+        self.resend_end = 0;
+    }
+
+    fn make_reject(
+        &mut self,
+        ref_seq_num: u64,
+        ref_tag: Option<u32>,
+        ref_msg_type: Option<&[u8]>,
+        reason: u32,
+        err_text: &str,
+    ) {
+        self.send_message(b"3", |msg| {
+            msg.set(REF_SEQ_NUM, ref_seq_num);
+            if let Some(ref_tag) = ref_tag {
+                msg.set(REF_TAG_ID, ref_tag);
+            }
+            if let Some(ref_msg_type) = ref_msg_type {
+                msg.set(REF_MSG_TYPE, ref_msg_type);
+            }
+            msg.set(SESSION_REJECT_REASON, reason);
+            msg.set(TEXT, err_text);
+        });
+    }
+
+    fn make_logout(&mut self, text: Option<&str>) {
+        self.send_message(b"5", |msg| {
+            if let Some(text) = text {
+                msg.set(TEXT, text);
             }
+        });
+    }
+
+    fn make_resend_request(&mut self, start: u64, end: u64) {
+        self.send_message(b"2", |msg| {
+            msg.set(BEGIN_SEQ_NO, start);
+            msg.set(END_SEQ_NO, end);
+        });
+    }
+
+    fn make_gap_fill(&mut self, msg_seq_num: u64, new_seq_no: u64) {
+        self.encode_message(b"4", msg_seq_num, |msg| {
+            msg.set(POSS_DUP_FLAG, true);
+            msg.set(GAP_FILL_FLAG, true);
+            msg.set(NEW_SEQ_NO, new_seq_no);
+        });
+    }
+
+    /// Encodes a new outbound message with the next outbound `MsgSeqNum <34>`.
+    fn send_message<F>(&mut self, msg_type: &[u8], f: F)
+    where
+        F: FnOnce(&mut EncoderHandle<Vec<u8>>),
+    {
+        self.send_message_with(msg_type, |msg, _| f(msg));
+    }
+
+    /// Like [`FixConnection::send_message`], but `f` also has access to the
+    /// [`Backend`].
+    fn send_message_with<F>(&mut self, msg_type: &[u8], f: F)
+    where
+        F: FnOnce(&mut EncoderHandle<Vec<u8>>, &mut B),
+    {
+        let msg_seq_num = self.seq_numbers.next_outbound();
+        self.seq_numbers.incr_outbound();
+        let offset = self.buffer.len();
+        self.encode_message_with(msg_type, msg_seq_num, f);
+        if let Err(err) = self.store.store(msg_seq_num, &self.buffer[offset..]) {
+            self.store_error = Some(err);
         }
     }
 
-    /// Callback for processing incoming FIX application messages.
-    fn on_inbound_app_message(&mut self, message: Message<&[u8]>) -> Result<(), Self::Error>;
+    /// Encodes a stored message again, with `PossDupFlag <43>` and
+    /// `OrigSendingTime <122>`.
+    fn resend_message(&mut self, msg_seq_num: u64, stored: StoredMessage) {
+        self.encode_message(stored.msg_type, msg_seq_num, |msg| {
+            msg.set(POSS_DUP_FLAG, true);
+            if let Some(orig_sending_time) = stored.orig_sending_time {
+                msg.set(ORIG_SENDING_TIME, orig_sending_time);
+            }
+            msg.write_raw(stored.body);
+        });
+    }
 
-    /// Callback for post-processing outbound FIX messages.
-    fn on_outbound_message(&mut self, message: &[u8]) -> Result<(), Self::Error>;
+    fn encode_message<F>(&mut self, msg_type: &[u8], msg_seq_num: u64, f: F)
+    where
+        F: FnOnce(&mut EncoderHandle<Vec<u8>>),
+    {
+        self.encode_message_with(msg_type, msg_seq_num, |msg, _| f(msg));
+    }
 
-    fn environment(&self) -> Environment;
+    fn encode_message_with<F>(&mut self, msg_type: &[u8], msg_seq_num: u64, f: F)
+    where
+        F: FnOnce(&mut EncoderHandle<Vec<u8>>, &mut B),
+    {
+        let mut msg =
+            self.encoder
+                .start_message(self.config.begin_string(), &mut self.buffer, msg_type);
+        msg.set(SENDER_COMP_ID, self.config.sender_comp_id());
+        msg.set(TARGET_COMP_ID, self.config.target_comp_id());
+        msg.set(MSG_SEQ_NUM, msg_seq_num);
+        msg.set_with(SENDING_TIME, Timestamp::utc_now(), TimePrecision::Millis);
+        f(&mut msg, &mut self.backend);
+        let (bytes, offset) = msg.done();
+        self.backend.on_outbound_message(&bytes[offset..]).ok();
+    }
+}
 
-    fn heartbeat(&self) -> Duration;
+/// The parts of a stored outbound message that are needed to resend it.
+struct StoredMessage<'a> {
+    msg_type: &'a [u8],
+    orig_sending_time: Option<&'a [u8]>,
+    body: &'a [u8],
+}
 
-    fn seq_numbers(&self) -> SeqNumbers;
+impl<'a> StoredMessage<'a> {
+    /// Returns [`None`] if `message` must not be resent, i.e. it's an admin
+    /// message other than `Reject <3>`.
+    fn parse(message: &'a [u8], separator: u8) -> Option<Self> {
+        let frame = RawDecoder::<DecoderConfig>::new().decode(message).ok()?;
+        let payload = &message[frame.payload];
+        let mut msg_type = None;
+        let mut orig_sending_time = None;
+        // Header fields are always encoded first, so the rest of the message
+        // can be copied as-is.
+        let mut body_start = 0;
+        for field in payload.split_inclusive(|byte| *byte == separator) {
+            let i_eq = field.iter().position(|byte| *byte == b'=').unwrap_or(0);
+            let value = &field[i_eq + 1..field.len() - 1];
+            match &field[..i_eq] {
+                b"35" => msg_type = Some(value),
+                b"52" => orig_sending_time = Some(value),
+                b"34" | b"43" | b"49" | b"56" | b"97" | b"122" => {}
+                _ => break,
+            }
+            body_start += field.len();
+        }
+        let msg_type = msg_type.filter(|t| *t == b"3" || !is_admin_msg_type(t))?;
+        Some(Self {
+            msg_type,
+            orig_sending_time,
+            body: &payload[body_start..],
+        })
+    }
+}
+
+fn is_admin_msg_type(msg_type: &[u8]) -> bool {
+    matches!(msg_type, b"0" | b"1" | b"2" | b"3" | b"4" | b"5" | b"A")
+}
+
+fn timestamp_to_chrono_utc(timestamp: Timestamp) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+
+    let date = timestamp.date();
+    let time = timestamp.time();
+    let naive = chrono::NaiveDate::from_ymd_opt(date.year() as i32, date.month(), date.day())?
+        .and_hms_nano_opt(time.hour(), time.minute(), time.second(), time.nano())?;
+    Some(chrono::Utc.from_utc_datetime(&naive))
+}
 
-    fn msg_seq_num(&mut self) -> &mut MsgSeqNumCounter;
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::session::{SessionDays, SessionSchedule};
+    use crate::tagvalue::Decoder;
+    use crate::Dictionary;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt as _};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_util::compat::*;
+
+    #[derive(Debug, Clone, Default)]
+    struct TestBackend {
+        inbound_app_messages: Arc<Mutex<Vec<(String, Option<String>)>>>,
+        handshakes: Arc<Mutex<u32>>,
+        // Both sent and required in `Logon <A>`, if any.
+        password: Option<String>,
+    }
+
+    impl Backend for TestBackend {
+        type Error = String;
+
+        fn sender_comp_id(&self) -> &[u8] {
+            b"SENDER"
+        }
 
-    fn on_inbound_message(
-        &'a mut self,
-        msg: Message<&[u8]>,
-        builder: MessageBuilder,
-    ) -> Response<'a> {
-        if self.verifier().verify_test_message_indicator(msg).is_err() {
-            return self.on_wrong_environment(msg);
+        fn target_comp_id(&self) -> &[u8] {
+            b"TARGET"
         }
-        let seq_num = if let Ok(n) = msg.fv::<u64>(&MSG_SEQ_NUM) {
-            let expected = self.msg_seq_num_inbound.expected();
-            if n < expected {
-                return self.on_low_seqnum(msg);
-            } else if n > expected {
-                // Refer to specs. §4.8 for more information.
-                return self.on_high_seqnum(msg);
+
+        fn on_inbound_app_message(&mut self, message: Message<&[u8]>) -> Result<(), String> {
+            let msg_type = message.fv::<&str>(MSG_TYPE).unwrap().to_string();
+            let cl_ord_id = message.fv::<&str>(11).ok().map(|s| s.to_string());
+            self.inbound_app_messages
+                .lock()
+                .unwrap()
+                .push((msg_type, cl_ord_id));
+            Ok(())
+        }
+
+        fn on_outbound_message(&mut self, _message: &[u8]) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_inbound_logon(&mut self, logon: Message<&[u8]>) -> Result<(), LogonRejection> {
+            match &self.password {
+                Some(password) if logon.fv::<&str>(554) != Ok(password.as_str()) => {
+                    Err(LogonRejection::InvalidCredentials)
+                }
+                _ => Ok(()),
             }
-            n
-        } else {
-            // See §4.5.3.
-            return self.on_missing_seqnum(msg);
-        };
+        }
 
-        // Increment immediately.
-        self.msg_seq_num_inbound.next();
+        fn on_outbound_logon(&mut self, logon: &mut impl SetField<u32>) {
+            if let Some(password) = &self.password {
+                logon.set(553, "USER");
+                logon.set(554, password.as_str());
+            }
+        }
 
-        if self.verifier().verify_sending_time(&msg).is_err() {
-            return self.make_reject_for_inaccurate_sending_time(msg);
+        fn on_successful_handshake(&mut self) -> Result<(), String> {
+            *self.handshakes.lock().unwrap() += 1;
+            Ok(())
         }
 
-        let msg_type = if let Ok(x) = msg.fv::<&[u8]>(&MSG_TYPE) {
-            x
-        } else {
-            self.on_inbound_app_message(msg).ok();
-            return self.on_application_message(msg);
-        };
-        self.dispatch_by_msg_type(msg_type, msg)
+        fn pending_message(&mut self) -> Option<&[u8]> {
+            None
+        }
     }
 
-    fn on_resend_request(&self, msg: &Message<&[u8]>) {
-        let begin_seq_num = msg.fv(&BEGIN_SEQ_NO).unwrap();
-        let end_seq_num = msg.fv(&END_SEQ_NO).unwrap();
-        self.on_resend_request(begin_seq_num..end_seq_num).ok();
+    fn config(sender_comp_id: &str, target_comp_id: &str) -> Config {
+        let mut config = Config::default();
+        config.sender_comp_id = sender_comp_id.to_string();
+        config.target_comp_id = target_comp_id.to_string();
+        config
     }
 
-    fn on_logout(&mut self, data: ResponseData, _msg: &Message<&[u8]>) -> &[u8] {
-        let fix_message = {
-            let msg_seq_num = self.next();
-            let mut msg = self.start_message(data.begin_string, b"5");
-            self.set_sender_and_target(&mut msg);
-            msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-            msg.set_fv_with_key(&TEXT, "Logout");
-            msg.done()
-        };
-        fix_message
-    }
-
-    //    fn add_seqnum(&self, msg: &mut RawEncoderState) {
-    //        msg.add_field(tags::MSG_SEQ_NUM, self.seq_numbers().next_outbound());
-    //        self.seq_numbers_mut().incr_outbound();
-    //    }
-    //
-    //    fn add_sending_time(&self, msg: &mut RawEncoderState) {
-    //        msg.add_field(tags::SENDING_TIME, DtfTimestamp::utc_now());
-    //    }
-    //
-    //    #[must_use]
-    fn on_heartbeat_is_due(&mut self) -> &[u8] {
-        let fix_message = {
-            let begin_string = self.begin_string();
-            let msg_seq_num = self.msg_seq_num_outbound.next();
-            let mut msg = self
-                .encoder
-                .start_message(begin_string, &mut self.buffer, b"0");
-            self.set_sender_and_target(&mut msg);
-            msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-            self.set_sending_time(&mut msg);
-            msg.done()
-        };
-        fix_message
+    fn decoder() -> DecoderStreaming<Vec<u8>> {
+        Decoder::<DecoderConfig>::new(Dictionary::fix44()).streaming(vec![])
     }
 
-    fn set_sender_and_target(&'a self, msg: &mut impl FvWrite<'a, Key = u32>) {
-        msg.set_fv_with_key(&SENDER_COMP_ID, self.sender_comp_id());
-        msg.set_fv_with_key(&TARGET_COMP_ID, self.target_comp_id());
+    fn encode_message(msg_type: &[u8], msg_seq_num: u64, fields: &[(u32, &str)]) -> Vec<u8> {
+        let mut encoder = Encoder::<DecoderConfig>::default();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, msg_type);
+        msg.set(SENDER_COMP_ID, "INITIATOR");
+        msg.set(TARGET_COMP_ID, "ACCEPTOR");
+        msg.set(MSG_SEQ_NUM, msg_seq_num);
+        msg.set(SENDING_TIME, Timestamp::utc_now());
+        for (tag, value) in fields {
+            msg.set(*tag, *value);
+        }
+        msg.done().0.to_vec()
     }
 
-    fn set_sending_time(&'a self, msg: &mut impl FvWrite<'a, Key = u32>) {
-        msg.set_fv_with_key(&SENDING_TIME, chrono::Utc::now());
+    fn acceptor() -> FixConnection<TestBackend> {
+        FixConnection::new(config("ACCEPTOR", "INITIATOR"), TestBackend::default())
     }
 
-    fn set_header_details(&'a self, _msg: &mut impl FvWrite<'a, Key = u32>) {}
+    /// Spawns `conn` as an acceptor on a random local port and returns its
+    /// address, together with `conn` itself once it terminates.
+    async fn spawn_acceptor(
+        mut conn: FixConnection<TestBackend>,
+    ) -> (
+        std::net::SocketAddr,
+        tokio::task::JoinHandle<(io::Result<()>, FixConnection<TestBackend>)>,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let task = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (input, output) = stream.into_split();
+            let result = conn
+                .accept(input.compat(), output.compat_write(), decoder())
+                .await;
+            (result, conn)
+        });
+        (addr, task)
+    }
 
-    fn on_heartbeat(&mut self, _msg: Message<&[u8]>) {
-        // TODO: verify stuff.
+    async fn read_until(stream: &mut TcpStream, pattern: &str) -> String {
+        let mut response = Vec::new();
+        while !String::from_utf8_lossy(&response[..]).contains(pattern) {
+            let mut buffer = [0; 1024];
+            let n = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buffer))
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(n > 0);
+            response.extend_from_slice(&buffer[..n]);
+        }
+        String::from_utf8_lossy(&response[..]).to_string()
     }
 
-    fn on_test_request(&mut self, msg: Message<&[u8]>) -> &[u8] {
-        let test_req_id = msg.fv::<&[u8]>(&TEST_REQ_ID).unwrap();
-        let begin_string = self.begin_string();
-        let msg_seq_num = self.msg_seq_num_outbound.next();
-        let mut msg = self.start_message(begin_string, b"1");
-        self.set_sender_and_target(&mut msg);
-        msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-        self.set_sending_time(&mut msg);
-        msg.set_fv_with_key(&TEST_REQ_ID, test_req_id);
-        msg.done()
+    async fn read_until_eof(stream: &mut TcpStream) -> Vec<u8> {
+        let mut response = Vec::new();
+        tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut response))
+            .await
+            .unwrap()
+            .unwrap();
+        response
     }
 
-    fn on_wrong_environment(&mut self, _message: Message<&[u8]>) -> Response {
-        self.make_logout(errs::production_env())
+    #[tokio::test]
+    async fn initiator_and_acceptor_logon_exchange_and_logout() {
+        let (addr, acceptor) = spawn_acceptor(acceptor()).await;
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (input, output) = stream.into_split();
+        let mut initiator =
+            FixConnection::new(config("INITIATOR", "ACCEPTOR"), TestBackend::default());
+        let handle = initiator.handle();
+        handle
+            .send(b"D", |msg| {
+                msg.set(11, "ORDER-1");
+            })
+            .unwrap();
+        handle.logout(Some("Bye".to_string())).unwrap();
+        let initiator_result = tokio::time::timeout(
+            Duration::from_secs(5),
+            initiator.initiate(input.compat(), output.compat_write(), decoder()),
+        )
+        .await
+        .unwrap();
+        initiator_result.unwrap();
+
+        let (acceptor_result, acceptor) = tokio::time::timeout(Duration::from_secs(5), acceptor)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(acceptor_result.is_ok());
+
+        assert_eq!(*initiator.backend().handshakes.lock().unwrap(), 1);
+        assert_eq!(*acceptor.backend().handshakes.lock().unwrap(), 1);
+        assert_eq!(
+            &acceptor.backend().inbound_app_messages.lock().unwrap()[..],
+            &[("D".to_string(), Some("ORDER-1".to_string()))]
+        );
+        // Logon, NewOrderSingle, Logout.
+        assert_eq!(initiator.seq_numbers().next_outbound(), 4);
+        assert_eq!(acceptor.seq_numbers().next_inbound(), 4);
+        // Logon, Logout.
+        assert_eq!(initiator.seq_numbers().next_inbound(), 3);
+        assert_eq!(acceptor.seq_numbers().next_outbound(), 3);
     }
 
-    fn generate_error_seqnum_too_low(&mut self) -> &[u8] {
-        let begin_string = self.begin_string();
-        let msg_seq_num = self.msg_seq_num_outbound.next();
-        let text = errs::msg_seq_num(self.msg_seq_num_inbound.0 + 1);
-        let mut msg = self.start_message(begin_string, b"FIXME");
-        msg.set_fv_with_key(&MSG_TYPE, "5");
-        self.set_sender_and_target(&mut msg);
-        msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-        msg.set_fv_with_key(&TEXT, text.as_str());
-        msg.done()
+    #[tokio::test]
+    async fn msg_seq_num_too_low_triggers_logout() {
+        let (addr, acceptor) = spawn_acceptor(acceptor()).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"D", 1, &[(11, "ORDER-1")]))
+            .await
+            .unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(response.contains("\x0135=A\x01"));
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains(errs::msg_seq_num(2).as_str()));
+
+        let (_result, acceptor) = acceptor.await.unwrap();
+        assert!(acceptor
+            .backend()
+            .inbound_app_messages
+            .lock()
+            .unwrap()
+            .is_empty());
     }
 
-    fn on_missing_seqnum(&mut self, _message: Message<&[u8]>) -> Response {
-        self.make_logout(errs::missing_field("MsgSeqNum", MSG_SEQ_NUM))
+    #[tokio::test]
+    async fn wrong_comp_id_triggers_reject_and_logout() {
+        let (addr, acceptor) = spawn_acceptor(acceptor()).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        let mut encoder = Encoder::<DecoderConfig>::default();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        msg.set(SENDER_COMP_ID, "SOMEONE_ELSE");
+        msg.set(TARGET_COMP_ID, "ACCEPTOR");
+        msg.set(MSG_SEQ_NUM, 2u64);
+        msg.set(SENDING_TIME, Timestamp::utc_now());
+        stream.write_all(msg.done().0).await.unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(response.contains("\x0135=3\x01"));
+        assert!(response.contains("\x01373=9\x01"));
+        assert!(response.contains("\x0135=5\x01"));
+
+        let (_result, acceptor) = acceptor.await.unwrap();
+        assert!(acceptor
+            .backend()
+            .inbound_app_messages
+            .lock()
+            .unwrap()
+            .is_empty());
     }
 
-    fn on_low_seqnum(&mut self, _message: Message<&[u8]>) -> Response {
-        self.make_logout(errs::msg_seq_num(self.msg_seq_num_inbound.0 + 1))
+    #[tokio::test]
+    async fn test_request_is_answered_with_heartbeat() {
+        let (addr, _acceptor) = spawn_acceptor(acceptor()).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"1", 2, &[(112, "PING")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"5", 3, &[]))
+            .await
+            .unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(response.contains("\x0135=0\x01"));
+        assert!(response.contains("\x01112=PING\x01"));
     }
 
-    fn on_reject(
-        &mut self,
-        _ref_seq_num: u64,
-        ref_tag: Option<u32>,
-        ref_msg_type: Option<&[u8]>,
-        reason: u32,
-        err_text: String,
-    ) -> Response {
-        let begin_string = self.begin_string();
-        let sender_comp_id = self.sender_comp_id();
-        let target_comp_id = self.target_comp_id();
-        let msg_seq_num = self.msg_seq_num_outbound.next();
-        let mut msg = self.start_message(begin_string, b"3");
-        self.set_sender_and_target(&mut msg);
-        msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-        if let Some(ref_tag) = ref_tag {
-            msg.set_fv_with_key(&REF_TAG_ID, ref_tag);
-        }
-        if let Some(ref_msg_type) = ref_msg_type {
-            msg.set_fv_with_key(&REF_MSG_TYPE, ref_msg_type);
-        }
-        msg.set_fv_with_key(&SESSION_REJECT_REASON, reason);
-        msg.set_fv_with_key(&TEXT, err_text.as_str());
-        Response::OutboundBytes(msg.done())
-    }
-
-    fn make_reject_for_inaccurate_sending_time(&mut self, offender: Message<&[u8]>) -> Response {
-        let ref_seq_num = offender.fv(&MSG_SEQ_NUM).unwrap();
-        let ref_msg_type = offender.fv::<&str>(&MSG_TYPE).unwrap();
-        self.on_reject(
-            ref_seq_num,
-            Some(SENDING_TIME),
-            Some(ref_msg_type.as_bytes()),
-            SENDING_TIME_ACCURACY_PROBLEM,
-            "Bad SendingTime".to_string(),
-        )
+    #[tokio::test]
+    async fn resend_request_is_answered_from_the_store() {
+        let acceptor = acceptor();
+        acceptor
+            .handle()
+            .send(b"8", |msg| {
+                msg.set(11, "ORDER-1");
+            })
+            .unwrap();
+        let (addr, acceptor) = spawn_acceptor(acceptor).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        read_until(&mut stream, "\x0111=ORDER-1\x01").await;
+        stream
+            .write_all(&encode_message(b"2", 2, &[(7, "1"), (16, "0")]))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x01122=").await;
+        // `Logon <A>` is replaced by a gap fill...
+        let gap_fill = response.find("\x0135=4\x01").unwrap();
+        assert!(response[gap_fill..].contains("\x0134=1\x01"));
+        assert!(response[gap_fill..].contains("\x01123=Y\x01"));
+        assert!(response[gap_fill..].contains("\x0136=2\x01"));
+        // ...and the application message is resent.
+        let resent = response[gap_fill..].find("\x0135=8\x01").unwrap() + gap_fill;
+        assert!(response[resent..].contains("\x0134=2\x01"));
+        assert!(response[resent..].contains("\x0143=Y\x01"));
+        assert!(response[resent..].contains("\x0111=ORDER-1\x01"));
+
+        stream
+            .write_all(&encode_message(b"5", 3, &[]))
+            .await
+            .unwrap();
+        read_until_eof(&mut stream).await;
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(acceptor.store().seq_numbers(), Some(acceptor.seq_numbers()));
+    }
+
+    #[tokio::test]
+    async fn out_of_order_messages_are_queued_until_the_gap_is_filled() {
+        let (addr, acceptor) = spawn_acceptor(acceptor()).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"D", 3, &[(11, "ORDER-3")]))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x0135=2\x01").await;
+        let resend_request = response.find("\x0135=2\x01").unwrap();
+        assert!(response[resend_request..].contains("\x017=2\x01"));
+        assert!(response[resend_request..].contains("\x0116=2\x01"));
+        stream
+            .write_all(&encode_message(b"D", 2, &[(43, "Y"), (11, "ORDER-2")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"5", 4, &[]))
+            .await
+            .unwrap();
+        read_until_eof(&mut stream).await;
+
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(acceptor.seq_numbers().next_inbound(), 5);
+        assert_eq!(
+            *acceptor.backend().inbound_app_messages.lock().unwrap(),
+            vec![
+                ("D".to_string(), Some("ORDER-2".to_string())),
+                ("D".to_string(), Some("ORDER-3".to_string())),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn resend_requests_are_chunked_and_sequence_resets_fill_gaps() {
+        let mut config = config("ACCEPTOR", "INITIATOR");
+        config.max_resend_range = Some(2);
+        let (addr, acceptor) =
+            spawn_acceptor(FixConnection::new(config, TestBackend::default())).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"D", 7, &[(11, "ORDER-7")]))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x0135=2\x01").await;
+        assert!(response.contains("\x017=2\x0116=3\x01"));
+        // GapFill mode goes through the usual `MsgSeqNum <34>` validation...
+        stream
+            .write_all(&encode_message(
+                b"4",
+                2,
+                &[(43, "Y"), (123, "Y"), (36, "4")],
+            ))
+            .await
+            .unwrap();
+        read_until(&mut stream, "\x017=4\x0116=5\x01").await;
+        // ...while Reset mode ignores it.
+        stream
+            .write_all(&encode_message(b"4", 1, &[(36, "7")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"5", 8, &[]))
+            .await
+            .unwrap();
+        read_until_eof(&mut stream).await;
+
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(acceptor.seq_numbers().next_inbound(), 9);
+        assert_eq!(
+            *acceptor.backend().inbound_app_messages.lock().unwrap(),
+            vec![("D".to_string(), Some("ORDER-7".to_string()))]
+        );
     }
 
-    fn make_logout(&mut self, text: String) -> Response {
-        let fix_message = {
-            let begin_string = self.begin_string();
-            let sender_comp_id = self.sender_comp_id();
-            let target_comp_id = self.target_comp_id();
-            let msg_seq_num = self.msg_seq_num_outbound.next();
-            let mut msg = self.start_message(begin_string, b"5");
-            self.set_sender_and_target(&mut msg);
-            msg.set_fv_with_key(&MSG_SEQ_NUM, msg_seq_num);
-            msg.set_fv_with_key(&TEXT, text.as_str());
-            self.set_sending_time(&mut msg);
-            msg.done()
+    #[tokio::test]
+    async fn sequence_reset_to_a_lower_seq_num_is_rejected() {
+        let (addr, _acceptor) = spawn_acceptor(acceptor()).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"4", 2, &[(36, "1")]))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x0135=3\x01").await;
+        let reject = response.find("\x0135=3\x01").unwrap();
+        assert!(response[reject..].contains("\x01371=36\x01"));
+        assert!(response[reject..].contains("\x01373=5\x01"));
+    }
+
+    fn schedule_around_now(
+        from_now: chrono::Duration,
+        to_now: chrono::Duration,
+    ) -> SessionSchedule {
+        let now = chrono::Utc::now().time();
+        SessionSchedule {
+            start_time: now + from_now,
+            end_time: now + to_now,
+            days: SessionDays::Daily(vec![]),
+            timezone: crate::field_types::Tz::UTC,
+        }
+    }
+
+    #[tokio::test]
+    async fn logon_outside_of_the_schedule_is_refused() {
+        let mut config = config("ACCEPTOR", "INITIATOR");
+        config.schedule = Some(schedule_around_now(
+            chrono::Duration::hours(2),
+            chrono::Duration::hours(3),
+        ));
+        let mut initiator = FixConnection::new(config.clone(), TestBackend::default());
+        let err = initiator
+            .initiate(futures::io::empty(), futures::io::sink(), decoder())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+
+        let (addr, acceptor) =
+            spawn_acceptor(FixConnection::new(config, TestBackend::default())).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(!response.contains("\x0135=A\x01"));
+        assert!(response.contains("\x0135=5\x01"));
+        let (_result, acceptor) = acceptor.await.unwrap();
+        assert_eq!(*acceptor.backend().handshakes.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn logout_at_the_end_of_the_schedule() {
+        let mut config = config("ACCEPTOR", "INITIATOR");
+        config.schedule = Some(schedule_around_now(
+            chrono::Duration::hours(-1),
+            chrono::Duration::seconds(2),
+        ));
+        let (addr, acceptor) =
+            spawn_acceptor(FixConnection::new(config, TestBackend::default())).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x0135=5\x01").await;
+        assert!(response.contains("\x0135=A\x01"));
+        stream
+            .write_all(&encode_message(b"5", 2, &[]))
+            .await
+            .unwrap();
+        read_until_eof(&mut stream).await;
+        let (result, _acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reset_seq_num_flag_resets_seq_numbers() {
+        let mut config = config("ACCEPTOR", "INITIATOR");
+        config.seq_numbers = SeqNumbers {
+            next_inbound: 5,
+            next_outbound: 5,
         };
-        Response::OutboundBytes(fix_message)
+        let (addr, acceptor) =
+            spawn_acceptor(FixConnection::new(config, TestBackend::default())).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(
+                b"A",
+                1,
+                &[(98, "0"), (108, "30"), (141, "Y")],
+            ))
+            .await
+            .unwrap();
+        let response = read_until(&mut stream, "\x0110=").await;
+        assert!(response.contains("\x0135=A\x01"));
+        assert!(response.contains("\x0134=1\x01"));
+        assert!(response.contains("\x01141=Y\x01"));
+        stream
+            .write_all(&encode_message(b"5", 2, &[]))
+            .await
+            .unwrap();
+        read_until_eof(&mut stream).await;
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(acceptor.seq_numbers().next_inbound(), 3);
+        assert_eq!(acceptor.seq_numbers().next_outbound(), 3);
     }
 
-    fn make_resend_request(&mut self, start: u64, end: u64) -> Response {
-        let begin_string = self.begin_string();
-        let mut msg = self.start_message(begin_string, b"2");
-        //Self::add_comp_id(msg);
-        //self.add_sending_time(msg);
-        //self.add_seqnum(msg);
-        msg.set_fv_with_key(&BEGIN_SEQ_NO, start);
-        msg.set_fv_with_key(&END_SEQ_NO, end);
-        Response::OutboundBytes(msg.done())
+    #[tokio::test]
+    async fn reset_seq_num_policy_always() {
+        let (addr, acceptor) = spawn_acceptor(acceptor()).await;
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (input, output) = stream.into_split();
+        let mut config = config("INITIATOR", "ACCEPTOR");
+        config.seq_numbers = SeqNumbers {
+            next_inbound: 1,
+            next_outbound: 10,
+        };
+        config.reset_seq_num_policy = ResetSeqNumPolicy::Always;
+        let mut initiator = FixConnection::new(config, TestBackend::default());
+        let handle = initiator.handle();
+        handle.logout(None).unwrap();
+        initiator
+            .initiate(input.compat(), output.compat_write(), decoder())
+            .await
+            .unwrap();
+
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(*acceptor.backend().handshakes.lock().unwrap(), 1);
+        assert_eq!(initiator.seq_numbers().next_outbound(), 3);
     }
 
-    fn on_high_seqnum(&mut self, msg: Message<&[u8]>) -> Response {
-        let msg_seq_num = msg.fv(&MSG_SEQ_NUM).unwrap();
-        self.make_resend_request(self.seq_numbers().next_inbound(), msg_seq_num);
-        todo!()
+    fn password_protected(config: Config) -> FixConnection<TestBackend> {
+        let backend = TestBackend {
+            password: Some("SECRET".to_string()),
+            ..TestBackend::default()
+        };
+        FixConnection::new(config, backend)
     }
 
-    fn on_logon(&mut self, _logon: Message<&[u8]>) {
-        let begin_string = self.begin_string();
-        let mut _msg = self.start_message(begin_string, b"A");
-        //Self::add_comp_id(msg);
-        //self.add_sending_time(msg);
-        //self.add_sending_time(msg);
+    #[tokio::test]
+    async fn logon_hooks_inject_and_validate_credentials() {
+        let (addr, acceptor) =
+            spawn_acceptor(password_protected(config("ACCEPTOR", "INITIATOR"))).await;
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (input, output) = stream.into_split();
+        let mut initiator = password_protected(config("INITIATOR", "ACCEPTOR"));
+        initiator.handle().logout(None).unwrap();
+        initiator
+            .initiate(input.compat(), output.compat_write(), decoder())
+            .await
+            .unwrap();
+
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(*initiator.backend().handshakes.lock().unwrap(), 1);
+        assert_eq!(*acceptor.backend().handshakes.lock().unwrap(), 1);
     }
 
-    fn on_application_message(&mut self, msg: Message<'a, &'a [u8]>) -> Response<'a> {
-        Response::Application(msg)
+    #[tokio::test]
+    async fn logon_with_invalid_credentials_is_refused() {
+        let (addr, acceptor) =
+            spawn_acceptor(password_protected(config("ACCEPTOR", "INITIATOR"))).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(
+                b"A",
+                1,
+                &[(98, "0"), (108, "30"), (553, "USER"), (554, "WRONG")],
+            ))
+            .await
+            .unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(!response.contains("\x0135=A\x01"));
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains("\x0158=Invalid Username(553) or Password(554)\x01"));
+        assert!(!response.contains("\x011409="));
+
+        let (_result, acceptor) = acceptor.await.unwrap();
+        assert_eq!(*acceptor.backend().handshakes.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn fixt_logon_responses_carry_session_status() {
+        let fixt_acceptor = || {
+            let mut config = config("ACCEPTOR", "INITIATOR");
+            config.begin_string = "FIXT.1.1".to_string();
+            password_protected(config)
+        };
+        let (addr, _acceptor) = spawn_acceptor(fixt_acceptor()).await;
+
+        let logon = |password: &str| {
+            let mut encoder = Encoder::<DecoderConfig>::default();
+            let mut buffer = Vec::new();
+            let mut msg = encoder.start_message(b"FIXT.1.1", &mut buffer, b"A");
+            msg.set(SENDER_COMP_ID, "INITIATOR");
+            msg.set(TARGET_COMP_ID, "ACCEPTOR");
+            msg.set(MSG_SEQ_NUM, 1u64);
+            msg.set(SENDING_TIME, Timestamp::utc_now());
+            msg.set(98, "0");
+            msg.set(108, "30");
+            msg.set(554, password);
+            msg.set(925, "NEW_SECRET");
+            msg.set(1137, "9");
+            msg.done().0.to_vec()
+        };
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(&logon("WRONG")).await.unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains("\x011409=5\x01"));
+
+        let (addr, _acceptor) = spawn_acceptor(fixt_acceptor()).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(&logon("SECRET")).await.unwrap();
+        let response = read_until(&mut stream, "\x0110=").await;
+        assert!(response.contains("\x0135=A\x01"));
+        assert!(response.contains("\x011409=1\x01"));
+    }
+
+    #[tokio::test]
+    async fn invalid_messages_are_rejected() {
+        let mut conn = acceptor();
+        conn.set_validator(Some(Validator::new(Dictionary::fix44())));
+        let (addr, acceptor) = spawn_acceptor(conn).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(&encode_message(b"A", 1, &[(98, "0"), (108, "30")]))
+            .await
+            .unwrap();
+        // `Side <54>` is missing.
+        stream
+            .write_all(&encode_message(
+                b"D",
+                2,
+                &[(11, "ORDER-2"), (60, "20210302-10:00:00"), (40, "1")],
+            ))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"ZZ", 3, &[]))
+            .await
+            .unwrap();
+        stream
+            .write_all(&encode_message(b"5", 4, &[]))
+            .await
+            .unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        let reject = response.find("\x0135=3\x01").unwrap();
+        assert!(response[reject..].contains("\x0145=2\x01"));
+        assert!(response[reject..].contains("\x01371=54\x01"));
+        assert!(response[reject..].contains("\x01373=1\x01"));
+        let business_reject = response.find("\x0135=j\x01").unwrap();
+        assert!(response[business_reject..].contains("\x0145=3\x01"));
+        assert!(response[business_reject..].contains("\x01380=3\x01"));
+
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(acceptor.seq_numbers().next_inbound(), 5);
+        assert!(acceptor
+            .backend()
+            .inbound_app_messages
+            .lock()
+            .unwrap()
+            .is_empty());
+    }
+
+    fn fixt_config(sender_comp_id: &str, target_comp_id: &str) -> Config {
+        let mut config = config(sender_comp_id, target_comp_id);
+        config.begin_string = "FIXT.1.1".to_string();
+        config
     }
-}
 
-//fn add_time_to_msg(mut msg: EncoderHandle) {
-//    // https://www.onixs.biz/fix-dictionary/4.4/index.html#UTCTimestamp.
-//    let time = chrono::Utc::now();
-//    let timestamp = time.format("%Y%m%d-%H:%M:%S.%.3f");
-//    msg.set_fv_with_key(fix44::SENDING_TIME, timestamp.to_string().as_str());
-//}
-
-//#[cfg(test)]
-//mod test {
-//    use super::*;
-//    use std::time::Duration;
-//
-//    fn conn() -> FixConnection {
-//        let builder = FixConnectionBuilder {
-//            environment: Environment::ProductionDisallowTest,
-//            heartbeat: Duration::from_secs(30),
-//            seq_numbers: SeqNumbers::default(),
-//            sender_comp_id: "SENDER".to_string(),
-//            target_comp_id: "TARGET".to_string(),
-//        };
-//        builder.build()
-//    }
-//
-//    #[test]
-//    fn on_heartbeat_is_due() {
-//        let conn = &mut conn();
-//        let responses = &mut conn.on_heartbeat_is_due();
-//        let next = responses.next().unwrap();
-//        let msg = next.as_outbound().unwrap();
-//        assert_eq!(msg.field_str(tags::MSG_TYPE), Some("0"));
-//        assert_eq!(msg.field_str(tags::SENDER_COMP_ID), Some("SENDER"));
-//        assert_eq!(msg.field_str(tags::TARGET_COMP_ID), Some("TARGET"));
-//        assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), None);
-//        assert_eq!(msg.field_i64(tags::TEST_REQ_ID), None);
-//        assert!(responses.next().is_none());
-//    }
-//
-//    #[test]
-//    fn terminate_transport_when_error() {
-//        let conn = &mut conn();
-//        let responses = &mut conn.on_transport_error();
-//        let next = responses.next().unwrap();
-//        assert!(next.as_terminate_transport().is_some());
-//    }
-//
-//    #[test]
-//    fn inaccurate_sending_time() {
-//        let conn = &mut conn();
-//        let mut msg = FixMessage::new();
-//        msg.add_str(tags::MSG_TYPE, "BE");
-//        msg.add_str(tags::SENDER_COMP_ID, "SENDER");
-//        msg.add_str(tags::TARGET_COMP_ID, "TARGET");
-//        msg.add_i64(tags::MSG_SEQ_NUM, 1);
-//        msg.add_str(
-//            tags::USER_REQUEST_ID,
-//            "47b6f4a6-993d-4430-b68f-d9b680a1a772",
-//        );
-//        msg.add_i64(tags::USER_REQUEST_TYPE, 1);
-//        msg.add_str(tags::USERNAME, "john-doe");
-//        let mut responses = conn.on_inbound_message(msg);
-//        let next = responses.next().unwrap();
-//        let msg = next.as_outbound().unwrap();
-//        assert_eq!(msg.field_str(tags::MSG_TYPE), Some("3"));
-//        assert_eq!(msg.field_str(tags::SENDER_COMP_ID), Some("SENDER"));
-//        assert_eq!(msg.field_str(tags::TARGET_COMP_ID), Some("TARGET"));
-//        assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), None);
-//        assert_eq!(msg.field_i64(tags::TEST_REQ_ID), None);
-//        assert_eq!(msg.field_i64(tags::SESSION_REJECT_REASON), Some(10));
-//        assert_eq!(msg.field_i64(tags::REF_SEQ_NUM), Some(10));
-//        assert!(responses.next().is_none());
-//    }
-//}
+    #[tokio::test]
+    async fn fixt_logon_without_default_appl_ver_id_is_refused() {
+        let (addr, acceptor) = spawn_acceptor(FixConnection::new(
+            fixt_config("ACCEPTOR", "INITIATOR"),
+            TestBackend::default(),
+        ))
+        .await;
+
+        let mut encoder = Encoder::<DecoderConfig>::default();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIXT.1.1", &mut buffer, b"A");
+        msg.set(SENDER_COMP_ID, "INITIATOR");
+        msg.set(TARGET_COMP_ID, "ACCEPTOR");
+        msg.set(MSG_SEQ_NUM, 1u64);
+        msg.set(SENDING_TIME, Timestamp::utc_now());
+        msg.set(98, "0");
+        msg.set(108, "30");
+        let logon = msg.done().0.to_vec();
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(&logon).await.unwrap();
+        let response = read_until_eof(&mut stream).await;
+        let response = String::from_utf8_lossy(&response[..]);
+        assert!(!response.contains("\x0135=A\x01"));
+        assert!(response.contains("\x0135=5\x01"));
+        assert!(response.contains(errs::missing_field("DefaultApplVerID", 1137).as_str()));
+
+        let (_result, acceptor) = acceptor.await.unwrap();
+        assert_eq!(*acceptor.backend().handshakes.lock().unwrap(), 0);
+    }
+
+    #[cfg(all(feature = "fixt11", feature = "fix50sp2"))]
+    #[tokio::test]
+    async fn fixt_sessions_negotiate_default_appl_ver_id() {
+        let fixt_decoder = || {
+            let mut decoder = Decoder::<DecoderConfig>::new(Dictionary::fixt11());
+            decoder.add_appl_ver_id(b"9", Dictionary::fix50sp2());
+            decoder.streaming(vec![])
+        };
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let acceptor = tokio::spawn(async move {
+            let mut conn =
+                FixConnection::new(fixt_config("ACCEPTOR", "INITIATOR"), TestBackend::default());
+            let (stream, _) = listener.accept().await.unwrap();
+            let (input, output) = stream.into_split();
+            let result = conn
+                .accept(input.compat(), output.compat_write(), fixt_decoder())
+                .await;
+            (result, conn)
+        });
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (input, output) = stream.into_split();
+        let mut config = fixt_config("INITIATOR", "ACCEPTOR");
+        config.default_appl_ver_id = Some("9".to_string());
+        let mut initiator = FixConnection::new(config, TestBackend::default());
+        let handle = initiator.handle();
+        handle
+            .send(b"D", |msg| {
+                msg.set(11, "ORDER-1");
+            })
+            .unwrap();
+        handle.logout(None).unwrap();
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            initiator.initiate(input.compat(), output.compat_write(), fixt_decoder()),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let (result, acceptor) = acceptor.await.unwrap();
+        assert!(result.is_ok());
+        assert_eq!(initiator.default_appl_ver_id(), Some(b"9" as &[u8]));
+        assert_eq!(acceptor.default_appl_ver_id(), Some(b"9" as &[u8]));
+        assert_eq!(
+            &acceptor.backend().inbound_app_messages.lock().unwrap()[..],
+            &[("D".to_string(), Some("ORDER-1".to_string()))]
+        );
+    }
+}
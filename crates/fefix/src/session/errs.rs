@@ -30,6 +30,14 @@ pub fn production_env() -> String {
         .to_string()
 }
 
+pub fn outside_schedule() -> String {
+    "Logon(A) outside of the session schedule".to_string()
+}
+
+pub fn end_of_session() -> String {
+    "End of the session schedule".to_string()
+}
+
 pub fn missing_field(name: &str, tag: u32) -> String {
     format!("Missing mandatory field {}({})", name, tag)
 }
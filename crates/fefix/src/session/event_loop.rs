@@ -1,4 +1,4 @@
-use crate::tagvalue::{DecodeError, DecoderStreaming, Message};
+use crate::tagvalue::{garbled_len, DecodeError, DecoderStreaming, Message};
 use crate::StreamingDecoder;
 use futures::select;
 use futures::{AsyncRead, AsyncReadExt, FutureExt};
@@ -16,11 +16,21 @@ use std::time::Instant;
 pub struct LlEventLoop<I> {
     decoder: DecoderStreaming<Vec<u8>>,
     input: I,
+    // Number of bytes at the start of the decoder's buffer that have actually
+    // been read from `input`. Keeping track of it outside of
+    // `next_event` makes the latter cancellation-safe.
+    num_bytes_filled: usize,
+    has_message: bool,
+    // Data to be read before `input`. See `LlEventLoop::replay`.
+    replay_buffer: Vec<u8>,
+    // The bytes that were discarded because of the last `LlEvent::BadMessage`.
+    garbled: Vec<u8>,
     heartbeat: Duration,
     heartbeat_soft_tolerance: Duration,
     heartbeat_hard_tolerance: Duration,
     last_reset: Instant,
     last_heartbeat: Instant,
+    test_request_is_pending: bool,
     is_alive: bool,
 }
 
@@ -36,15 +46,29 @@ where
         Self {
             decoder,
             input,
+            num_bytes_filled: 0,
+            has_message: false,
+            replay_buffer: Vec::new(),
+            garbled: Vec::new(),
             heartbeat,
             heartbeat_soft_tolerance,
             heartbeat_hard_tolerance,
             last_reset: Instant::now(),
             last_heartbeat: Instant::now(),
+            test_request_is_pending: false,
             is_alive: true,
         }
     }
 
+    /// Changes the heartbeat interval of `self`, e.g. after it was negotiated
+    /// with the counterparty via `Logon <A>`. Soft and hard tolerances are
+    /// reset to their default values, i.e. twice and three times `heartbeat`.
+    pub fn set_heartbeat(&mut self, heartbeat: Duration) {
+        self.heartbeat = heartbeat;
+        self.heartbeat_soft_tolerance = heartbeat * 2;
+        self.heartbeat_hard_tolerance = heartbeat * 3;
+    }
+
     /// How long after a missed `Heartbeat <0>` should we send a `TestRequest
     /// <1>`?
     pub fn set_soft_tolerance(&mut self, soft_tolerance: Duration) {
@@ -56,52 +80,94 @@ where
         self.heartbeat_hard_tolerance = hard_tolerance;
     }
 
+    /// Waits for the next [`LlEvent`] to happen.
+    ///
+    /// Returns [`None`] once the event loop has terminated, i.e. after
+    /// [`LlEvent::Logout`] or [`LlEvent::IoError`]. After a
+    /// [`LlEvent::BadMessage`], the event loop skips to the next `8=FIX` and
+    /// keeps decoding.
+    /// Dropping the returned future before completion is safe and no input
+    /// data is lost.
     pub async fn next_event<'a>(&'a mut self) -> Option<LlEvent<'a>> {
-        let mut buf_filled_len = 0;
-        let mut buf = self.decoder.fillable();
+        if self.has_message {
+            // The last event was a message, which we must now discard before
+            // parsing the next one.
+            self.decoder.clear();
+            self.num_bytes_filled = 0;
+            self.has_message = false;
+        }
 
         loop {
             if !self.is_alive {
                 return None;
             }
 
+            let num_bytes_required = self.decoder.num_bytes_required();
+            if self.num_bytes_filled >= num_bytes_required {
+                match self.decoder.try_parse() {
+                    Ok(Some(())) => {
+                        self.has_message = true;
+                        let msg = self.decoder.message();
+                        return Some(LlEvent::Message(msg));
+                    }
+                    Ok(None) => {
+                        continue;
+                    }
+                    Err(err) => {
+                        self.resync();
+                        return Some(LlEvent::BadMessage(err, &self.garbled[..]));
+                    }
+                }
+            }
+
+            let buffer = self.decoder.buffer();
+            if buffer.len() < num_bytes_required {
+                buffer.resize(num_bytes_required, 0);
+            }
+            let buf = &mut buffer[self.num_bytes_filled..num_bytes_required];
+            if !self.replay_buffer.is_empty() {
+                let num_bytes = buf.len().min(self.replay_buffer.len());
+                buf[..num_bytes].copy_from_slice(&self.replay_buffer[..num_bytes]);
+                self.replay_buffer.drain(..num_bytes);
+                self.num_bytes_filled += num_bytes;
+                continue;
+            }
+
             let now = Instant::now();
-            let mut timer_heartbeat = Delay::new(now - self.last_heartbeat + self.heartbeat).fuse();
-            let mut timer_test_request =
-                Delay::new(now - self.last_reset + self.heartbeat_soft_tolerance).fuse();
-            let mut timer_logout =
-                Delay::new(now - self.last_reset + self.heartbeat_hard_tolerance).fuse();
+            let mut timer_heartbeat =
+                Delay::new(remaining(self.heartbeat, now - self.last_heartbeat)).fuse();
+            let mut timer_test_request = if self.test_request_is_pending {
+                // We've already sent a `TestRequest <1>` and we're waiting
+                // for the response; don't send another one.
+                Delay::new(self.heartbeat_hard_tolerance).fuse()
+            } else {
+                Delay::new(remaining(
+                    self.heartbeat_soft_tolerance,
+                    now - self.last_reset,
+                ))
+                .fuse()
+            };
+            let mut timer_logout = Delay::new(remaining(
+                self.heartbeat_hard_tolerance,
+                now - self.last_reset,
+            ))
+            .fuse();
+
             let mut read_result = self.input.read(buf).fuse();
 
             select! {
                 read_result = read_result => {
                     match read_result {
                         Err(e) => {
+                            self.is_alive = false;
                             return Some(LlEvent::IoError(e));
                         }
+                        Ok(0) => {
+                            self.is_alive = false;
+                            return Some(LlEvent::IoError(io::ErrorKind::UnexpectedEof.into()));
+                        }
                         Ok(num_bytes) => {
-                            buf_filled_len += num_bytes;
-                            if buf_filled_len < buf.len() {
-                                continue;
-                            }
-
-                            let result = self.decoder.try_parse();
-                            buf_filled_len = 0;
-                            buf = &mut self.decoder.fillable()[buf_filled_len..];
-
-                            match result {
-                                Ok(Some(())) => {
-                                    let msg = self.decoder.message();
-                                    return Some(LlEvent::Message(msg));
-                                }
-                                Ok(None) => {
-                                    continue;
-                                }
-                                Err(err) => {
-                                    self.is_alive = false;
-                                    return Some(LlEvent::BadMessage(err))
-                                }
-                            }
+                            self.num_bytes_filled += num_bytes;
                         }
                     };
                 },
@@ -110,6 +176,7 @@ where
                     return Some(LlEvent::Heartbeat);
                 },
                 () = timer_test_request => {
+                    self.test_request_is_pending = true;
                     return Some(LlEvent::TestRequest);
                 },
                 () = timer_logout => {
@@ -120,19 +187,60 @@ where
         }
     }
 
+    /// Discards the garbled bytes at the start of the decoder's buffer and
+    /// feeds the rest to the decoder again.
+    fn resync(&mut self) {
+        let data = &self.decoder.buffer()[..self.num_bytes_filled];
+        let garbled_len = garbled_len(data);
+        self.garbled.clear();
+        self.garbled.extend_from_slice(&data[..garbled_len]);
+        let rest = data[garbled_len..].to_vec();
+        self.replay_buffer.splice(0..0, rest);
+        self.decoder.clear();
+        self.num_bytes_filled = 0;
+    }
+
+    /// Returns a mutable reference to the underlying decoder, e.g. to change
+    /// its `DefaultApplVerID <1137>` after `Logon <A>`.
+    pub fn decoder_mut(&mut self) -> &mut DecoderStreaming<Vec<u8>> {
+        &mut self.decoder
+    }
+
+    /// Makes `self` yield `message` as a [`LlEvent::Message`] before reading any
+    /// more data from the input. This is useful to process again messages
+    /// that were previously received out of order.
+    ///
+    /// Call this only after receiving a [`LlEvent::Message`], i.e. at message
+    /// boundaries.
+    pub fn replay(&mut self, message: &[u8]) {
+        self.replay_buffer.extend_from_slice(message);
+    }
+
     /// Resets the FIX counterparty's `Heartbeat <0>` -associated timers.
     pub fn ping_heartbeat(&mut self) {
         self.last_reset = Instant::now();
+        self.test_request_is_pending = false;
+    }
+
+    /// Resets the timer of outbound `Heartbeat <0>` messages. Call this
+    /// whenever a message is sent to the FIX counterparty.
+    pub fn ping_outbound(&mut self) {
+        self.last_heartbeat = Instant::now();
     }
 }
 
+fn remaining(interval: Duration, elapsed: Duration) -> Duration {
+    interval.checked_sub(elapsed).unwrap_or_default()
+}
+
 /// A low level event produced by a [`LlEventLoop`].
 #[derive(Debug)]
 pub enum LlEvent<'a> {
     /// Incoming FIX message.
     Message(Message<'a, &'a [u8]>),
-    /// Tried to parse an incoming FIX message, but got illegal data.
-    BadMessage(DecodeError),
+    /// Tried to parse an incoming FIX message, but got illegal data. The
+    /// garbled bytes were discarded up to the start of the next message.
+    BadMessage(DecodeError, &'a [u8]),
     /// I/O error at the transport layer.
     IoError(io::Error),
     /// Time to send a new `HeartBeat <0>` message.
@@ -151,6 +259,10 @@ pub enum LlEvent<'a> {
 mod test {
     use super::*;
     use crate::tagvalue::{Config, Decoder};
+    use crate::FieldMap;
+    use futures::executor::block_on;
+    use futures::io::Cursor;
+    use quickcheck_macros::quickcheck;
     use tokio::io::AsyncWriteExt;
     use tokio::net::{TcpListener, TcpStream};
     use tokio_util::compat::*;
@@ -193,4 +305,63 @@ mod test {
                 || matches!(event, Some(LlEvent::TestRequest))
         );
     }
+
+    const HEARTBEAT: &[u8] =
+        b"8=FIX.4.4\x019=42\x0135=0\x0149=A\x0156=B\x0134=12\x0152=20100304-07:59:30\x0110=187\x01";
+
+    fn garbled_event_loop(input: Vec<u8>) -> LlEventLoop<Cursor<Vec<u8>>> {
+        LlEventLoop::new(
+            Decoder::<Config>::new(crate::Dictionary::fix44()).streaming(vec![]),
+            Cursor::new(input),
+            Duration::from_secs(30),
+        )
+    }
+
+    #[test]
+    fn garbled_messages_are_skipped() {
+        let mut input = b"foo".to_vec();
+        input.extend_from_slice(HEARTBEAT);
+        input.extend_from_slice(b"8=FIX.4.4\x019=8\x01bar");
+        input.extend_from_slice(HEARTBEAT);
+        let mut event_loop = garbled_event_loop(input);
+        block_on(async {
+            let event = event_loop.next_event().await;
+            assert!(matches!(event, Some(LlEvent::BadMessage(_, b"foo"))));
+            let event = event_loop.next_event().await;
+            assert!(matches!(event, Some(LlEvent::Message(msg)) if msg.fv_raw(34) == Some(b"12")));
+            let event = event_loop.next_event().await;
+            assert!(
+                matches!(event, Some(LlEvent::BadMessage(_, garbled)) if garbled == b"8=FIX.4.4\x019=8\x01bar")
+            );
+            let event = event_loop.next_event().await;
+            assert!(matches!(event, Some(LlEvent::Message(_))));
+            let event = event_loop.next_event().await;
+            assert!(matches!(event, Some(LlEvent::IoError(_))));
+            assert!(event_loop.next_event().await.is_none());
+        });
+    }
+
+    #[quickcheck]
+    fn garbled_input_never_panics(chunks: Vec<Vec<u8>>) -> bool {
+        // Interleave random bytes with pieces of valid messages.
+        let mut input = Vec::new();
+        for chunk in chunks.iter() {
+            input.extend_from_slice(chunk);
+            input.extend_from_slice(&HEARTBEAT[..chunk.len() % HEARTBEAT.len() + 1]);
+        }
+        input.extend_from_slice(HEARTBEAT);
+        let input_len = input.len();
+        let mut event_loop = garbled_event_loop(input);
+        let mut num_bytes = 0;
+        block_on(async {
+            while let Some(event) = event_loop.next_event().await {
+                match event {
+                    LlEvent::Message(msg) => num_bytes += msg.as_bytes().len(),
+                    LlEvent::BadMessage(_, garbled) => num_bytes += garbled.len(),
+                    _ => (),
+                }
+            }
+        });
+        num_bytes <= input_len
+    }
 }
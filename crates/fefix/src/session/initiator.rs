@@ -0,0 +1,422 @@
+use super::acceptor::new_decoder;
+use super::{Backend, Config, Configure, FixConnection, MemoryMessageStore, MessageStore};
+use crate::tagvalue::DecoderStreaming;
+use crate::Dictionary;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_util::compat::{TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
+
+/// Rules for reconnecting after failed connection attempts and dropped
+/// connections. Intervals grow exponentially with each consecutive failure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// The interval before the first reconnection attempt.
+    pub initial_interval: Duration,
+    /// The upper bound of the interval between attempts, jitter aside.
+    pub max_interval: Duration,
+    /// The growth factor of the interval after each consecutive failure.
+    pub multiplier: f64,
+    /// The maximum random deviation of each interval, as a fraction of the
+    /// interval itself (e.g. 0.1 for ±10%).
+    pub jitter: f64,
+    /// The maximum number of consecutive failed attempts before giving up, if
+    /// any. Successful Logons reset the count.
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    /// Returns the interval to wait for before the `attempt`-th consecutive
+    /// reconnection attempt (1-based), without jitter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::ReconnectPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = ReconnectPolicy {
+    ///     initial_interval: Duration::from_secs(1),
+    ///     max_interval: Duration::from_secs(10),
+    ///     multiplier: 2.0,
+    ///     jitter: 0.0,
+    ///     max_attempts: None,
+    /// };
+    /// assert_eq!(policy.interval(1), Duration::from_secs(1));
+    /// assert_eq!(policy.interval(3), Duration::from_secs(4));
+    /// assert_eq!(policy.interval(10), Duration::from_secs(10));
+    /// ```
+    pub fn interval(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let secs = self.initial_interval.as_secs_f64() * self.multiplier.powi(exponent);
+        if secs.is_finite() && secs < self.max_interval.as_secs_f64() {
+            Duration::from_secs_f64(secs)
+        } else {
+            self.max_interval
+        }
+    }
+
+    /// Like [`ReconnectPolicy::interval`], but with a random jitter.
+    fn interval_with_jitter(&self, attempt: u32) -> Duration {
+        let random = RandomState::new().build_hasher().finish();
+        // A uniformly distributed value between -1 and 1.
+        let deviation = (random as f64 / u64::MAX as f64) * 2.0 - 1.0;
+        let factor = 1.0 + self.jitter.clamp(0.0, 1.0) * deviation;
+        self.interval(attempt).mul_f64(factor)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    /// Returns a [`ReconnectPolicy`] that starts from 1 second and doubles up
+    /// to 1 minute, with ±10% jitter and unlimited attempts.
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.1,
+            max_attempts: None,
+        }
+    }
+}
+
+/// A FIX initiator that keeps its [`FixConnection`] alive over TCP,
+/// reconnecting whenever the transport layer drops.
+///
+/// Connection attempts cycle through a list of addresses, so that failing
+/// hosts are replaced by the next ones. Each new connection resumes the FIX
+/// session from the [`SeqNumbers`](super::SeqNumbers) persisted by the
+/// [`MessageStore`]. [`Backend::on_connecting`],
+/// [`Backend::on_successful_handshake`] and [`Backend::on_disconnected`] are
+/// invoked along the way.
+#[derive(Debug)]
+pub struct Initiator<B, C = Config, S = MemoryMessageStore> {
+    connection: FixConnection<B, C, S>,
+    addresses: Vec<SocketAddr>,
+    dictionary: Dictionary,
+    appl_ver_ids: Vec<(Vec<u8>, Dictionary)>,
+    reconnect_policy: ReconnectPolicy,
+}
+
+impl<B, C, S> Initiator<B, C, S>
+where
+    B: Backend,
+    C: Configure,
+    S: MessageStore,
+{
+    /// Creates a new [`Initiator`] for `connection`, which connects to
+    /// `addresses` in order. Inbound messages are decoded according to
+    /// `dictionary`.
+    pub fn new(
+        connection: FixConnection<B, C, S>,
+        addresses: Vec<SocketAddr>,
+        dictionary: Dictionary,
+    ) -> Self {
+        Self {
+            connection,
+            addresses,
+            dictionary,
+            appl_ver_ids: Vec::new(),
+            reconnect_policy: ReconnectPolicy::default(),
+        }
+    }
+
+    /// Decodes application messages with `ApplVerID <1128>` `appl_ver_id`
+    /// according to `dict`. See
+    /// [`Decoder::add_appl_ver_id`](crate::tagvalue::Decoder::add_appl_ver_id).
+    pub fn add_appl_ver_id(&mut self, appl_ver_id: &[u8], dict: Dictionary) {
+        self.appl_ver_ids.push((appl_ver_id.to_vec(), dict));
+    }
+
+    /// Returns an immutable reference to the [`ReconnectPolicy`] of `self`.
+    pub fn reconnect_policy(&self) -> &ReconnectPolicy {
+        &self.reconnect_policy
+    }
+
+    /// Returns a mutable reference to the [`ReconnectPolicy`] of `self`.
+    pub fn reconnect_policy_mut(&mut self) -> &mut ReconnectPolicy {
+        &mut self.reconnect_policy
+    }
+
+    /// Returns an immutable reference to the [`FixConnection`] of `self`.
+    pub fn connection(&self) -> &FixConnection<B, C, S> {
+        &self.connection
+    }
+
+    /// Returns a mutable reference to the [`FixConnection`] of `self`.
+    pub fn connection_mut(&mut self) -> &mut FixConnection<B, C, S> {
+        &mut self.connection
+    }
+
+    /// Consumes `self` and returns its [`FixConnection`].
+    pub fn into_connection(self) -> FixConnection<B, C, S> {
+        self.connection
+    }
+
+    /// Connects and reconnects to the counterparty until either the FIX
+    /// session is logged out via
+    /// [`FixConnectionHandle::logout`](super::FixConnectionHandle::logout),
+    /// or [`ReconnectPolicy::max_attempts`] is exceeded. Outside of
+    /// [`Configure::schedule`], [`Initiator`] waits for the next session to
+    /// start.
+    pub async fn run(&mut self) -> io::Result<()> {
+        if self.addresses.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "No address to connect to",
+            ));
+        }
+        let mut address_index = 0;
+        let mut failed_attempts = 0;
+        loop {
+            self.wait_for_schedule().await;
+            let address = self.addresses[address_index];
+            self.connection.backend_mut().on_connecting(address).ok();
+            let error = match TcpStream::connect(address).await {
+                Ok(stream) => {
+                    let (input, output) = stream.into_split();
+                    let decoder = self.decoder();
+                    let result = self
+                        .connection
+                        .initiate(input.compat(), output.compat_write(), decoder)
+                        .await;
+                    if self.connection.is_logout_requested() {
+                        return result;
+                    }
+                    if self.connection.has_logged_on() {
+                        // The connection dropped after a successful Logon, so
+                        // we try the same address again.
+                        failed_attempts = 0;
+                        let interval = self.reconnect_policy.interval_with_jitter(1);
+                        tokio::time::sleep(interval).await;
+                        continue;
+                    }
+                    result.err()
+                }
+                Err(err) => {
+                    self.connection
+                        .backend_mut()
+                        .on_disconnected(Some(&err))
+                        .ok();
+                    Some(err)
+                }
+            };
+            failed_attempts += 1;
+            if let Some(max_attempts) = self.reconnect_policy.max_attempts {
+                if failed_attempts >= max_attempts {
+                    return Err(error.unwrap_or_else(|| {
+                        io::Error::new(io::ErrorKind::ConnectionRefused, "Logon failed")
+                    }));
+                }
+            }
+            // Fail over to the next address.
+            address_index = (address_index + 1) % self.addresses.len();
+            let interval = self.reconnect_policy.interval_with_jitter(failed_attempts);
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    fn decoder(&self) -> DecoderStreaming<Vec<u8>> {
+        new_decoder(&self.dictionary, &self.appl_ver_ids)
+    }
+
+    async fn wait_for_schedule(&self) {
+        if let Some(schedule) = self.connection.config().schedule() {
+            let now = chrono::Utc::now();
+            if !schedule.contains(now) {
+                if let Some(next_start) = schedule.next_start(now) {
+                    let interval = (next_start - now).to_std().unwrap_or_default();
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::field_types::Timestamp;
+    use crate::tagvalue::{Config as DecoderConfig, Encoder, Message};
+    use crate::SetField;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[derive(Debug, Clone, Default)]
+    struct TestBackend {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Backend for TestBackend {
+        type Error = String;
+
+        fn sender_comp_id(&self) -> &[u8] {
+            b"SENDER"
+        }
+
+        fn target_comp_id(&self) -> &[u8] {
+            b"TARGET"
+        }
+
+        fn on_inbound_app_message(&mut self, _message: Message<&[u8]>) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_outbound_message(&mut self, _message: &[u8]) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_successful_handshake(&mut self) -> Result<(), String> {
+            self.events.lock().unwrap().push("logged on".to_string());
+            Ok(())
+        }
+
+        fn on_connecting(&mut self, _address: SocketAddr) -> Result<(), String> {
+            self.events.lock().unwrap().push("connecting".to_string());
+            Ok(())
+        }
+
+        fn on_disconnected(&mut self, _error: Option<&io::Error>) -> Result<(), String> {
+            self.events.lock().unwrap().push("disconnected".to_string());
+            Ok(())
+        }
+
+        fn pending_message(&mut self) -> Option<&[u8]> {
+            None
+        }
+    }
+
+    fn initiator(addresses: Vec<SocketAddr>) -> Initiator<TestBackend> {
+        let mut config = Config::default();
+        config.sender_comp_id = "INITIATOR".to_string();
+        config.target_comp_id = "ACCEPTOR".to_string();
+        let connection = FixConnection::new(config, TestBackend::default());
+        let mut initiator = Initiator::new(connection, addresses, Dictionary::fix44());
+        *initiator.reconnect_policy_mut() = ReconnectPolicy {
+            initial_interval: Duration::from_millis(10),
+            max_interval: Duration::from_millis(50),
+            jitter: 0.0,
+            ..ReconnectPolicy::default()
+        };
+        initiator
+    }
+
+    fn encode_message(msg_type: &[u8], msg_seq_num: u64) -> Vec<u8> {
+        let mut encoder = Encoder::<DecoderConfig>::default();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, msg_type);
+        msg.set(49, "ACCEPTOR");
+        msg.set(56, "INITIATOR");
+        msg.set(34, msg_seq_num);
+        msg.set(52, Timestamp::utc_now());
+        if msg_type == b"A" {
+            msg.set(98, 0u32);
+            msg.set(108, 30u32);
+        }
+        msg.done().0.to_vec()
+    }
+
+    async fn read_until(stream: &mut TcpStream, pattern: &str) -> String {
+        let mut response = Vec::new();
+        while !String::from_utf8_lossy(&response[..]).contains(pattern) {
+            let mut buffer = [0; 1024];
+            let n = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buffer))
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(n > 0);
+            response.extend_from_slice(&buffer[..n]);
+        }
+        String::from_utf8_lossy(&response[..]).to_string()
+    }
+
+    /// Returns an address that refuses connections.
+    async fn dead_address() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        listener.local_addr().unwrap()
+    }
+
+    #[test]
+    fn intervals_grow_exponentially() {
+        let policy = ReconnectPolicy {
+            initial_interval: Duration::from_millis(100),
+            max_interval: Duration::from_secs(1),
+            multiplier: 3.0,
+            jitter: 0.5,
+            max_attempts: None,
+        };
+        assert_eq!(policy.interval(0), Duration::from_millis(100));
+        assert_eq!(policy.interval(2), Duration::from_millis(300));
+        assert_eq!(policy.interval(u32::MAX), Duration::from_secs(1));
+        for _ in 0..100 {
+            let interval = policy.interval_with_jitter(2);
+            assert!(interval >= Duration::from_millis(150));
+            assert!(interval <= Duration::from_millis(450));
+        }
+    }
+
+    #[tokio::test]
+    async fn reconnects_with_failover_and_resumes_seq_numbers() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut initiator = initiator(vec![dead_address().await, listener.local_addr().unwrap()]);
+        let handle = initiator.connection().handle();
+        let events = initiator.connection().backend().events.clone();
+
+        let acceptor = async move {
+            // The first connection drops right after the Logon handshake.
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let logon = read_until(&mut stream, "\x0110=").await;
+            assert!(logon.contains("\x0134=1\x01"));
+            stream.write_all(&encode_message(b"A", 1)).await.unwrap();
+            drop(stream);
+
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let logon = read_until(&mut stream, "\x0110=").await;
+            assert!(logon.contains("\x0134=2\x01"));
+            stream.write_all(&encode_message(b"A", 2)).await.unwrap();
+            handle.logout(None).unwrap();
+            read_until(&mut stream, "\x0135=5\x01").await;
+            stream.write_all(&encode_message(b"5", 3)).await.unwrap();
+        };
+        let (result, ()) = tokio::join!(initiator.run(), acceptor);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "connecting",
+                "disconnected",
+                "connecting",
+                "logged on",
+                "disconnected",
+                "connecting",
+                "logged on",
+                "disconnected",
+            ]
+        );
+        assert_eq!(initiator.connection().seq_numbers().next_outbound(), 4);
+        assert_eq!(initiator.connection().seq_numbers().next_inbound(), 4);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let mut initiator = initiator(vec![dead_address().await, dead_address().await]);
+        initiator.reconnect_policy_mut().max_attempts = Some(3);
+        let events = initiator.connection().backend().events.clone();
+
+        assert!(initiator.run().await.is_err());
+        assert_eq!(
+            events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|event| *event == "connecting")
+                .count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+/// The reason why a `Logon <A>` was refused by
+/// [`Backend::on_inbound_logon`](super::Backend::on_inbound_logon).
+///
+/// The counterparty is informed with a `Logout <5>`, which carries the
+/// [`LogonRejection`] as `Text <58>` and, with FIXT.1.1, as
+/// `SessionStatus <1409>`.
+///
+/// Please note that [`LogonRejection`] is marked with `#[non_exhaustive]`,
+/// which future-proofs the enumeration type in case more variants are added.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogonRejection {
+    /// Invalid `Username <553>` or `Password <554>`.
+    InvalidCredentials,
+    /// The account is locked.
+    AccountLocked,
+    /// Logons are not allowed at this time.
+    NotAllowed,
+    /// The password has expired.
+    PasswordExpired,
+    /// `NewPassword <925>` does not comply with the password policy.
+    NewPasswordNotCompliant,
+    /// Any other reason, described by `Text <58>`.
+    Other(String),
+}
+
+impl LogonRejection {
+    /// Returns the `SessionStatus <1409>` value of `self`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::LogonRejection;
+    ///
+    /// assert_eq!(LogonRejection::InvalidCredentials.session_status(), Some(5));
+    /// assert_eq!(LogonRejection::Other("foo".to_string()).session_status(), None);
+    /// ```
+    pub fn session_status(&self) -> Option<u32> {
+        match self {
+            Self::NewPasswordNotCompliant => Some(3),
+            Self::InvalidCredentials => Some(5),
+            Self::AccountLocked => Some(6),
+            Self::NotAllowed => Some(7),
+            Self::PasswordExpired => Some(8),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for LogonRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidCredentials => write!(f, "Invalid Username(553) or Password(554)"),
+            Self::AccountLocked => write!(f, "Account locked"),
+            Self::NotAllowed => write!(f, "Logons are not allowed at this time"),
+            Self::PasswordExpired => write!(f, "Password expired"),
+            Self::NewPasswordNotCompliant => {
+                write!(f, "NewPassword(925) does not comply with the policy")
+            }
+            Self::Other(text) => write!(f, "{}", text),
+        }
+    }
+}
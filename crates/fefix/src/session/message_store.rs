@@ -0,0 +1,419 @@
+use super::SeqNumbers;
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const FILE_NAME_MESSAGES: &str = "messages";
+const FILE_NAME_SEQ_NUMBERS: &str = "seqnums";
+const FILE_NAME_CREATION_TIME: &str = "created";
+
+// `MsgSeqNum <34>` (8 bytes) + message length (4 bytes).
+const MESSAGE_RECORD_HEADER_LEN: usize = 12;
+// Next inbound (8 bytes) + next outbound (8 bytes).
+const SEQ_NUMBERS_RECORD_LEN: usize = 16;
+
+/// A storage for outbound FIX messages and [`SeqNumbers`].
+///
+/// A [`MessageStore`] allows a [`FixConnection`](super::FixConnection) to
+/// answer `ResendRequest <2>` messages on its own and to resume FIX sessions
+/// after restarts.
+pub trait MessageStore {
+    /// Saves the raw byte contents of an outbound `message` with
+    /// `MsgSeqNum <34>` equal to `msg_seq_num`.
+    fn store(&mut self, msg_seq_num: u64, message: &[u8]) -> io::Result<()>;
+
+    /// Returns all stored messages with `MsgSeqNum <34>` within `range`,
+    /// sorted by `MsgSeqNum <34>`. Missing messages are simply skipped.
+    fn fetch(&mut self, range: Range<u64>) -> io::Result<Vec<(u64, Vec<u8>)>>;
+
+    /// Returns the last [`SeqNumbers`] saved by
+    /// [`MessageStore::store_seq_numbers`], if any.
+    fn seq_numbers(&self) -> Option<SeqNumbers>;
+
+    /// Saves `seq_numbers`.
+    fn store_seq_numbers(&mut self, seq_numbers: SeqNumbers) -> io::Result<()>;
+
+    /// Deletes all messages and [`SeqNumbers`], e.g. at the start of a new FIX
+    /// session.
+    fn reset(&mut self) -> io::Result<()>;
+
+    /// Returns the time at which `self` was created or last
+    /// [`reset`](MessageStore::reset), if known. `None` by default.
+    fn creation_time(&self) -> Option<SystemTime> {
+        None
+    }
+}
+
+/// A [`MessageStore`] that keeps everything in memory. Contents are lost when
+/// dropped.
+#[derive(Debug, Clone)]
+pub struct MemoryMessageStore {
+    messages: BTreeMap<u64, Vec<u8>>,
+    seq_numbers: Option<SeqNumbers>,
+    creation_time: SystemTime,
+}
+
+impl MemoryMessageStore {
+    /// Creates a new, empty [`MemoryMessageStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for MemoryMessageStore {
+    fn default() -> Self {
+        Self {
+            messages: BTreeMap::new(),
+            seq_numbers: None,
+            creation_time: SystemTime::now(),
+        }
+    }
+}
+
+impl MessageStore for MemoryMessageStore {
+    fn store(&mut self, msg_seq_num: u64, message: &[u8]) -> io::Result<()> {
+        self.messages.insert(msg_seq_num, message.to_vec());
+        Ok(())
+    }
+
+    fn fetch(&mut self, range: Range<u64>) -> io::Result<Vec<(u64, Vec<u8>)>> {
+        Ok(self
+            .messages
+            .range(range)
+            .map(|(msg_seq_num, message)| (*msg_seq_num, message.clone()))
+            .collect())
+    }
+
+    fn seq_numbers(&self) -> Option<SeqNumbers> {
+        self.seq_numbers
+    }
+
+    fn store_seq_numbers(&mut self, seq_numbers: SeqNumbers) -> io::Result<()> {
+        self.seq_numbers = Some(seq_numbers);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.messages.clear();
+        self.seq_numbers = None;
+        self.creation_time = SystemTime::now();
+        Ok(())
+    }
+
+    fn creation_time(&self) -> Option<SystemTime> {
+        Some(self.creation_time)
+    }
+}
+
+/// A [`MessageStore`] backed by append-only files within a directory.
+///
+/// Messages are only ever appended to disk; an in-memory index of their
+/// location is rebuilt by [`FileMessageStore::open`]. Incomplete records at
+/// the end of the files, e.g. after a crash, are discarded.
+#[derive(Debug)]
+pub struct FileMessageStore {
+    messages: File,
+    seq_numbers_file: File,
+    // `MsgSeqNum <34>` -> position and length of the message within
+    // `messages`.
+    index: BTreeMap<u64, (u64, usize)>,
+    messages_len: u64,
+    seq_numbers: Option<SeqNumbers>,
+    creation_time_file: File,
+    creation_time: SystemTime,
+}
+
+impl FileMessageStore {
+    /// Opens the [`FileMessageStore`] located in `dir`, creating it if it
+    /// doesn't exist.
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let open_options = {
+            let mut options = OpenOptions::new();
+            options.read(true).write(true).create(true);
+            options
+        };
+        let mut messages = open_options.open(dir.join(FILE_NAME_MESSAGES))?;
+        let mut seq_numbers_file = open_options.open(dir.join(FILE_NAME_SEQ_NUMBERS))?;
+
+        let (index, messages_len) = {
+            let mut contents = Vec::new();
+            messages.read_to_end(&mut contents)?;
+            index_messages(&contents[..])
+        };
+        messages.set_len(messages_len)?;
+
+        let seq_numbers = {
+            let mut contents = Vec::new();
+            seq_numbers_file.read_to_end(&mut contents)?;
+            contents
+                .chunks_exact(SEQ_NUMBERS_RECORD_LEN)
+                .last()
+                .map(decode_seq_numbers)
+        };
+        // Only the last record is relevant, so we can compact the file.
+        seq_numbers_file.set_len(0)?;
+        seq_numbers_file.seek(SeekFrom::Start(0))?;
+        if let Some(seq_numbers) = seq_numbers {
+            seq_numbers_file.write_all(&encode_seq_numbers(seq_numbers))?;
+            seq_numbers_file.sync_data()?;
+        }
+
+        let mut creation_time_file = open_options.open(dir.join(FILE_NAME_CREATION_TIME))?;
+        let creation_time = {
+            let mut contents = Vec::new();
+            creation_time_file.read_to_end(&mut contents)?;
+            if contents.len() == 8 {
+                let nanos = u64::from_be_bytes(read_array(&contents[..]));
+                UNIX_EPOCH + Duration::from_nanos(nanos)
+            } else {
+                let now = SystemTime::now();
+                write_creation_time(&mut creation_time_file, now)?;
+                now
+            }
+        };
+
+        Ok(Self {
+            messages,
+            seq_numbers_file,
+            index,
+            messages_len,
+            seq_numbers,
+            creation_time_file,
+            creation_time,
+        })
+    }
+}
+
+impl MessageStore for FileMessageStore {
+    fn store(&mut self, msg_seq_num: u64, message: &[u8]) -> io::Result<()> {
+        let mut record = Vec::with_capacity(MESSAGE_RECORD_HEADER_LEN + message.len());
+        record.extend_from_slice(&msg_seq_num.to_be_bytes());
+        record.extend_from_slice(&(message.len() as u32).to_be_bytes());
+        record.extend_from_slice(message);
+        self.messages.seek(SeekFrom::Start(self.messages_len))?;
+        self.messages.write_all(&record[..])?;
+        self.messages.sync_data()?;
+        let position = self.messages_len + MESSAGE_RECORD_HEADER_LEN as u64;
+        self.index.insert(msg_seq_num, (position, message.len()));
+        self.messages_len += record.len() as u64;
+        Ok(())
+    }
+
+    fn fetch(&mut self, range: Range<u64>) -> io::Result<Vec<(u64, Vec<u8>)>> {
+        let mut messages = Vec::new();
+        for (msg_seq_num, (position, len)) in self.index.range(range) {
+            let mut message = vec![0; *len];
+            self.messages.seek(SeekFrom::Start(*position))?;
+            self.messages.read_exact(&mut message[..])?;
+            messages.push((*msg_seq_num, message));
+        }
+        Ok(messages)
+    }
+
+    fn seq_numbers(&self) -> Option<SeqNumbers> {
+        self.seq_numbers
+    }
+
+    fn store_seq_numbers(&mut self, seq_numbers: SeqNumbers) -> io::Result<()> {
+        self.seq_numbers_file.seek(SeekFrom::End(0))?;
+        self.seq_numbers_file
+            .write_all(&encode_seq_numbers(seq_numbers))?;
+        self.seq_numbers_file.sync_data()?;
+        self.seq_numbers = Some(seq_numbers);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.messages.set_len(0)?;
+        self.messages.sync_data()?;
+        self.seq_numbers_file.set_len(0)?;
+        self.seq_numbers_file.sync_data()?;
+        self.index.clear();
+        self.messages_len = 0;
+        self.seq_numbers = None;
+        self.creation_time = SystemTime::now();
+        write_creation_time(&mut self.creation_time_file, self.creation_time)
+    }
+
+    fn creation_time(&self) -> Option<SystemTime> {
+        Some(self.creation_time)
+    }
+}
+
+/// Returns the index of all complete message records within `contents`, as
+/// well as their total length in bytes.
+fn index_messages(contents: &[u8]) -> (BTreeMap<u64, (u64, usize)>, u64) {
+    let mut index = BTreeMap::new();
+    let mut i = 0;
+    while contents.len() - i >= MESSAGE_RECORD_HEADER_LEN {
+        let msg_seq_num = u64::from_be_bytes(read_array(&contents[i..]));
+        let len = u32::from_be_bytes(read_array(&contents[i + 8..])) as usize;
+        let start = i + MESSAGE_RECORD_HEADER_LEN;
+        if contents.len() - start < len {
+            break;
+        }
+        index.insert(msg_seq_num, (start as u64, len));
+        i = start + len;
+    }
+    (index, i as u64)
+}
+
+fn encode_seq_numbers(seq_numbers: SeqNumbers) -> [u8; SEQ_NUMBERS_RECORD_LEN] {
+    let mut record = [0; SEQ_NUMBERS_RECORD_LEN];
+    record[..8].copy_from_slice(&seq_numbers.next_inbound().to_be_bytes());
+    record[8..].copy_from_slice(&seq_numbers.next_outbound().to_be_bytes());
+    record
+}
+
+fn decode_seq_numbers(record: &[u8]) -> SeqNumbers {
+    SeqNumbers {
+        next_inbound: u64::from_be_bytes(read_array(record)),
+        next_outbound: u64::from_be_bytes(read_array(&record[8..])),
+    }
+}
+
+fn write_creation_time(file: &mut File, creation_time: SystemTime) -> io::Result<()> {
+    let nanos = creation_time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64;
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(&nanos.to_be_bytes())?;
+    file.sync_data()
+}
+
+fn read_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut array = [0; N];
+    array.copy_from_slice(&bytes[..N]);
+    array
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("fefix-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn seq_numbers(inbound: u64, outbound: u64) -> SeqNumbers {
+        SeqNumbers {
+            next_inbound: inbound,
+            next_outbound: outbound,
+        }
+    }
+
+    fn store_and_fetch(store: &mut impl MessageStore) {
+        store.store(1, b"foo").unwrap();
+        store.store(2, b"bar").unwrap();
+        store.store(4, b"spam").unwrap();
+        assert_eq!(
+            store.fetch(2..5).unwrap(),
+            vec![(2, b"bar".to_vec()), (4, b"spam".to_vec())]
+        );
+        assert!(store.fetch(5..10).unwrap().is_empty());
+        assert_eq!(store.seq_numbers(), None);
+        store.store_seq_numbers(seq_numbers(3, 5)).unwrap();
+        store.store_seq_numbers(seq_numbers(4, 5)).unwrap();
+        assert_eq!(store.seq_numbers(), Some(seq_numbers(4, 5)));
+    }
+
+    #[test]
+    fn memory_store_and_fetch() {
+        store_and_fetch(&mut MemoryMessageStore::new());
+    }
+
+    #[test]
+    fn file_store_and_fetch() {
+        let dir = temp_dir();
+        store_and_fetch(&mut FileMessageStore::open(&dir).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn file_store_survives_reopening() {
+        let dir = temp_dir();
+        {
+            let mut store = FileMessageStore::open(&dir).unwrap();
+            store.store(1, b"foo").unwrap();
+            store.store(2, b"bar").unwrap();
+            store.store_seq_numbers(seq_numbers(1, 3)).unwrap();
+        }
+        let mut store = FileMessageStore::open(&dir).unwrap();
+        assert_eq!(store.seq_numbers(), Some(seq_numbers(1, 3)));
+        store.store(3, b"spam").unwrap();
+        assert_eq!(
+            store.fetch(1..4).unwrap(),
+            vec![
+                (1, b"foo".to_vec()),
+                (2, b"bar".to_vec()),
+                (3, b"spam".to_vec())
+            ]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn file_store_discards_incomplete_records() {
+        let dir = temp_dir();
+        {
+            let mut store = FileMessageStore::open(&dir).unwrap();
+            store.store(1, b"foo").unwrap();
+            store.store(2, b"bar").unwrap();
+        }
+        {
+            let file = OpenOptions::new()
+                .write(true)
+                .open(dir.join(FILE_NAME_MESSAGES))
+                .unwrap();
+            let len = file.metadata().unwrap().len();
+            file.set_len(len - 1).unwrap();
+        }
+        let mut store = FileMessageStore::open(&dir).unwrap();
+        store.store(3, b"spam").unwrap();
+        assert_eq!(
+            store.fetch(1..4).unwrap(),
+            vec![(1, b"foo".to_vec()), (3, b"spam".to_vec())]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn file_store_reset() {
+        let dir = temp_dir();
+        {
+            let mut store = FileMessageStore::open(&dir).unwrap();
+            store.store(1, b"foo").unwrap();
+            store.store_seq_numbers(seq_numbers(2, 2)).unwrap();
+            store.reset().unwrap();
+            assert!(store.fetch(1..2).unwrap().is_empty());
+            assert_eq!(store.seq_numbers(), None);
+        }
+        let mut store = FileMessageStore::open(&dir).unwrap();
+        assert!(store.fetch(1..2).unwrap().is_empty());
+        assert_eq!(store.seq_numbers(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn file_store_creation_time() {
+        let dir = temp_dir();
+        let creation_time = {
+            let mut store = FileMessageStore::open(&dir).unwrap();
+            let creation_time = store.creation_time().unwrap();
+            store.reset().unwrap();
+            assert!(store.creation_time().unwrap() >= creation_time);
+            store.creation_time()
+        };
+        let store = FileMessageStore::open(&dir).unwrap();
+        assert_eq!(store.creation_time(), creation_time);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
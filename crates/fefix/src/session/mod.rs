@@ -5,32 +5,50 @@
 //! The above is a conceptual view of the FIX Session layer, complete with its
 //! state machine and transitions between initiator and acceptor.
 
+mod acceptor;
 pub mod backends;
 mod config;
-//mod connection; FIXME
+mod connection;
 mod environment;
 mod errs;
 mod event_loop;
 mod heartbeat_rule;
+#[cfg(feature = "utils-tokio")]
+mod initiator;
+mod logon_rejection;
+mod message_store;
 mod resend_request_range;
+mod schedule;
 mod seq_numbers;
 
+pub use acceptor::{Acceptor, AcceptorError, SessionId};
 pub use config::{Config, Configure};
-// pub use connection::*; FIXME
+pub use connection::*;
 pub use environment::Environment;
 pub use event_loop::*;
 pub use heartbeat_rule::HeartbeatRule;
+#[cfg(feature = "utils-tokio")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "utils-tokio")))]
+pub use initiator::{Initiator, ReconnectPolicy};
+pub use logon_rejection::LogonRejection;
+pub use message_store::{FileMessageStore, MemoryMessageStore, MessageStore};
 pub use resend_request_range::ResendRequestRange;
+pub use schedule::{ResetSeqNumPolicy, SessionDays, SessionSchedule};
 pub use seq_numbers::{SeqNumberError, SeqNumbers};
 
-use crate::{tagvalue::Message, FieldType, SetField};
+use crate::tagvalue::{DecodeError, Message};
+use crate::SetField;
+use std::io;
+use std::net::SocketAddr;
 use std::ops::Range;
 
 /// The owner of a [`FixConnection`]. It can react to events, store incoming
 /// messages, send messages, etc..
 pub trait Backend: Clone {
-    /// The type of errors that can arise during a [`FixConnection`].
-    type Error: for<'a> FieldType<'a>;
+    /// The type of errors that can arise during a [`FixConnection`]. Errors
+    /// that originate from inbound application messages are reported to the
+    /// counterparty as `Text <58>`.
+    type Error: std::fmt::Display;
 
     fn sender_comp_id(&self) -> &[u8];
     fn target_comp_id(&self) -> &[u8];
@@ -73,14 +91,57 @@ pub trait Backend: Clone {
         }
     }
 
-    /// Callback for processing `ResendRequest` messages.
-    fn on_resend_request(&mut self, range: Range<u64>) -> Result<(), Self::Error>;
+    /// Callback for logging garbled inbound data, which is otherwise ignored.
+    /// `garbled` contains all bytes that were discarded to resynchronize
+    /// with the start of the next message.
+    #[inline]
+    fn on_garbled_message(
+        &mut self,
+        _garbled: &[u8],
+        _error: &DecodeError,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Callback for processing `ResendRequest` messages. The
+    /// [`FixConnection`] answers them on its own with the contents of its
+    /// [`MessageStore`] right after this callback.
+    #[inline]
+    fn on_resend_request(&mut self, _range: Range<u64>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Callback for validating inbound `Logon <A>` messages, e.g. their
+    /// `Username <553>`, `Password <554>` and `NewPassword <925>` fields.
+    /// Refused Logons are answered with a `Logout <5>` that carries the
+    /// [`LogonRejection`].
+    #[inline]
+    fn on_inbound_logon(&mut self, _logon: Message<&[u8]>) -> Result<(), LogonRejection> {
+        Ok(())
+    }
+
+    /// Callback for adding custom fields to outbound `Logon <A>` messages, e.g.
+    /// credentials.
+    #[inline]
+    fn on_outbound_logon(&mut self, _logon: &mut impl SetField<u32>) {}
 
     /// Callback for additional logic to execute after a valid [`FixConnection`]
     /// is established with the counterparty.
     fn on_successful_handshake(&mut self) -> Result<(), Self::Error>;
 
-    fn fetch_messages(&mut self) -> Result<&[&[u8]], Self::Error>;
+    /// Callback for when an initiator is about to open a new connection to
+    /// `address`, e.g. after the previous one dropped.
+    #[inline]
+    fn on_connecting(&mut self, _address: SocketAddr) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Callback for when the transport layer of a [`FixConnection`] is closed,
+    /// either gracefully or because of `error`.
+    #[inline]
+    fn on_disconnected(&mut self, _error: Option<&io::Error>) -> Result<(), Self::Error> {
+        Ok(())
+    }
 
     fn pending_message(&mut self) -> Option<&[u8]>;
 }
@@ -116,7 +177,3 @@ impl Iterator for MsgSeqNumCounter {
         Some(MsgSeqNumCounter::next(self))
     }
 }
-
-// FIXME
-#[derive(Debug)]
-pub struct FixConnection;
@@ -0,0 +1,216 @@
+use crate::field_types::Tz;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use std::ops::Range;
+
+/// The trading hours of a FIX session, i.e. the time windows during which a
+/// [`FixConnection`](super::FixConnection) is allowed to be logged on.
+///
+/// Times are expressed in the local time of [`SessionSchedule::timezone`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::field_types::Tz;
+/// use fefix::FieldType;
+/// use fefix::session::{SessionDays, SessionSchedule};
+/// use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+///
+/// // From 08:00 to 17:00 in UTC+01:00, Monday to Friday.
+/// let schedule = SessionSchedule {
+///     start_time: NaiveTime::from_hms(8, 0, 0),
+///     end_time: NaiveTime::from_hms(17, 0, 0),
+///     days: SessionDays::Daily(vec![
+///         Weekday::Mon,
+///         Weekday::Tue,
+///         Weekday::Wed,
+///         Weekday::Thu,
+///         Weekday::Fri,
+///     ]),
+///     timezone: Tz::deserialize(b"+01").unwrap(),
+/// };
+///
+/// // 2021-03-05 is a Friday.
+/// assert!(schedule.contains(Utc.ymd(2021, 3, 5).and_hms(7, 0, 0)));
+/// assert!(!schedule.contains(Utc.ymd(2021, 3, 5).and_hms(16, 0, 0)));
+/// assert!(!schedule.contains(Utc.ymd(2021, 3, 6).and_hms(7, 0, 0)));
+/// assert_eq!(
+///     schedule.next_start(Utc.ymd(2021, 3, 5).and_hms(16, 0, 0)),
+///     Some(Utc.ymd(2021, 3, 8).and_hms(7, 0, 0))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionSchedule {
+    /// The local time at which sessions start.
+    pub start_time: NaiveTime,
+    /// The local time at which sessions end. Sessions end on the following
+    /// day (or week) if this is not after [`SessionSchedule::start_time`].
+    pub end_time: NaiveTime,
+    /// The days of the week on which sessions take place.
+    pub days: SessionDays,
+    /// The timezone of [`SessionSchedule::start_time`] and
+    /// [`SessionSchedule::end_time`].
+    pub timezone: Tz,
+}
+
+/// The days of the week covered by a [`SessionSchedule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SessionDays {
+    /// One session per day, starting on any of the listed days of the week.
+    /// An empty list stands for every day.
+    Daily(Vec<Weekday>),
+    /// One session per week, from the start time on the first day of the week
+    /// to the end time on the second one.
+    Weekly(Weekday, Weekday),
+}
+
+impl SessionSchedule {
+    /// Returns the session that is ongoing at `now`, if any.
+    pub fn current_session(&self, now: DateTime<Utc>) -> Option<Range<DateTime<Utc>>> {
+        let now = self.to_local(now);
+        // No session lasts longer than a week.
+        (0..=7)
+            .filter_map(|days_ago| self.session_starting_on(now.date() - Duration::days(days_ago)))
+            .find(|session| session.contains(&now))
+            .map(|session| self.to_utc(session.start)..self.to_utc(session.end))
+    }
+
+    /// Returns `true` if and only if `now` is within a session of `self`.
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        self.current_session(now).is_some()
+    }
+
+    /// Returns the start of the first session after `now`, if any.
+    pub fn next_start(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let now = self.to_local(now);
+        (0..=7)
+            .filter_map(|days| self.session_starting_on(now.date() + Duration::days(days)))
+            .map(|session| session.start)
+            .find(|start| *start > now)
+            .map(|start| self.to_utc(start))
+    }
+
+    fn session_starting_on(&self, date: NaiveDate) -> Option<Range<NaiveDateTime>> {
+        let (is_start_day, duration_in_days, period_in_days) = match &self.days {
+            SessionDays::Daily(days) => (days.is_empty() || days.contains(&date.weekday()), 0, 1),
+            SessionDays::Weekly(start, end) => {
+                let days = (7 + end.num_days_from_monday() - start.num_days_from_monday()) % 7;
+                (date.weekday() == *start, days, 7)
+            }
+        };
+        if !is_start_day {
+            return None;
+        }
+        let start = date.and_time(self.start_time);
+        let mut end = (date + Duration::days(duration_in_days.into())).and_time(self.end_time);
+        if end <= start {
+            end += Duration::days(period_in_days);
+        }
+        Some(start..end)
+    }
+
+    fn to_local(&self, datetime: DateTime<Utc>) -> NaiveDateTime {
+        datetime.naive_utc() + Duration::seconds(self.timezone.offset_as_secs().into())
+    }
+
+    fn to_utc(&self, datetime: NaiveDateTime) -> DateTime<Utc> {
+        let naive = datetime - Duration::seconds(self.timezone.offset_as_secs().into());
+        DateTime::from_utc(naive, Utc)
+    }
+}
+
+/// When to reset [`SeqNumbers`](super::SeqNumbers) during the Logon
+/// handshake. Initiators that reset their [`SeqNumbers`](super::SeqNumbers)
+/// also ask their counterparty to do the same with `ResetSeqNumFlag <141>`.
+///
+/// Requests to reset from the counterparty are always honored, regardless of
+/// the [`ResetSeqNumPolicy`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResetSeqNumPolicy {
+    /// [`SeqNumbers`](super::SeqNumbers) are never reset.
+    Never,
+    /// [`SeqNumbers`](super::SeqNumbers) are reset at every Logon.
+    Always,
+    /// [`SeqNumbers`](super::SeqNumbers) are reset at the first Logon of each
+    /// session of the [`SessionSchedule`], e.g. daily or weekly. Without a
+    /// [`SessionSchedule`], each UTC day is a separate session. This relies
+    /// on [`MessageStore::creation_time`](super::MessageStore::creation_time).
+    NewSession,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FieldType;
+    use chrono::TimeZone;
+
+    fn schedule(start: (u32, u32), end: (u32, u32), days: SessionDays) -> SessionSchedule {
+        SessionSchedule {
+            start_time: NaiveTime::from_hms(start.0, start.1, 0),
+            end_time: NaiveTime::from_hms(end.0, end.1, 0),
+            days,
+            timezone: Tz::UTC,
+        }
+    }
+
+    #[test]
+    fn daily_overnight_sessions() {
+        let schedule = schedule((22, 0), (6, 0), SessionDays::Daily(vec![]));
+        // 2021-03-01 is a Monday.
+        let session = schedule
+            .current_session(Utc.ymd(2021, 3, 2).and_hms(3, 0, 0))
+            .unwrap();
+        assert_eq!(session.start, Utc.ymd(2021, 3, 1).and_hms(22, 0, 0));
+        assert_eq!(session.end, Utc.ymd(2021, 3, 2).and_hms(6, 0, 0));
+        assert!(!schedule.contains(Utc.ymd(2021, 3, 2).and_hms(6, 0, 0)));
+        assert_eq!(
+            schedule.next_start(Utc.ymd(2021, 3, 2).and_hms(12, 0, 0)),
+            Some(Utc.ymd(2021, 3, 2).and_hms(22, 0, 0))
+        );
+    }
+
+    #[test]
+    fn weekly_sessions() {
+        let schedule = schedule(
+            (17, 0),
+            (17, 0),
+            SessionDays::Weekly(Weekday::Sun, Weekday::Fri),
+        );
+        // 2021-03-07 is a Sunday.
+        let session = schedule
+            .current_session(Utc.ymd(2021, 3, 10).and_hms(12, 0, 0))
+            .unwrap();
+        assert_eq!(session.start, Utc.ymd(2021, 3, 7).and_hms(17, 0, 0));
+        assert_eq!(session.end, Utc.ymd(2021, 3, 12).and_hms(17, 0, 0));
+        assert!(!schedule.contains(Utc.ymd(2021, 3, 13).and_hms(12, 0, 0)));
+        assert_eq!(
+            schedule.next_start(Utc.ymd(2021, 3, 13).and_hms(12, 0, 0)),
+            Some(Utc.ymd(2021, 3, 14).and_hms(17, 0, 0))
+        );
+    }
+
+    #[test]
+    fn weekly_sessions_with_the_same_start_and_end_day() {
+        let schedule = schedule(
+            (9, 0),
+            (9, 0),
+            SessionDays::Weekly(Weekday::Mon, Weekday::Mon),
+        );
+        let session = schedule
+            .current_session(Utc.ymd(2021, 3, 7).and_hms(12, 0, 0))
+            .unwrap();
+        assert_eq!(session.start, Utc.ymd(2021, 3, 1).and_hms(9, 0, 0));
+        assert_eq!(session.end, Utc.ymd(2021, 3, 8).and_hms(9, 0, 0));
+    }
+
+    #[test]
+    fn sessions_in_other_timezones() {
+        let mut schedule = schedule((8, 0), (17, 0), SessionDays::Daily(vec![Weekday::Mon]));
+        schedule.timezone = Tz::deserialize(b"-05").unwrap();
+        assert!(!schedule.contains(Utc.ymd(2021, 3, 1).and_hms(12, 0, 0)));
+        assert!(schedule.contains(Utc.ymd(2021, 3, 1).and_hms(13, 0, 0)));
+        assert!(schedule.contains(Utc.ymd(2021, 3, 1).and_hms(21, 59, 59)));
+        assert!(!schedule.contains(Utc.ymd(2021, 3, 1).and_hms(22, 0, 0)));
+    }
+}
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 use std::num::NonZeroU64;
 
 /// A tracker for seq. numbers inside a FIX session.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SeqNumbers {
     pub next_inbound: u64,
     pub next_outbound: u64,
@@ -1,6 +1,40 @@
 const SOH: u8 = 0x1;
 const DEFAULT_MAX_MESSAGE_SIZE: usize = 0xffff;
 
+/// What to do with a tag that appears more than once outside repeating groups,
+/// or more than once within the same group entry; see
+/// [`Configure::duplicate_tag_policy`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateTagPolicy {
+    /// Fail with [`DecodeError::DuplicateTag`](super::DecodeError::DuplicateTag).
+    Error,
+    /// Keep the first occurrence and discard the others.
+    FirstWins,
+    /// Keep the value of the last occurrence, in the position of the first one.
+    LastWins,
+    /// Keep all occurrences in [`Message::fields`](super::Message::fields),
+    /// e.g. for [`Validator`](super::Validator). Random access returns the last
+    /// one.
+    #[default]
+    KeepAll,
+}
+
+/// What to do with tags that are not defined by the [`Dictionary`] of the
+/// decoder; see [`Configure::unknown_tag_policy`].
+///
+/// [`Dictionary`]: crate::Dictionary
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum UnknownTagPolicy {
+    /// Decode unknown tags like any other tag.
+    #[default]
+    Allow,
+    /// Fail with [`DecodeError::UnknownTag`](super::DecodeError::UnknownTag).
+    Reject,
+    /// Set unknown tags apart from the other fields, so that they are only
+    /// available via [`Message::unknown_fields`](super::Message::unknown_fields).
+    Collect,
+}
+
 /// A provider of configuration options related to FIX encoding and decoding.
 ///
 /// # Implementing this trait
@@ -65,6 +99,34 @@ pub trait Configure: Clone + Default {
     fn should_decode_associative(&self) -> bool {
         true
     }
+
+    /// Determines what the decoder does with duplicate tags. Duplicates are
+    /// only detected when [`Configure::should_decode_associative`] is on.
+    ///
+    /// [`DuplicateTagPolicy::KeepAll`] by default.
+    #[inline]
+    fn duplicate_tag_policy(&self) -> DuplicateTagPolicy {
+        DuplicateTagPolicy::KeepAll
+    }
+
+    /// Determines what the decoder does with tags that are not defined by its
+    /// [`Dictionary`](crate::Dictionary).
+    ///
+    /// [`UnknownTagPolicy::Allow`] by default.
+    #[inline]
+    fn unknown_tag_policy(&self) -> UnknownTagPolicy {
+        UnknownTagPolicy::Allow
+    }
+
+    /// Determines whether or not the decoder rejects `StandardHeader` fields
+    /// that come after the first message body field. Unknown tags are never
+    /// considered to be part of the message body.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    #[inline]
+    fn verify_header_order(&self) -> bool {
+        false
+    }
 }
 
 /// A `struct` that has settable fields and implements [`Configure`].
@@ -79,6 +141,9 @@ pub struct Config {
     max_message_size: Option<usize>,
     verify_checksum: bool,
     should_decode_associative: bool,
+    duplicate_tag_policy: DuplicateTagPolicy,
+    unknown_tag_policy: UnknownTagPolicy,
+    verify_header_order: bool,
 }
 
 impl Config {
@@ -130,6 +195,32 @@ impl Config {
     pub fn set_decode_assoc(&mut self, should: bool) {
         self.should_decode_associative = should;
     }
+
+    /// Changes the value of [`Configure::duplicate_tag_policy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure, DuplicateTagPolicy};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.duplicate_tag_policy(), DuplicateTagPolicy::KeepAll);
+    /// config.set_duplicate_tag_policy(DuplicateTagPolicy::Error);
+    /// assert_eq!(config.duplicate_tag_policy(), DuplicateTagPolicy::Error);
+    /// ```
+    pub fn set_duplicate_tag_policy(&mut self, policy: DuplicateTagPolicy) {
+        self.duplicate_tag_policy = policy;
+    }
+
+    /// Changes the value of [`Configure::unknown_tag_policy`].
+    pub fn set_unknown_tag_policy(&mut self, policy: UnknownTagPolicy) {
+        self.unknown_tag_policy = policy;
+    }
+
+    /// Turns on or off [`Configure::verify_header_order`]. Off by default.
+    pub fn set_verify_header_order(&mut self, verify: bool) {
+        self.verify_header_order = verify;
+    }
 }
 
 impl Configure for Config {
@@ -152,6 +243,21 @@ impl Configure for Config {
     fn should_decode_associative(&self) -> bool {
         self.should_decode_associative
     }
+
+    #[inline]
+    fn duplicate_tag_policy(&self) -> DuplicateTagPolicy {
+        self.duplicate_tag_policy
+    }
+
+    #[inline]
+    fn unknown_tag_policy(&self) -> UnknownTagPolicy {
+        self.unknown_tag_policy
+    }
+
+    #[inline]
+    fn verify_header_order(&self) -> bool {
+        self.verify_header_order
+    }
 }
 
 impl Default for Config {
@@ -161,6 +267,9 @@ impl Default for Config {
             separator: SOH,
             verify_checksum: true,
             should_decode_associative: true,
+            duplicate_tag_policy: DuplicateTagPolicy::default(),
+            unknown_tag_policy: UnknownTagPolicy::default(),
+            verify_header_order: false,
         }
     }
 }
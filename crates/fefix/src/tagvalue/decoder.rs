@@ -1,16 +1,21 @@
-use super::{Config, Configure, DecodeError, RawDecoder, RawDecoderStreaming, RawFrame};
-use crate::dict::IsFieldDefinition;
+use super::{
+    Config, Configure, DecodeError, DuplicateTagPolicy, RawDecoder, RawDecoderStreaming, RawFrame,
+    UnknownTagPolicy,
+};
+use crate::dict::{IsFieldDefinition, LayoutItem, LayoutItemKind};
+use crate::field_types::MessageEncoding;
 use crate::FieldValueError;
 use crate::{
     dict::FixDatatype, Buffer, Dictionary, FieldMap, FieldType, GetConfig, RepeatingGroup,
     StreamingDecoder, TagU32,
 };
-use nohash_hasher::IntMap;
+use nohash_hasher::{IntMap, IntSet};
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt::Debug;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Univocally locates a tag within a FIX message, even with nested groups.
 ///
@@ -35,12 +40,35 @@ enum FieldLocatorContext {
     },
 }
 
+fn top_level_locator(tag: u32) -> Option<FieldLocator> {
+    Some(FieldLocator {
+        tag: TagU32::new(tag)?,
+        context: FieldLocatorContext::TopLevel,
+    })
+}
+
 // Number of bytes before the start of the `BeginString` field:
 //
 //   ~~
 //   8=FIX.4.2|...
 const BEGIN_STRING_OFFSET: usize = 2;
 
+const APPL_VER_ID: u32 = 1128;
+const MESSAGE_ENCODING: u32 = 347;
+
+/// Fields that need special treatment during decoding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TagKind {
+    NumInGroup,
+    /// A `Length` field that gives the length of the data field with this
+    /// tag, which is allowed to contain the separator.
+    Length(TagU32),
+    /// Any other field defined by the dictionary.
+    Regular,
+}
+
+type TagLookup = IntMap<u32, TagKind>;
+
 /// FIX message decoder.
 ///
 /// One should create a [`Decoder`] per stream of FIX messages.
@@ -48,7 +76,13 @@ const BEGIN_STRING_OFFSET: usize = 2;
 pub struct Decoder<C = Config> {
     builder: MessageBuilder<'static>,
     raw_decoder: RawDecoder<C>,
-    tag_lookup: IntMap<u32, FixDatatype>,
+    tag_lookup: TagLookup,
+    header_tags: IntSet<u32>,
+    // FIXT.1.1 only: application-level dictionaries by `ApplVerID <1128>`.
+    appl_ver_ids: Vec<(Vec<u8>, TagLookup)>,
+    default_appl_ver_id: Option<usize>,
+    // Index in `appl_ver_ids` of the message that is being decoded, if any.
+    current_appl_ver_id: Option<usize>,
 }
 
 impl<C> Decoder<C>
@@ -61,24 +95,71 @@ where
         Self {
             builder: MessageBuilder::default(),
             raw_decoder: RawDecoder::default(),
-            tag_lookup: dict
-                .iter_fields()
-                .filter_map(|field| {
-                    let mut fix_type = field.data_type().basetype();
-                    if field.is_num_in_group() {
-                        fix_type = FixDatatype::NumInGroup;
-                    }
+            tag_lookup: tag_lookup(&dict),
+            header_tags: header_tags(&dict),
+            appl_ver_ids: Vec::new(),
+            default_appl_ver_id: None,
+            current_appl_ver_id: None,
+        }
+    }
 
-                    if fix_type == FixDatatype::Length || fix_type == FixDatatype::NumInGroup {
-                        Some((field.tag().get(), fix_type))
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
+    /// Registers `dict` as the application-level [`Dictionary`] of
+    /// `ApplVerID <1128>` `appl_ver_id`, e.g. `9` for FIX 5.0 SP2.
+    ///
+    /// This is only useful with FIXT.1.1, where `self` is created with the
+    /// FIXT.1.1 [`Dictionary`] (session-level messages) and each application
+    /// message is then decoded according to its `ApplVerID <1128>`, or
+    /// according to [`Decoder::set_default_appl_ver_id`] if the former is
+    /// missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::prelude::*;
+    ///
+    /// # #[cfg(all(feature = "fixt11", feature = "fix50sp2"))]
+    /// # {
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fixt11());
+    /// decoder.config_mut().set_separator(b'|');
+    /// decoder.add_appl_ver_id(b"9", Dictionary::fix50sp2());
+    ///
+    /// let data = b"8=FIXT.1.1|9=42|35=8|1128=9|453=2|448=A|447=D|448=B|447=D|10=000|";
+    /// let message = decoder.decode(data).unwrap();
+    /// let parties = message.group(453).unwrap();
+    /// assert_eq!(parties.len(), 2);
+    /// # }
+    /// ```
+    pub fn add_appl_ver_id(&mut self, appl_ver_id: &[u8], dict: Dictionary) {
+        let tag_lookup = tag_lookup(&dict);
+        match self.appl_ver_id_index(appl_ver_id) {
+            Some(i) => self.appl_ver_ids[i].1 = tag_lookup,
+            None => self.appl_ver_ids.push((appl_ver_id.to_vec(), tag_lookup)),
         }
     }
 
+    /// Returns the `DefaultApplVerID <1137>` of `self`, if any.
+    pub fn default_appl_ver_id(&self) -> Option<&[u8]> {
+        self.default_appl_ver_id
+            .map(|i| self.appl_ver_ids[i].0.as_slice())
+    }
+
+    /// Sets the `DefaultApplVerID <1137>` of `self`, i.e. the application
+    /// version of messages without `ApplVerID <1128>`. This is typically
+    /// negotiated via `Logon <A>`.
+    ///
+    /// `appl_ver_id` is ignored unless it was previously registered via
+    /// [`Decoder::add_appl_ver_id`].
+    pub fn set_default_appl_ver_id(&mut self, appl_ver_id: Option<&[u8]>) {
+        self.default_appl_ver_id = appl_ver_id.and_then(|id| self.appl_ver_id_index(id));
+    }
+
+    fn appl_ver_id_index(&self, appl_ver_id: &[u8]) -> Option<usize> {
+        self.appl_ver_ids
+            .iter()
+            .position(|(id, _)| id.as_slice() == appl_ver_id)
+    }
+
     /// Adds a [`Buffer`] to `self`, turning it into a [`StreamingDecoder`].
     pub fn streaming<B>(self, buffer: B) -> DecoderStreaming<B, C>
     where
@@ -118,6 +199,32 @@ where
         self.from_frame(frame)
     }
 
+    /// Like [`Decoder::decode`], but returns an [`OwnedMessage`] which takes
+    /// ownership of `bytes` instead of borrowing `self`. No data is copied,
+    /// so this is cheap with e.g. [`Vec<u8>`] or `bytes::Bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::definitions::fix44;
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::prelude::*;
+    ///
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+    /// decoder.config_mut().set_separator(b'|');
+    /// let data = b"8=FIX.4.4|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=185|".to_vec();
+    /// let message = decoder.decode_owned(data).unwrap();
+    /// let handle = std::thread::spawn(move || message.fv::<u32>(fix44::MSG_SEQ_NUM));
+    /// assert_eq!(handle.join().unwrap(), Ok(12));
+    /// ```
+    pub fn decode_owned<T>(&mut self, bytes: T) -> Result<OwnedMessage<T>, DecodeError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let (fields, unknown_fields) = self.decode(bytes.as_ref())?.owned_fields();
+        Ok(OwnedMessage::new(bytes, fields, unknown_fields))
+    }
+
     fn message_builder_mut<'a>(&'a mut self) -> &'a mut MessageBuilder<'a> {
         unsafe { std::mem::transmute(&mut self.builder) }
     }
@@ -127,6 +234,7 @@ where
         T: AsRef<[u8]>,
     {
         self.builder.clear();
+        self.current_appl_ver_id = self.default_appl_ver_id;
         self.message_builder_mut().bytes = frame.as_bytes();
         let separator = self.config().separator();
         let payload = frame.payload();
@@ -135,7 +243,7 @@ where
             frame.as_bytes(),
             BEGIN_STRING_OFFSET,
             frame.begin_string().len(),
-        );
+        )?;
         let mut i = 0;
         while i < payload.len() {
             let index_of_next_equal_sign = {
@@ -149,23 +257,16 @@ where
                 }
                 i_eq.unwrap()
             };
-            let field_value_len = if let Some(len) = self.builder.state.data_field_length {
-                self.builder.state.data_field_length = None;
-                len
-            } else {
-                let len = (&payload[index_of_next_equal_sign + 1..])
-                    .iter()
-                    .copied()
-                    .position(|byte| byte == separator);
-                if len.is_none() {
-                    break;
-                }
-                len.unwrap()
-            };
             let tag_num = {
                 let mut tag = 0u32;
                 for byte in (&payload[i..index_of_next_equal_sign]).iter().copied() {
-                    tag = tag * 10 + (byte as u32 - b'0' as u32);
+                    if !byte.is_ascii_digit() {
+                        return Err(DecodeError::Invalid);
+                    }
+                    tag = tag
+                        .checked_mul(10)
+                        .and_then(|tag| tag.checked_add((byte - b'0') as u32))
+                        .ok_or(DecodeError::Invalid)?;
                 }
                 if let Some(tag) = TagU32::new(tag) {
                     tag
@@ -173,12 +274,34 @@ where
                     break;
                 }
             };
+            let field_value_len = match self.builder.state.data_field.take() {
+                Some(data_field) if data_field.tag == tag_num => {
+                    // The data field must be followed by the separator.
+                    let end = (index_of_next_equal_sign + 1)
+                        .checked_add(data_field.len)
+                        .filter(|end| payload.get(*end) == Some(&separator))
+                        .ok_or(DecodeError::InvalidDataLength {
+                            tag: data_field.length_tag.get(),
+                        })?;
+                    end - index_of_next_equal_sign - 1
+                }
+                _ => {
+                    let len = (&payload[index_of_next_equal_sign + 1..])
+                        .iter()
+                        .copied()
+                        .position(|byte| byte == separator);
+                    if len.is_none() {
+                        break;
+                    }
+                    len.unwrap()
+                }
+            };
             self.store_field(
                 tag_num,
                 frame.payload(),
                 index_of_next_equal_sign + 1,
                 field_value_len,
-            );
+            )?;
             // Equal sign                ~~~
             // Separator                                       ~~~
             i = index_of_next_equal_sign + 1 + field_value_len + 1;
@@ -196,9 +319,34 @@ where
         raw_message: &'a [u8],
         field_value_start: usize,
         field_value_len: usize,
-    ) {
+    ) -> Result<(), DecodeError> {
         let config_assoc = self.config().should_decode_associative();
+        let duplicate_tag_policy = self.config().duplicate_tag_policy();
         let field_value = &raw_message[field_value_start..][..field_value_len];
+        let tag_kind = self
+            .current_appl_ver_id
+            .and_then(|i| self.appl_ver_ids[i].1.get(&tag.get()))
+            .or_else(|| self.tag_lookup.get(&tag.get()))
+            .copied();
+        match (tag_kind, self.config().unknown_tag_policy()) {
+            (Some(_), _) | (None, UnknownTagPolicy::Allow) => (),
+            (None, UnknownTagPolicy::Reject) => {
+                return Err(DecodeError::UnknownTag { tag: tag.get() });
+            }
+            (None, UnknownTagPolicy::Collect) => {
+                self.message_builder_mut()
+                    .unknown_fields
+                    .push((tag, field_value));
+                return Ok(());
+            }
+        }
+        if tag_kind.is_some() && self.config().verify_header_order() {
+            if !self.header_tags.contains(&tag.get()) {
+                self.builder.state.is_within_body = true;
+            } else if self.builder.state.is_within_body {
+                return Err(DecodeError::HeaderFieldAfterBody { tag: tag.get() });
+            }
+        }
         if self.builder.state.new_group.is_some() {
             // We are entering a new group, but we still don't know which tag
             // will be the first one in each entry.
@@ -210,28 +358,73 @@ where
                 group_info.current_entry_i += 1;
             }
         }
-        self.message_builder_mut()
-            .add_field(
-                tag,
-                &raw_message[field_value_start..][..field_value_len],
-                config_assoc,
-            )
-            .unwrap();
-        let fix_type = self.tag_lookup.get(&tag.get());
-        if fix_type == Some(&FixDatatype::NumInGroup) {
-            self.builder
-                .state
-                .add_group(tag, self.builder.field_locators.len() - 1, field_value);
-        } else if fix_type == Some(&FixDatatype::Length) {
-            // FIXME
-            let last_field_locator = self.builder.field_locators.last().unwrap();
-            let last_field = self.builder.fields.get(last_field_locator).unwrap();
-            let last_field_value = last_field.1;
-            let s = std::str::from_utf8(last_field_value).unwrap();
-            let data_field_length = str::parse(s).unwrap();
-            self.builder.state.data_field_length = Some(data_field_length);
+        let field_i = self.message_builder_mut().add_field(
+            tag,
+            &raw_message[field_value_start..][..field_value_len],
+            config_assoc,
+            duplicate_tag_policy,
+        )?;
+        if tag.get() == APPL_VER_ID && !self.appl_ver_ids.is_empty() {
+            self.current_appl_ver_id = self.appl_ver_id_index(field_value);
+        }
+        match (tag_kind, field_i) {
+            (Some(TagKind::NumInGroup), Some(field_i)) => {
+                self.builder.state.add_group(tag, field_i, field_value)?;
+            }
+            // Discarded data fields must be skipped all the same.
+            (Some(TagKind::Length(data_tag)), _) => {
+                let len = usize::deserialize(field_value)
+                    .map_err(|_| DecodeError::InvalidDataLength { tag: tag.get() })?;
+                self.builder.state.data_field = Some(DecoderStateDataField {
+                    length_tag: tag,
+                    tag: data_tag,
+                    len,
+                });
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+fn tag_lookup(dict: &Dictionary) -> TagLookup {
+    dict.iter_fields()
+        .map(|field| {
+            let tag_kind = if field.is_num_in_group() {
+                TagKind::NumInGroup
+            } else if field.fix_datatype() == FixDatatype::Length {
+                field
+                    .associated_data_tag()
+                    .map_or(TagKind::Regular, TagKind::Length)
+            } else {
+                TagKind::Regular
+            };
+            (field.tag().get(), tag_kind)
+        })
+        .collect()
+}
+
+fn header_tags(dict: &Dictionary) -> IntSet<u32> {
+    fn add_items<'a>(tags: &mut IntSet<u32>, items: impl Iterator<Item = LayoutItem<'a>>) {
+        for item in items {
+            match item.kind() {
+                LayoutItemKind::Field(field) => {
+                    tags.insert(field.tag().get());
+                }
+                LayoutItemKind::Component(component) => add_items(tags, component.items()),
+                LayoutItemKind::Group(len_field, items) => {
+                    tags.insert(len_field.tag().get());
+                    add_items(tags, items.into_iter());
+                }
+            }
         }
     }
+
+    let mut tags = IntSet::default();
+    if let Some(header) = dict.component_by_name("StandardHeader") {
+        add_items(&mut tags, header.items());
+    }
+    tags
 }
 
 impl<C> GetConfig for Decoder<C> {
@@ -301,6 +494,17 @@ where
     B: Buffer,
     C: Configure,
 {
+    /// Returns an immutable reference to the underlying [`Decoder`].
+    pub fn decoder(&self) -> &Decoder<C> {
+        &self.decoder
+    }
+
+    /// Returns a mutable reference to the underlying [`Decoder`], e.g. to
+    /// change its [`Decoder::set_default_appl_ver_id`].
+    pub fn decoder_mut(&mut self) -> &mut Decoder<C> {
+        &mut self.decoder
+    }
+
     /// # Panics
     ///
     /// Panics if [`DecoderStreaming::try_parse()`] didn't return [`Ok(Some(()))`].
@@ -314,6 +518,16 @@ where
             field_locator_context: FieldLocatorContext::TopLevel,
         }
     }
+
+    /// Copies the current message into a new [`OwnedMessage`], which outlives
+    /// the next call to [`DecoderStreaming::clear`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`DecoderStreaming::try_parse()`] didn't return [`Ok(Some(()))`].
+    pub fn owned_message(&self) -> OwnedMessage {
+        self.message().to_owned_message()
+    }
 }
 
 impl<B, C> GetConfig for DecoderStreaming<B, C> {
@@ -439,6 +653,120 @@ impl<'a, T> Message<'a, T> {
     pub fn len(&self) -> usize {
         self.builder.field_locators.len()
     }
+
+    /// Returns an [`Iterator`] over the fields of the whole message that are
+    /// not defined by the [`Dictionary`] of the [`Decoder`], in sequential
+    /// order. These are only set apart from the other fields with
+    /// [`UnknownTagPolicy::Collect`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder, UnknownTagPolicy};
+    /// use fefix::prelude::*;
+    ///
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+    /// decoder.config_mut().set_separator(b'|');
+    /// decoder.config_mut().set_unknown_tag_policy(UnknownTagPolicy::Collect);
+    ///
+    /// let message = decoder.decode(b"8=FIX.4.4|9=17|35=0|9999=X|34=1|10=000|").unwrap();
+    /// assert_eq!(message.fv_raw(9999), None);
+    /// let unknown_fields: Vec<_> = message.unknown_fields().collect();
+    /// assert_eq!(unknown_fields, vec![(TagU32::new(9999).unwrap(), &b"X"[..])]);
+    /// ```
+    pub fn unknown_fields(&self) -> impl Iterator<Item = (TagU32, &[u8])> + '_ {
+        self.builder.unknown_fields.iter().copied()
+    }
+}
+
+impl<'a, T> Message<'a, T> {
+    /// Copies the contents of `self` into a new [`OwnedMessage`], which
+    /// outlives the [`Decoder`] or [`DecoderStreaming`] that produced `self`.
+    /// Use [`Decoder::decode_owned`] to avoid the copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::definitions::fix44;
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::prelude::*;
+    ///
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+    /// decoder.config_mut().set_separator(b'|');
+    /// let owned = decoder
+    ///     .decode(b"8=FIX.4.4|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=185|")
+    ///     .unwrap()
+    ///     .to_owned_message();
+    /// decoder.decode(b"8=FIX.4.4|9=5|35=0|10=000|").ok();
+    /// assert_eq!(owned.fv(fix44::MSG_SEQ_NUM), Ok(12));
+    /// ```
+    pub fn to_owned_message(&self) -> OwnedMessage {
+        let (fields, unknown_fields) = self.owned_fields();
+        OwnedMessage::new(self.builder.bytes.to_vec(), fields, unknown_fields)
+    }
+
+    /// Returns all fields of the whole message in sequential order, with
+    /// their index. Each field comes with its parent group entry, if any,
+    /// identified by the index of the `NumInGroup` field and by the index of
+    /// the entry. The parent group entry of `self` is returned too.
+    pub(crate) fn fields_with_parents(&self) -> (Option<(usize, usize)>, Vec<FieldWithParent<'_>>) {
+        let parent = |context| match context {
+            FieldLocatorContext::TopLevel => None,
+            FieldLocatorContext::WithinGroup {
+                index_of_group_tag,
+                entry_index,
+            } => Some((index_of_group_tag as usize, entry_index as usize)),
+        };
+        let fields = self
+            .builder
+            .field_locators
+            .iter()
+            .enumerate()
+            .filter_map(|(i, locator)| {
+                let (tag, value, j) = self.builder.fields.get(locator)?;
+                // Only the last occurrence of duplicate fields is available.
+                if i == *j {
+                    Some((i, *tag, *value, parent(locator.context)))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        (parent(self.field_locator_context), fields)
+    }
+
+    fn owned_fields(&self) -> (Vec<OwnedField>, Vec<OwnedField>) {
+        let start_of_message = self.builder.bytes.as_ptr() as usize;
+        let owned_field = |locator, value: &[u8]| {
+            // Field values always point into the message itself.
+            let start = value.as_ptr() as usize - start_of_message;
+            OwnedField {
+                locator,
+                start: start as u32,
+                end: (start + value.len()) as u32,
+            }
+        };
+        let fields = self
+            .builder
+            .field_locators
+            .iter()
+            .zip(self.builder.field_values.iter())
+            .map(|(locator, value)| owned_field(*locator, value))
+            .collect();
+        let unknown_fields = self
+            .builder
+            .unknown_fields
+            .iter()
+            .map(|(tag, value)| {
+                let locator = FieldLocator {
+                    tag: *tag,
+                    context: FieldLocatorContext::TopLevel,
+                };
+                owned_field(locator, value)
+            })
+            .collect();
+        (fields, unknown_fields)
+    }
 }
 
 impl<'a, T> PartialEq for Message<'a, T> {
@@ -452,6 +780,224 @@ impl<'a, T> PartialEq for Message<'a, T> {
 
 impl<'a, T> Eq for Message<'a, T> {}
 
+/// A field returned by [`Message::fields_with_parents`].
+pub(crate) type FieldWithParent<'a> = (usize, TagU32, &'a [u8], Option<(usize, usize)>);
+
+/// A FIX message that owns its contents, unlike [`Message`], which borrows
+/// them from its [`Decoder`]. [`OwnedMessage`] is cheap to clone, and it's
+/// [`Send`] and `'static` as long as `T` is [`Send`], [`Sync`] and `'static`,
+/// so it can be moved across threads and tasks.
+///
+/// [`OwnedMessage`]s are returned by [`Decoder::decode_owned`],
+/// [`Message::to_owned_message`] and [`DecoderStreaming::owned_message`].
+/// Group entries share the contents of the whole message.
+#[derive(Debug)]
+pub struct OwnedMessage<T = Vec<u8>> {
+    inner: Arc<OwnedMessageData<T>>,
+    field_locator_context: FieldLocatorContext,
+}
+
+#[derive(Debug)]
+struct OwnedMessageData<T> {
+    bytes: T,
+    // In sequential order.
+    fields: Vec<OwnedField>,
+    indices: HashMap<FieldLocator, usize>,
+    unknown_fields: Vec<OwnedField>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct OwnedField {
+    locator: FieldLocator,
+    start: u32,
+    end: u32,
+}
+
+impl<T> OwnedMessage<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn new(bytes: T, fields: Vec<OwnedField>, unknown_fields: Vec<OwnedField>) -> Self {
+        let indices = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field.locator, i))
+            .collect();
+        Self {
+            inner: Arc::new(OwnedMessageData {
+                bytes,
+                fields,
+                indices,
+                unknown_fields,
+            }),
+            field_locator_context: FieldLocatorContext::TopLevel,
+        }
+    }
+
+    /// Returns an [`Iterator`] over all fields in `self`, in sequential order
+    /// starting from the very first field.
+    pub fn fields(&self) -> impl Iterator<Item = (TagU32, &[u8])> + '_ {
+        self.inner
+            .fields
+            .iter()
+            .map(move |field| (field.locator.tag, self.field_value(field)))
+    }
+
+    /// Returns the underlying byte contents of `self`.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inner.bytes.as_ref()
+    }
+
+    /// Like [`Message::unknown_fields`].
+    pub fn unknown_fields(&self) -> impl Iterator<Item = (TagU32, &[u8])> + '_ {
+        self.inner
+            .unknown_fields
+            .iter()
+            .map(move |field| (field.locator.tag, self.field_value(field)))
+    }
+
+    /// Returns the number of FIX tags contained in `self`.
+    pub fn len(&self) -> usize {
+        self.inner.fields.len()
+    }
+
+    /// Returns `true` if `self` has no fields, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.inner.fields.is_empty()
+    }
+
+    fn field(&self, tag: u32) -> Option<(usize, &OwnedField)> {
+        let locator = FieldLocator {
+            tag: TagU32::new(tag)?,
+            context: self.field_locator_context,
+        };
+        let i = *self.inner.indices.get(&locator)?;
+        Some((i, &self.inner.fields[i]))
+    }
+
+    fn field_value(&self, field: &OwnedField) -> &[u8] {
+        &self.as_bytes()[field.start as usize..field.end as usize]
+    }
+}
+
+impl<T> Clone for OwnedMessage<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            field_locator_context: self.field_locator_context,
+        }
+    }
+}
+
+impl<T> PartialEq for OwnedMessage<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.fields().eq(other.fields())
+    }
+}
+
+impl<T> Eq for OwnedMessage<T> where T: AsRef<[u8]> {}
+
+impl<T> FieldMap<u32> for OwnedMessage<T>
+where
+    T: AsRef<[u8]>,
+{
+    type Group = OwnedMessageGroup<T>;
+
+    fn group(
+        &self,
+        tag: u32,
+    ) -> Result<Self::Group, FieldValueError<<usize as FieldType<'_>>::Error>> {
+        let (index_of_group_tag, field) = self.field(tag).ok_or(FieldValueError::Missing)?;
+        let len = usize::deserialize(self.field_value(field)).map_err(FieldValueError::Invalid)?;
+        Ok(OwnedMessageGroup {
+            message: self.clone(),
+            index_of_group_tag: index_of_group_tag as u32,
+            len,
+        })
+    }
+
+    fn fv_raw(&self, tag: u32) -> Option<&[u8]> {
+        self.field(tag).map(|(_, field)| self.field_value(field))
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        top_level_locator(MESSAGE_ENCODING)
+            .and_then(|locator| self.inner.indices.get(&locator))
+            .map_or(Ok(MessageEncoding::default()), |i| {
+                MessageEncoding::deserialize(self.field_value(&self.inner.fields[*i]))
+            })
+    }
+}
+
+impl<F, T> FieldMap<&F> for OwnedMessage<T>
+where
+    F: IsFieldDefinition,
+    T: AsRef<[u8]>,
+{
+    type Group = OwnedMessageGroup<T>;
+
+    fn group(
+        &self,
+        field: &F,
+    ) -> Result<Self::Group, FieldValueError<<usize as FieldType<'_>>::Error>> {
+        self.group(field.tag().get())
+    }
+
+    fn fv_raw(&self, field: &F) -> Option<&[u8]> {
+        self.fv_raw(field.tag().get())
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <Self as FieldMap<u32>>::message_encoding(self)
+    }
+}
+
+/// A repeating group within an [`OwnedMessage`].
+#[derive(Debug)]
+pub struct OwnedMessageGroup<T = Vec<u8>> {
+    message: OwnedMessage<T>,
+    index_of_group_tag: u32,
+    len: usize,
+}
+
+impl<T> Clone for OwnedMessageGroup<T> {
+    fn clone(&self) -> Self {
+        Self {
+            message: self.message.clone(),
+            index_of_group_tag: self.index_of_group_tag,
+            len: self.len,
+        }
+    }
+}
+
+impl<T> RepeatingGroup for OwnedMessageGroup<T>
+where
+    T: AsRef<[u8]>,
+{
+    type Entry = OwnedMessage<T>;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, i: usize) -> Option<Self::Entry> {
+        if i < self.len {
+            Some(OwnedMessage {
+                inner: self.message.inner.clone(),
+                field_locator_context: FieldLocatorContext::WithinGroup {
+                    index_of_group_tag: self.index_of_group_tag,
+                    entry_index: i as u32,
+                },
+            })
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 struct DecoderGroupState {
     first_tag_of_every_group_entry: TagU32,
@@ -467,11 +1013,21 @@ struct DecoderStateNewGroup {
     num_entries: usize,
 }
 
+#[derive(Debug, Copy, Clone)]
+struct DecoderStateDataField {
+    length_tag: TagU32,
+    tag: TagU32,
+    len: usize,
+}
+
 #[derive(Debug, Clone)]
 struct DecoderState {
     group_information: Vec<DecoderGroupState>,
     new_group: Option<DecoderStateNewGroup>,
-    data_field_length: Option<usize>,
+    data_field: Option<DecoderStateDataField>,
+    // Whether a message body field was found; see
+    // `Configure::verify_header_order`.
+    is_within_body: bool,
 }
 
 impl DecoderState {
@@ -499,9 +1055,13 @@ impl DecoderState {
         });
     }
 
-    fn add_group(&mut self, tag: TagU32, index_of_group_tag: usize, field_value: &[u8]) {
-        let field_value_str = std::str::from_utf8(field_value).unwrap();
-        let num_entries = str::parse(field_value_str).unwrap();
+    fn add_group(
+        &mut self,
+        tag: TagU32,
+        index_of_group_tag: usize,
+        field_value: &[u8],
+    ) -> Result<(), DecodeError> {
+        let num_entries = usize::deserialize(field_value).map_err(|_| DecodeError::Invalid)?;
         if num_entries > 0 {
             self.new_group = Some(DecoderStateNewGroup {
                 tag,
@@ -509,6 +1069,7 @@ impl DecoderState {
                 num_entries,
             });
         }
+        Ok(())
     }
 }
 
@@ -518,7 +1079,11 @@ struct MessageBuilder<'a> {
     state: DecoderState,
     raw: &'a [u8],
     fields: HashMap<FieldLocator, (TagU32, &'a [u8], usize)>,
+    // In sequential order, together with `field_values`.
     field_locators: Vec<FieldLocator>,
+    field_values: Vec<&'a [u8]>,
+    // See `UnknownTagPolicy::Collect`.
+    unknown_fields: Vec<(TagU32, &'a [u8])>,
     i_first_cell: usize,
     i_last_cell: usize,
     len_end_header: usize,
@@ -533,10 +1098,13 @@ impl<'a> Default for MessageBuilder<'a> {
             state: DecoderState {
                 group_information: Vec::new(),
                 new_group: None,
-                data_field_length: None,
+                data_field: None,
+                is_within_body: false,
             },
             raw: b"",
             field_locators: Vec::new(),
+            field_values: Vec::new(),
+            unknown_fields: Vec::new(),
             fields: HashMap::new(),
             i_first_cell: 0,
             i_last_cell: 0,
@@ -553,19 +1121,36 @@ impl<'a> MessageBuilder<'a> {
         *self = Self::default();
     }
 
+    /// Adds a field according to `duplicate_tag_policy` and returns its
+    /// index, unless it was discarded.
     fn add_field(
         &mut self,
         tag: TagU32,
         field_value: &'a [u8],
         associative: bool,
-    ) -> Result<(), DecodeError> {
+        duplicate_tag_policy: DuplicateTagPolicy,
+    ) -> Result<Option<usize>, DecodeError> {
         let field_locator = self.state.current_field_locator(tag);
         let i = self.field_locators.len();
         if associative {
-            self.fields.insert(field_locator, (tag, field_value, i));
+            match (self.fields.get_mut(&field_locator), duplicate_tag_policy) {
+                (None, _) | (Some(_), DuplicateTagPolicy::KeepAll) => {
+                    self.fields.insert(field_locator, (tag, field_value, i));
+                }
+                (Some(_), DuplicateTagPolicy::Error) => {
+                    return Err(DecodeError::DuplicateTag { tag: tag.get() });
+                }
+                (Some(_), DuplicateTagPolicy::FirstWins) => return Ok(None),
+                (Some(field), DuplicateTagPolicy::LastWins) => {
+                    field.1 = field_value;
+                    self.field_values[field.2] = field_value;
+                    return Ok(Some(field.2));
+                }
+            }
         }
         self.field_locators.push(field_locator);
-        Ok(())
+        self.field_values.push(field_value);
+        Ok(Some(i))
     }
 }
 
@@ -591,10 +1176,10 @@ impl<'a, T> Iterator for Fields<'a, T> {
         if self.i == self.message.len() {
             None
         } else {
-            let context = self.message.builder.field_locators[self.i];
-            let field = self.message.builder.fields.get(&context).unwrap();
+            let tag = self.message.builder.field_locators[self.i].tag;
+            let value = self.message.builder.field_values[self.i];
             self.i += 1;
-            Some((field.0, field.1))
+            Some((tag, value))
         }
     }
 }
@@ -638,6 +1223,16 @@ where
         dbglog!("looking for {:?}", field_locator);
         self.builder.fields.get(&field_locator).map(|field| field.1)
     }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        // `MessageEncoding <347>` is part of `StandardHeader`, so it's never
+        // within a group.
+        top_level_locator(MESSAGE_ENCODING)
+            .and_then(|locator| self.builder.fields.get(&locator))
+            .map_or(Ok(MessageEncoding::default()), |field| {
+                MessageEncoding::deserialize(field.1)
+            })
+    }
 }
 
 impl<'a, F, T> FieldMap<&F> for Message<'a, T>
@@ -657,6 +1252,10 @@ where
     fn fv_raw(&self, field: &F) -> Option<&[u8]> {
         self.fv_raw(u32::from(field.tag().get()))
     }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <Self as FieldMap<u32>>::message_encoding(self)
+    }
 }
 
 #[cfg(feature = "utils-slog")]
@@ -704,7 +1303,10 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::tagvalue::Config;
+    use crate::definitions::fix44;
+    use crate::tagvalue::{Config, Encoder};
+    use crate::SetField;
+    use quickcheck_macros::quickcheck;
 
     // Use http://www.validfix.com/fix-analyzer.html for testing.
 
@@ -754,6 +1356,40 @@ mod test {
         assert_eq!(group.get(0).unwrap().fv_raw(278).unwrap(), b"BID" as &[u8]);
     }
 
+    #[cfg(all(feature = "fixt11", feature = "fix50sp2"))]
+    #[test]
+    fn application_messages_are_decoded_by_appl_ver_id() {
+        fn second_party_id<T>(message: Message<T>) -> Option<Vec<u8>>
+        where
+            T: AsRef<[u8]> + Clone,
+        {
+            let parties = message.group(453).ok()?;
+            parties.get(1)?.fv_raw(448).map(|id| id.to_vec())
+        }
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fixt11());
+        decoder.config_mut().set_separator(b'|');
+        decoder.add_appl_ver_id(b"9", Dictionary::fix50sp2());
+        let bytes = b"8=FIXT.1.1|9=35|35=8|453=2|448=A|447=D|448=B|447=D|10=000|";
+        // `NoPartyIDs <453>` is unknown to FIXT.1.1.
+        assert_eq!(second_party_id(decoder.decode(bytes).unwrap()), None);
+        decoder.set_default_appl_ver_id(Some(b"9"));
+        assert_eq!(decoder.default_appl_ver_id(), Some(b"9" as &[u8]));
+        assert_eq!(
+            second_party_id(decoder.decode(bytes).unwrap()),
+            Some(b"B".to_vec())
+        );
+        // `ApplVerID <1128>` takes precedence over the default one.
+        decoder.set_default_appl_ver_id(None);
+        let bytes = b"8=FIXT.1.1|9=42|35=8|1128=9|453=2|448=A|447=D|448=B|447=D|10=000|";
+        assert_eq!(
+            second_party_id(decoder.decode(bytes).unwrap()),
+            Some(b"B".to_vec())
+        );
+        let bytes = b"8=FIXT.1.1|9=42|35=8|1128=8|453=2|448=A|447=D|448=B|447=D|10=000|";
+        assert_eq!(second_party_id(decoder.decode(bytes).unwrap()), None);
+    }
+
     #[test]
     fn top_level_tag_after_empty_group() {
         let bytes = b"8=FIX.4.4|9=17|35=X|268=0|346=1|10=171|";
@@ -863,4 +1499,274 @@ mod test {
             codec.clear();
         }
     }
+
+    #[quickcheck]
+    fn decoding_arbitrary_fields_never_panics(fields: Vec<u8>) -> bool {
+        let mut msg = format!("8=FIX.4.4|9={}|", fields.len()).into_bytes();
+        msg.extend_from_slice(&fields);
+        msg.extend_from_slice(b"10=000|");
+        let mut decoder = decoder();
+        decoder.config_mut().set_verify_checksum(false);
+        decoder.decode(&msg[..]).ok();
+        decoder.decode(&fields[..]).ok();
+        true
+    }
+
+    #[test]
+    fn garbled_fields_are_invalid() {
+        let mut decoder = decoder();
+        decoder.config_mut().set_verify_checksum(false);
+        for msg in [
+            "8=FIX.4.4|9=6|!5=0||10=000|",
+            "8=FIX.4.4|9=13|35=0|268=foo|10=000|",
+        ] {
+            assert!(matches!(
+                decoder.decode(msg.as_bytes()),
+                Err(DecodeError::Invalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn malformed_data_lengths_are_invalid() {
+        let mut decoder = decoder();
+        decoder.config_mut().set_verify_checksum(false);
+        for msg in [
+            "8=FIX.4.4|9=15|35=B|95=99|96=|10=000|",
+            "8=FIX.4.4|9=17|35=B|95=1|96=abc|10=000|",
+            "8=FIX.4.4|9=17|35=B|95=x|96=abc|10=000|",
+            "8=FIX.4.4|9=36|35=B|95=99999999999999999999999|96=|10=000|",
+        ] {
+            assert!(matches!(
+                decoder.decode(msg.as_bytes()),
+                Err(DecodeError::InvalidDataLength { tag: 95 })
+            ));
+        }
+    }
+
+    #[test]
+    fn data_fields_may_contain_the_separator() {
+        let mut encoder = Encoder::<Config>::default();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"A");
+        msg.set(fix44::ENCRYPT_METHOD, 0u32);
+        msg.set(fix44::RAW_DATA_LENGTH, 6u32);
+        msg.set(fix44::RAW_DATA, b"\x01=\x0110=" as &[u8]);
+        msg.set(fix44::HEART_BT_INT, 30u32);
+        let (bytes, _) = msg.done();
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        let msg = decoder.decode(bytes).unwrap();
+        assert_eq!(msg.fv_raw(fix44::RAW_DATA), Some(&b"\x01=\x0110="[..]));
+        assert_eq!(msg.fv(fix44::HEART_BT_INT), Ok(30));
+        assert_eq!(msg.len(), 6);
+    }
+
+    #[test]
+    fn length_fields_without_data_fields() {
+        let mut decoder = decoder();
+        let msg = decoder
+            .decode(b"8=FIX.4.4|9=29|35=A|98=0|108=30|383=2|789=1|10=000|")
+            .unwrap();
+        assert_eq!(msg.fv(fix44::MAX_MESSAGE_SIZE), Ok(2));
+        assert_eq!(msg.fv(fix44::NEXT_EXPECTED_MSG_SEQ_NUM), Ok(1));
+    }
+
+    fn decoder_with(f: impl FnOnce(&mut Config)) -> Decoder {
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        f(decoder.config_mut());
+        decoder
+    }
+
+    #[test]
+    fn duplicate_tag_policies() {
+        const DUPLICATE: &[u8] = b"8=FIX.4.4|9=20|35=0|49=A|34=1|49=B|10=000|";
+        const DUPLICATE_IN_GROUP: &[u8] =
+            b"8=FIX.4.4|9=35|35=8|453=2|448=A|447=D|447=E|448=B|10=000|";
+
+        let mut decoder =
+            decoder_with(|c| c.set_duplicate_tag_policy(DuplicateTagPolicy::LastWins));
+        let msg = decoder.decode(DUPLICATE).unwrap();
+        assert_eq!(msg.fv_raw(fix44::SENDER_COMP_ID), Some(&b"B"[..]));
+        assert_eq!(msg.len(), 4);
+        assert_eq!(
+            msg.fields().nth(2),
+            Some((TagU32::new(49).unwrap(), &b"B"[..]))
+        );
+
+        let mut decoder = decoder_with(|c| c.set_duplicate_tag_policy(DuplicateTagPolicy::Error));
+        assert!(matches!(
+            decoder.decode(DUPLICATE),
+            Err(DecodeError::DuplicateTag { tag: 49 })
+        ));
+        assert!(matches!(
+            decoder.decode(DUPLICATE_IN_GROUP),
+            Err(DecodeError::DuplicateTag { tag: 447 })
+        ));
+
+        let mut decoder =
+            decoder_with(|c| c.set_duplicate_tag_policy(DuplicateTagPolicy::FirstWins));
+        let msg = decoder.decode(DUPLICATE).unwrap();
+        assert_eq!(msg.fv_raw(fix44::SENDER_COMP_ID), Some(&b"A"[..]));
+        assert_eq!(msg.len(), 4);
+        let msg = decoder.decode(DUPLICATE_IN_GROUP).unwrap();
+        let party = msg.group(fix44::NO_PARTY_I_DS).unwrap().get(0).unwrap();
+        assert_eq!(party.fv_raw(fix44::PARTY_ID_SOURCE), Some(&b"D"[..]));
+
+        let mut decoder = decoder_with(|_| ());
+        let msg = decoder.decode(DUPLICATE).unwrap();
+        assert_eq!(msg.fv_raw(fix44::SENDER_COMP_ID), Some(&b"B"[..]));
+        let values: Vec<_> = msg
+            .fields()
+            .filter(|(tag, _)| tag.get() == 49)
+            .map(|(_, value)| value)
+            .collect();
+        assert_eq!(values, vec![&b"A"[..], &b"B"[..]]);
+        let msg = decoder.decode_owned(DUPLICATE).unwrap();
+        assert_eq!(msg.len(), 5);
+        assert_eq!(msg.fv_raw(fix44::SENDER_COMP_ID), Some(&b"B"[..]));
+    }
+
+    #[test]
+    fn unknown_tag_policies() {
+        const UNKNOWN: &[u8] = b"8=FIX.4.4|9=17|35=0|9999=X|34=1|10=000|";
+
+        let mut decoder = decoder_with(|_| ());
+        assert_eq!(
+            decoder.decode(UNKNOWN).unwrap().fv_raw(9999),
+            Some(&b"X"[..])
+        );
+
+        let mut decoder = decoder_with(|c| c.set_unknown_tag_policy(UnknownTagPolicy::Reject));
+        assert!(matches!(
+            decoder.decode(UNKNOWN),
+            Err(DecodeError::UnknownTag { tag: 9999 })
+        ));
+
+        let mut decoder = decoder_with(|c| c.set_unknown_tag_policy(UnknownTagPolicy::Collect));
+        let msg = decoder.decode(UNKNOWN).unwrap();
+        assert_eq!(msg.fv_raw(9999), None);
+        assert_eq!(msg.fv(fix44::MSG_SEQ_NUM), Ok(1));
+        assert!(msg.fields().all(|(tag, _)| tag.get() != 9999));
+        let msg = decoder.decode_owned(UNKNOWN).unwrap();
+        let unknown_fields: Vec<_> = msg.unknown_fields().collect();
+        assert_eq!(
+            unknown_fields,
+            vec![(TagU32::new(9999).unwrap(), &b"X"[..])]
+        );
+    }
+
+    #[test]
+    fn header_fields_after_the_body() {
+        const HEADER_AFTER_BODY: &[u8] = b"8=FIX.4.4|9=21|35=0|34=1|112=T|49=A|10=000|";
+        const UNKNOWN_IN_HEADER: &[u8] = b"8=FIX.4.4|9=22|35=0|49=A|9999=X|56=B|10=000|";
+
+        let mut decoder = decoder_with(|_| ());
+        assert!(decoder.decode(HEADER_AFTER_BODY).is_ok());
+
+        let mut decoder = decoder_with(|c| c.set_verify_header_order(true));
+        assert!(matches!(
+            decoder.decode(HEADER_AFTER_BODY),
+            Err(DecodeError::HeaderFieldAfterBody { tag: 49 })
+        ));
+        assert!(decoder.decode(UNKNOWN_IN_HEADER).is_ok());
+    }
+
+    #[test]
+    fn typed_field_values() {
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        let bytes = b"8=FIX.4.4|9=51|35=8|54=1|39=X|453=2|448=A|447=D|452=1|448=B|447=D|10=000|";
+        let msg = decoder.decode(bytes).unwrap();
+        assert_eq!(msg.get(fix44::SIDE), Ok(fix44::Side::Buy));
+        assert!(matches!(
+            msg.get(fix44::ORD_STATUS),
+            Err(FieldValueError::Invalid(()))
+        ));
+        assert_eq!(msg.get(fix44::ORD_TYPE), Err(FieldValueError::Missing));
+        assert_eq!(msg.get_opt(fix44::ORD_TYPE), Ok(None));
+        let party = msg.group(fix44::NO_PARTY_I_DS).unwrap().get(0).unwrap();
+        assert_eq!(
+            party.get(fix44::PARTY_ROLE),
+            Ok(fix44::PartyRole::ExecutingFirm)
+        );
+
+        let msg = decoder.decode_owned(bytes.to_vec()).unwrap();
+        assert_eq!(msg.get_opt(fix44::SIDE), Ok(Some(fix44::Side::Buy)));
+    }
+
+    #[test]
+    fn text_fields_follow_the_message_encoding() {
+        let mut decoder = decoder();
+        let msg = decoder
+            .decode(b"8=FIX.4.4|9=25|35=B|148=x|354=3|355=\xe9t\xe9|10=000|")
+            .unwrap();
+        assert_eq!(msg.fv_text(fix44::ENCODED_TEXT).unwrap(), "\u{e9}t\u{e9}");
+
+        let data =
+            b"8=FIX.4.4|9=48|35=B|347=EUC-JP|148=x|354=2|355=\xa4\xa2|453=1|448=\xa4\xa4|10=000|";
+        let msg = decoder.decode(&data[..]).unwrap();
+        assert_eq!(msg.fv_text(fix44::ENCODED_TEXT).unwrap(), "\u{3042}");
+        assert_eq!(msg.fv_text_opt(fix44::ENCODED_HEADLINE), Ok(None));
+        let party = msg.group(fix44::NO_PARTY_I_DS).unwrap().get(0).unwrap();
+        assert_eq!(party.fv_text(fix44::PARTY_ID).unwrap(), "\u{3044}");
+        let msg = decoder.decode_owned(&data[..]).unwrap();
+        let party = msg.group(fix44::NO_PARTY_I_DS).unwrap().get(0).unwrap();
+        assert_eq!(party.fv_text(fix44::PARTY_ID).unwrap(), "\u{3044}");
+
+        let msg = decoder
+            .decode(b"8=FIX.4.4|9=40|35=B|347=ISO-2022-JP|148=x|354=2|355=\xa4\xa2|10=000|")
+            .unwrap();
+        assert!(matches!(
+            msg.fv_text(fix44::ENCODED_TEXT),
+            Err(FieldValueError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn owned_messages_outlive_the_decoder() {
+        fn assert_send_static<T: Send + 'static>(_: &T) {}
+
+        let mut decoder = decoder();
+        let msg = decoder
+            .decode_owned(RANDOM_MESSAGES[6].as_bytes().to_vec())
+            .unwrap();
+        let copy = decoder
+            .decode(RANDOM_MESSAGES[6].as_bytes())
+            .unwrap()
+            .to_owned_message();
+        decoder.decode(RANDOM_MESSAGES[0].as_bytes()).unwrap();
+        drop(decoder);
+        assert_send_static(&msg);
+        assert_eq!(msg, copy);
+        assert_eq!(msg.as_bytes(), RANDOM_MESSAGES[6].as_bytes());
+        assert_eq!(msg.len(), copy.fields().count());
+        assert_eq!(msg.fv_raw(262), Some(&b"A"[..]));
+        let group = msg.group(268).unwrap();
+        assert_eq!(group.len(), 2);
+        assert_eq!(group.get(1).unwrap().fv_raw(278), Some(&b"OFFER"[..]));
+        assert_eq!(group.get(1).unwrap().fv_raw(262), None);
+    }
+
+    #[test]
+    fn owned_messages_from_decoder_streaming() {
+        let mut codec = decoder().streaming(vec![]);
+        let stream = RANDOM_MESSAGES[1].as_bytes();
+        let mut i = 0;
+        let msg = loop {
+            let buf = codec.fillable();
+            buf.clone_from_slice(&stream[i..i + buf.len()]);
+            i += buf.len();
+            if codec.try_parse().unwrap().is_some() {
+                break codec.owned_message();
+            }
+        };
+        codec.clear();
+        assert_eq!(msg.fv_raw(55), Some(&b"SPMI.MI"[..]));
+        assert_eq!(
+            msg.fields().next(),
+            Some((TagU32::new(8).unwrap(), &b"FIX.4.2"[..]))
+        );
+    }
 }
@@ -1,10 +1,13 @@
-use super::{Config, Configure};
+use super::field_order::reorder;
+use super::{Config, Configure, EncodeError};
 use crate::dict::IsFieldDefinition;
-use crate::field_types::CheckSum;
-use crate::{Buffer, BufferWriter, FieldType, GetConfig, SetField, TagU32};
+use crate::field_types::{CheckSum, MessageEncoding};
+use crate::{Buffer, BufferWriter, Dictionary, FieldType, GetConfig, SetField, TagU32};
 use std::fmt::Write;
 use std::ops::Range;
 
+const MESSAGE_ENCODING: u32 = 347;
+
 /// A buffered, content-agnostic FIX encoder.
 ///
 /// [`Encoder`] is the fundamental building block for building higher-level
@@ -26,6 +29,8 @@ use std::ops::Range;
 #[derive(Debug, Clone, Default)]
 pub struct Encoder<C = Config> {
     config: C,
+    dict: Option<Dictionary>,
+    canonical_order: bool,
 }
 
 impl<C> Encoder<C>
@@ -37,6 +42,56 @@ where
         Self::default()
     }
 
+    /// Returns the [`Dictionary`] that is used to check the structure of
+    /// repeating groups, if any.
+    pub fn dictionary(&self) -> Option<&Dictionary> {
+        self.dict.as_ref()
+    }
+
+    /// Attaches `dict` to `self`. [`EncoderGroup`]s will then check that every
+    /// group entry starts with the delimiter field reported by
+    /// [`dict::Message::group_info`](crate::dict::Message::group_info).
+    pub fn set_dictionary(&mut self, dict: Option<Dictionary>) {
+        self.dict = dict;
+    }
+
+    /// Returns `true` if fields are reordered according to the [`Dictionary`]
+    /// of `self` when messages are closed, `false` otherwise. Defaults to
+    /// `false`.
+    pub fn canonical_order(&self) -> bool {
+        self.canonical_order
+    }
+
+    /// Enables or disables canonical field ordering. When enabled and a
+    /// [`Dictionary`] is attached, [`EncoderHandle::try_done`] and
+    /// [`EncoderHandle::done`] move header fields right after `MsgType <35>`
+    /// and sort all fields (repeating groups included) as per
+    /// `StandardHeader`, the message layout and `StandardTrailer`, no matter
+    /// the order in which they were set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::definitions::fix44;
+    /// use fefix::tagvalue::{Config, Encoder};
+    /// use fefix::prelude::*;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut encoder = Encoder::<Config>::default();
+    /// encoder.config_mut().set_separator(b'|');
+    /// encoder.set_dictionary(Some(Dictionary::fix44()));
+    /// encoder.set_canonical_order(true);
+    /// let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"0");
+    /// msg.set(fix44::TEST_REQ_ID, "T");
+    /// msg.set(fix44::MSG_SEQ_NUM, 2u32);
+    /// msg.set(fix44::SENDER_COMP_ID, "A");
+    /// let (bytes, _) = msg.try_done().unwrap();
+    /// assert!(bytes.starts_with(b"8=FIX.4.4|9=00000021|35=0|49=A|34=2|112=T|10="));
+    /// ```
+    pub fn set_canonical_order(&mut self, canonical_order: bool) {
+        self.canonical_order = canonical_order;
+    }
+
     /// Creates a new [`EncoderHandle`] that allows to set the field values of a
     /// new FIX message. The raw byte contents of the newly created FIX messages
     /// are appended directly at the end of `buffer`.
@@ -55,6 +110,8 @@ where
             buffer,
             initial_buffer_len,
             body_start_i: 0,
+            msg_type: 0..0,
+            message_encoding: None,
         };
         state.set(8, begin_string);
         // The second field is supposed to be `BodyLength(9)`, but obviously
@@ -75,6 +132,7 @@ where
         state.set(9, b"00000000" as &[u8]);
         state.body_start_i = state.buffer.len();
         state.set(35, msg_type);
+        state.msg_type = state.body_start_i + 3..state.buffer.len() - 1;
         state
     }
 }
@@ -99,6 +157,9 @@ pub struct EncoderHandle<'a, B, C = Config> {
     buffer: &'a mut B,
     initial_buffer_len: usize,
     body_start_i: usize,
+    msg_type: Range<usize>,
+    // The value of `MessageEncoding <347>`, if set.
+    message_encoding: Option<Range<usize>>,
 }
 
 impl<'a, B, C> EncoderHandle<'a, B, C>
@@ -109,12 +170,101 @@ where
     /// Closes the current message writing operation and returns its byte
     /// representation, as well as its offset within the whole contents of the
     /// [`Buffer`].
+    ///
+    /// If [canonical ordering](Encoder::set_canonical_order) is enabled but
+    /// fields can't be reordered, they are left in the order in which they
+    /// were set. Use [`EncoderHandle::try_done`] to detect such errors.
     pub fn done(mut self) -> (&'a [u8], usize) {
+        let _ = self.reorder();
+        self.finish()
+    }
+
+    /// Like [`EncoderHandle::done`], but fails if
+    /// [canonical ordering](Encoder::set_canonical_order) is enabled and
+    /// `self` contains fields that are not allowed by the [`Dictionary`] of
+    /// the [`Encoder`], or if the message type is not defined by it.
+    pub fn try_done(mut self) -> Result<(&'a [u8], usize), EncodeError> {
+        self.reorder()?;
+        Ok(self.finish())
+    }
+
+    fn finish(mut self) -> (&'a [u8], usize) {
         self.write_body_length();
         self.write_checksum();
         (self.buffer.as_slice(), self.initial_buffer_len)
     }
 
+    fn reorder(&mut self) -> Result<(), EncodeError> {
+        let dict = match &self.encoder.dict {
+            Some(dict) if self.encoder.canonical_order => dict,
+            _ => return Ok(()),
+        };
+        // `MsgType <35>` always comes first; everything else is reordered.
+        let fields_start_i = self.msg_type.end + 1;
+        let slice = self.buffer.as_mut_slice();
+        let fields = reorder(
+            dict,
+            &slice[self.msg_type.clone()],
+            &slice[fields_start_i..],
+            self.encoder.config.separator(),
+        )?;
+        slice[fields_start_i..].copy_from_slice(&fields);
+        Ok(())
+    }
+
+    /// Starts a new repeating group with `NumInGroup` field
+    /// `num_in_group_field`. Entries are then added with
+    /// [`EncoderGroup::entry`], and the `NumInGroup` field is written with
+    /// the right count when the group is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::definitions::fix44;
+    /// use fefix::tagvalue::{Config, Decoder, Encoder};
+    /// use fefix::prelude::*;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut encoder = Encoder::<Config>::default();
+    /// encoder.config_mut().set_separator(b'|');
+    /// encoder.set_dictionary(Some(Dictionary::fix44()));
+    /// let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"W");
+    /// msg.set(fix44::SYMBOL, "EUR/USD");
+    /// let mut group = msg.start_group(fix44::NO_MD_ENTRIES);
+    /// group.entry().set(fix44::MD_ENTRY_TYPE, fix44::MdEntryType::Bid);
+    /// group.set(fix44::MD_ENTRY_PX, "1.1");
+    /// group.entry().set(fix44::MD_ENTRY_TYPE, fix44::MdEntryType::Offer);
+    /// group.set(fix44::MD_ENTRY_PX, "1.2");
+    /// group.done().unwrap();
+    /// let (bytes, _) = msg.done();
+    ///
+    /// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+    /// decoder.config_mut().set_separator(b'|');
+    /// let msg = decoder.decode(bytes).unwrap();
+    /// let group = msg.group(fix44::NO_MD_ENTRIES).unwrap();
+    /// assert_eq!(group.len(), 2);
+    /// assert_eq!(group.get(1).unwrap().fv(fix44::MD_ENTRY_PX), Ok("1.2"));
+    /// ```
+    pub fn start_group<F>(&mut self, num_in_group_field: &F) -> EncoderGroup<'_, 'a, B, C>
+    where
+        F: IsFieldDefinition,
+    {
+        EncoderGroup::new(self, num_in_group_field.tag())
+    }
+
+    fn group_delimiter(&self, num_in_group_tag: TagU32) -> Option<TagU32> {
+        let dict = self.encoder.dict.as_ref()?;
+        let msg_type = std::str::from_utf8(&self.buffer.as_slice()[self.msg_type.clone()]).ok()?;
+        dict.message_by_msgtype(msg_type)?
+            .group_info(num_in_group_tag)
+    }
+
+    /// Appends `fields` as-is. `fields` must contain valid FIX fields,
+    /// complete with separators.
+    pub(crate) fn write_raw(&mut self, fields: &[u8]) {
+        self.buffer.extend_from_slice(fields);
+    }
+
     fn body_length_writable_range(&self) -> Range<usize> {
         self.body_start_i - 9..self.body_start_i - 1
     }
@@ -133,7 +283,7 @@ where
     }
 
     fn write_checksum(&mut self) {
-        let checksum = CheckSum::compute(self.buffer.as_slice());
+        let checksum = CheckSum::compute(&self.buffer.as_slice()[self.initial_buffer_len..]);
         self.set(10, checksum);
     }
 }
@@ -148,10 +298,22 @@ where
         V: FieldType<'s>,
     {
         write!(BufferWriter(self.buffer), "{}=", tag).unwrap();
+        let value_start_i = self.buffer.len();
         value.serialize_with(self.buffer, settings);
+        if tag == MESSAGE_ENCODING {
+            self.message_encoding = Some(value_start_i..self.buffer.len());
+        }
         self.buffer
             .extend_from_slice(&[self.encoder.config().separator()]);
     }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        self.message_encoding
+            .clone()
+            .map_or(Ok(MessageEncoding::default()), |range| {
+                MessageEncoding::deserialize(&self.buffer.as_slice()[range])
+            })
+    }
 }
 
 impl<'a, B, C> SetField<TagU32> for EncoderHandle<'a, B, C>
@@ -165,6 +327,10 @@ where
     {
         self.set_with(tag.get() as u32, value, settings)
     }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <Self as SetField<u32>>::message_encoding(self)
+    }
 }
 
 impl<'a, B, C, F> SetField<&F> for EncoderHandle<'a, B, C>
@@ -179,4 +345,582 @@ where
     {
         self.set_with(field.tag(), value, settings)
     }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <Self as SetField<u32>>::message_encoding(self)
+    }
+}
+
+/// A type returned by [`EncoderHandle::start_group`] to encode the entries of
+/// a FIX repeating group.
+///
+/// Fields are added to the current entry, which is started by
+/// [`EncoderGroup::entry`]. The `NumInGroup` field is only written (before
+/// all entries) when the group is closed with [`EncoderGroup::done`] or
+/// dropped, and it's omitted altogether if the group has no entries.
+#[derive(Debug)]
+pub struct EncoderGroup<'h, 'a, B, C = Config>
+where
+    B: Buffer,
+    C: Configure,
+{
+    handle: &'h mut EncoderHandle<'a, B, C>,
+    num_in_group_tag: TagU32,
+    delimiter: Option<TagU32>,
+    start_i: usize,
+    num_entries: usize,
+    first_tag_of_entry: Option<TagU32>,
+    error: Option<EncodeError>,
+    is_done: bool,
+}
+
+impl<'h, 'a, B, C> EncoderGroup<'h, 'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn new(handle: &'h mut EncoderHandle<'a, B, C>, num_in_group_tag: TagU32) -> Self {
+        let delimiter = handle.group_delimiter(num_in_group_tag);
+        let start_i = handle.buffer.len();
+        Self {
+            handle,
+            num_in_group_tag,
+            delimiter,
+            start_i,
+            num_entries: 0,
+            first_tag_of_entry: None,
+            error: None,
+            is_done: false,
+        }
+    }
+
+    /// Starts a new entry. All following fields and groups belong to it,
+    /// until the next call to [`EncoderGroup::entry`].
+    pub fn entry(&mut self) -> &mut Self {
+        self.finish_entry();
+        self.num_entries += 1;
+        self.first_tag_of_entry = None;
+        self
+    }
+
+    /// Returns the number of entries in `self`.
+    pub fn len(&self) -> usize {
+        self.num_entries
+    }
+
+    /// Returns `true` if `self` has no entries, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.num_entries == 0
+    }
+
+    /// Starts a new repeating group, nested within the current entry.
+    pub fn start_group<F>(&mut self, num_in_group_field: &F) -> EncoderGroup<'_, 'a, B, C>
+    where
+        F: IsFieldDefinition,
+    {
+        self.add_tag(num_in_group_field.tag());
+        EncoderGroup::new(self.handle, num_in_group_field.tag())
+    }
+
+    /// Closes `self` and writes its `NumInGroup` field. Fails if any entry
+    /// was empty, or didn't start with the delimiter field as reported by the
+    /// [`Dictionary`] of the [`Encoder`], if any.
+    pub fn done(mut self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+
+    fn add_tag(&mut self, tag: TagU32) {
+        if self.num_entries == 0 {
+            self.set_error(EncodeError::FieldOutsideOfGroupEntry {
+                num_in_group_tag: self.num_in_group_tag,
+                tag,
+            });
+        } else if self.first_tag_of_entry.is_none() {
+            self.first_tag_of_entry = Some(tag);
+            match self.delimiter {
+                Some(delimiter) if delimiter != tag => {
+                    self.set_error(EncodeError::InvalidGroupDelimiter {
+                        num_in_group_tag: self.num_in_group_tag,
+                        delimiter,
+                        tag,
+                    });
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn finish_entry(&mut self) {
+        if self.num_entries > 0 && self.first_tag_of_entry.is_none() {
+            self.set_error(EncodeError::EmptyGroupEntry {
+                num_in_group_tag: self.num_in_group_tag,
+            });
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), EncodeError> {
+        if self.is_done {
+            return Ok(());
+        }
+        self.is_done = true;
+        self.finish_entry();
+        if self.num_entries > 0 {
+            let separator = self.handle.encoder.config().separator();
+            let mut field = format!("{}={}", self.num_in_group_tag, self.num_entries).into_bytes();
+            field.push(separator);
+            // Make room for the `NumInGroup` field before all entries.
+            let buffer = &mut self.handle.buffer;
+            let end_i = buffer.len();
+            buffer.extend_from_slice(&field);
+            let slice = buffer.as_mut_slice();
+            slice.copy_within(self.start_i..end_i, self.start_i + field.len());
+            slice[self.start_i..][..field.len()].copy_from_slice(&field);
+        }
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn set_error(&mut self, err: EncodeError) {
+        if self.error.is_none() {
+            self.error = Some(err);
+        }
+    }
+}
+
+impl<'h, 'a, B, C> Drop for EncoderGroup<'h, 'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn drop(&mut self) {
+        // Errors can only be reported by `done`.
+        let _ = self.finish();
+    }
+}
+
+/// Implementors of [`SetField`] that can also start FIX repeating groups, i.e.
+/// [`EncoderHandle`] and [`EncoderGroup`]. Message builders generated by
+/// `codegen::gen_messages` (e.g. [`fix44::messages`](crate::definitions::fix44::messages))
+/// are generic over this trait, so that components can be encoded both at the
+/// top level and within group entries.
+pub trait StartGroup<'a, B, C>: SetField<u32>
+where
+    B: Buffer,
+    C: Configure,
+{
+    /// Starts a new repeating group with `NumInGroup` field
+    /// `num_in_group_field`.
+    fn start_group<F>(&mut self, num_in_group_field: &F) -> EncoderGroup<'_, 'a, B, C>
+    where
+        F: IsFieldDefinition;
+}
+
+impl<'a, B, C> StartGroup<'a, B, C> for EncoderHandle<'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn start_group<F>(&mut self, num_in_group_field: &F) -> EncoderGroup<'_, 'a, B, C>
+    where
+        F: IsFieldDefinition,
+    {
+        EncoderHandle::start_group(self, num_in_group_field)
+    }
+}
+
+impl<'h, 'a, B, C> StartGroup<'a, B, C> for EncoderGroup<'h, 'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn start_group<F>(&mut self, num_in_group_field: &F) -> EncoderGroup<'_, 'a, B, C>
+    where
+        F: IsFieldDefinition,
+    {
+        EncoderGroup::start_group(self, num_in_group_field)
+    }
+}
+
+impl<'h, 'a, B, C> SetField<u32> for EncoderGroup<'h, 'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn set_with<'s, V>(&'s mut self, tag: u32, value: V, settings: V::SerializeSettings)
+    where
+        V: FieldType<'s>,
+    {
+        if let Some(tag) = TagU32::new(tag) {
+            self.add_tag(tag);
+        }
+        self.handle.set_with(tag, value, settings)
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <EncoderHandle<'a, B, C> as SetField<u32>>::message_encoding(self.handle)
+    }
+}
+
+impl<'h, 'a, B, C> SetField<TagU32> for EncoderGroup<'h, 'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn set_with<'s, V>(&'s mut self, tag: TagU32, value: V, settings: V::SerializeSettings)
+    where
+        V: FieldType<'s>,
+    {
+        self.set_with(tag.get(), value, settings)
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <Self as SetField<u32>>::message_encoding(self)
+    }
+}
+
+impl<'h, 'a, B, C, F> SetField<&F> for EncoderGroup<'h, 'a, B, C>
+where
+    B: Buffer,
+    C: Configure,
+    F: IsFieldDefinition,
+{
+    fn set_with<'s, V>(&'s mut self, field: &F, value: V, settings: V::SerializeSettings)
+    where
+        V: FieldType<'s>,
+    {
+        self.set_with(field.tag(), value, settings)
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        <Self as SetField<u32>>::message_encoding(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::definitions::fix44;
+    use crate::tagvalue::Decoder;
+    use crate::{FieldMap, RepeatingGroup};
+
+    fn encoder() -> Encoder {
+        let mut encoder = Encoder::<Config>::default();
+        encoder.config_mut().set_separator(b'|');
+        encoder.set_dictionary(Some(Dictionary::fix44()));
+        encoder
+    }
+
+    #[test]
+    fn nested_groups_are_counted() {
+        let mut encoder = encoder();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        msg.set(fix44::CL_ORD_ID, "1");
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        parties.entry().set(fix44::PARTY_ID, "A");
+        let mut sub_ids = parties.start_group(fix44::NO_PARTY_SUB_I_DS);
+        sub_ids.entry().set(fix44::PARTY_SUB_ID, "A1");
+        sub_ids.entry().set(fix44::PARTY_SUB_ID, "A2");
+        sub_ids.done().unwrap();
+        parties.entry().set(fix44::PARTY_ID, "B");
+        parties.done().unwrap();
+        msg.set(fix44::SYMBOL, "FOO");
+        let (bytes, _) = msg.done();
+        assert!(bytes.starts_with(
+            b"8=FIX.4.4|9=00000055|35=D|11=1|453=2|448=A|802=2|523=A1|523=A2|448=B|55=FOO|10="
+        ));
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        let msg = decoder.decode(bytes).unwrap();
+        let parties = msg.group(fix44::NO_PARTY_I_DS).unwrap();
+        assert_eq!(parties.len(), 2);
+        let sub_ids = parties
+            .get(0)
+            .unwrap()
+            .group(fix44::NO_PARTY_SUB_I_DS)
+            .unwrap();
+        assert_eq!(sub_ids.get(1).unwrap().fv(fix44::PARTY_SUB_ID), Ok("A2"));
+    }
+
+    #[test]
+    fn empty_groups_are_omitted() {
+        let mut encoder = encoder();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        assert_eq!(msg.start_group(fix44::NO_PARTY_I_DS).done(), Ok(()));
+        // Dropped groups are closed too.
+        msg.start_group(fix44::NO_PARTY_I_DS)
+            .entry()
+            .set(fix44::PARTY_ID, "A");
+        let (bytes, _) = msg.done();
+        assert!(bytes.starts_with(b"8=FIX.4.4|9=00000017|35=D|453=1|448=A|10="));
+    }
+
+    #[test]
+    fn entries_must_start_with_the_delimiter() {
+        let mut encoder = encoder();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        parties.entry().set(fix44::PARTY_ID, "A");
+        parties.entry().set(fix44::PARTY_ROLE, 1u32);
+        assert_eq!(
+            parties.done(),
+            Err(EncodeError::InvalidGroupDelimiter {
+                num_in_group_tag: fix44::NO_PARTY_I_DS.tag(),
+                delimiter: fix44::PARTY_ID.tag(),
+                tag: fix44::PARTY_ROLE.tag(),
+            })
+        );
+
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        parties.set(fix44::PARTY_ID, "A");
+        assert!(matches!(
+            parties.done(),
+            Err(EncodeError::FieldOutsideOfGroupEntry { .. })
+        ));
+
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        parties.entry();
+        assert!(matches!(
+            parties.done(),
+            Err(EncodeError::EmptyGroupEntry { .. })
+        ));
+    }
+
+    #[test]
+    fn canonical_order_sorts_header_body_and_groups() {
+        let mut encoder = encoder();
+        encoder.set_canonical_order(true);
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        msg.set(fix44::SYMBOL, "FOO");
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        parties.entry().set(fix44::PARTY_ID, "A");
+        parties.set(fix44::PARTY_ROLE, 1u32);
+        parties.set(fix44::PARTY_ID_SOURCE, "D");
+        parties.entry().set(fix44::PARTY_ID, "B");
+        parties.done().unwrap();
+        msg.set(fix44::CL_ORD_ID, "1");
+        msg.set(fix44::SENDER_COMP_ID, "S");
+        let (bytes, _) = msg.try_done().unwrap();
+        assert!(bytes.starts_with(
+            b"8=FIX.4.4|9=00000052|35=D|49=S|11=1|453=2|448=A|447=D|452=1|448=B|55=FOO|10="
+        ));
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        let msg = decoder.decode(bytes).unwrap();
+        let parties = msg.group(fix44::NO_PARTY_I_DS).unwrap();
+        assert_eq!(parties.len(), 2);
+        assert_eq!(parties.get(0).unwrap().fv(fix44::PARTY_ROLE), Ok(1));
+    }
+
+    #[test]
+    fn canonical_order_keeps_data_fields_intact() {
+        let mut encoder = encoder();
+        encoder.set_canonical_order(true);
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"A");
+        msg.set(fix44::RAW_DATA_LENGTH, 5u32);
+        msg.set(fix44::RAW_DATA, b"1=2|3" as &[u8]);
+        msg.set(fix44::HEART_BT_INT, 30u32);
+        msg.set(fix44::ENCRYPT_METHOD, 0u32);
+        let (bytes, _) = msg.try_done().unwrap();
+        assert!(bytes.starts_with(b"8=FIX.4.4|9=00000031|35=A|98=0|108=30|95=5|96=1=2|3|10="));
+    }
+
+    #[test]
+    fn canonical_order_rejects_unknown_fields() {
+        let mut encoder = encoder();
+        encoder.set_canonical_order(true);
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        msg.set(fix44::TEST_REQ_ID, "T");
+        assert_eq!(
+            msg.try_done().map(|_| ()),
+            Err(EncodeError::FieldNotAllowed { tag: 112 })
+        );
+
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        msg.set(fix44::SYMBOL, "FOO");
+        msg.set(fix44::TEST_REQ_ID, "T");
+        msg.set(fix44::CL_ORD_ID, "1");
+        // `done` leaves fields as they are.
+        let (bytes, _) = msg.done();
+        assert!(bytes.starts_with(b"8=FIX.4.4|9=00000023|35=D|55=FOO|112=T|11=1|10="));
+
+        let mut buffer = Vec::new();
+        let msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"ZZ");
+        assert_eq!(msg.try_done().map(|_| ()), Err(EncodeError::InvalidMsgType));
+    }
+
+    #[test]
+    fn text_follows_the_message_encoding() {
+        let mut encoder = encoder();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"B");
+        assert_eq!(msg.set_text(fix44::HEADLINE, "Zürich"), Ok(()));
+        assert!(msg.set_text(fix44::HEADLINE, "東京").is_err());
+        msg.set(fix44::MESSAGE_ENCODING, MessageEncoding::ShiftJis);
+        assert_eq!(msg.set_text(fix44::TEXT, "東京"), Ok(()));
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        assert_eq!(parties.entry().set_text(fix44::PARTY_ID, "ｱ"), Ok(()));
+        parties.done().unwrap();
+        let (bytes, _) = msg.done();
+        assert!(bytes.starts_with(
+            b"8=FIX.4.4|9=00000050|35=B|148=Z\xfcrich|347=Shift_JIS|58=\x93\x8c\x8b\x9e|453=1|448=\xb1|10="
+        ));
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        let msg = decoder.decode(bytes).unwrap();
+        assert_eq!(msg.fv_text(fix44::TEXT).unwrap(), "東京");
+        let party = msg.group(fix44::NO_PARTY_I_DS).unwrap().get(0).unwrap();
+        assert_eq!(party.fv_text(fix44::PARTY_ID).unwrap(), "ｱ");
+    }
+
+    #[test]
+    fn typed_message_builders_and_readers() {
+        use crate::definitions::fix44::messages::*;
+        use crate::field_types::{Date, Time, Timestamp};
+
+        let transact_time = Timestamp::new(
+            Date::new(2021, 5, 10).unwrap(),
+            Time::from_hmsm(12, 30, 0, 0).unwrap(),
+        );
+        let mut encoder = encoder();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        let mut order = NewOrderSingleBuilder::new(&mut msg);
+        order.header().set_sender_comp_id(b"A");
+        order
+            .set_cl_ord_id(b"1")
+            .set_side(fix44::Side::Buy)
+            .set_transact_time(transact_time.clone())
+            .set_ord_type(fix44::OrdType::Limit)
+            .set_price(1.5);
+        order.instrument().set_symbol(b"FOO");
+        let mut parties = order.parties();
+        let mut party_ids = parties.start_no_party_i_ds();
+        party_ids
+            .entry()
+            .set_party_id(b"P1")
+            .set_party_role(fix44::PartyRole::ExecutingFirm);
+        party_ids.entry().set_party_id(b"P2");
+        party_ids.done().unwrap();
+        let (bytes, _) = msg.done();
+        assert!(bytes.starts_with(
+            b"8=FIX.4.4|9=00000090|35=D|49=A|11=1|54=1|60=20210510-12:30:00.000|40=2|44=1.5|55=FOO|453=2|448=P1|452=1|448=P2|10="
+        ));
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        let order = NewOrderSingleReader::new(decoder.decode(bytes).unwrap());
+        assert_eq!(order.header().sender_comp_id(), Ok(&b"A"[..]));
+        assert_eq!(order.cl_ord_id(), Ok(&b"1"[..]));
+        assert_eq!(order.side(), Ok(fix44::Side::Buy));
+        assert_eq!(order.transact_time(), Ok(transact_time));
+        assert_eq!(order.ord_type(), Ok(fix44::OrdType::Limit));
+        assert_eq!(order.price(), Ok(Some(1.5)));
+        assert_eq!(order.stop_px(), Ok(None));
+        assert_eq!(order.instrument().symbol(), Ok(Some(&b"FOO"[..])));
+        let party_ids = order
+            .parties()
+            .no_party_i_ds()
+            .unwrap()
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(party_ids.len(), 2);
+        assert_eq!(party_ids[0].party_id(), Ok(Some(&b"P1"[..])));
+        assert_eq!(
+            party_ids[0].party_role(),
+            Ok(Some(fix44::PartyRole::ExecutingFirm))
+        );
+        assert_eq!(party_ids[1].party_role(), Ok(None));
+        assert!(order.pre_alloc_grp().no_allocs().unwrap().is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "owned-messages", feature = "json-encoding"))]
+    fn owned_messages_round_trip() {
+        use crate::definitions::fix44::messages::{NewOrderSingle, Parties, PartiesNoPartyIDs};
+
+        let mut encoder = encoder();
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        msg.set(fix44::SENDER_COMP_ID, "A");
+        msg.set(fix44::TARGET_COMP_ID, "B");
+        msg.set(fix44::MSG_SEQ_NUM, 1u32);
+        msg.set(fix44::SENDING_TIME, "20210510-12:30:00.000");
+        msg.set(fix44::CL_ORD_ID, "1");
+        msg.set(fix44::SYMBOL, "FOO");
+        msg.set(fix44::SIDE, fix44::Side::Buy);
+        msg.set(fix44::TRANSACT_TIME, "20210510-12:30:00.000");
+        msg.set(fix44::ORD_TYPE, fix44::OrdType::Limit);
+        msg.set(fix44::PRICE, "1.5");
+        let (bytes, _) = msg.done();
+        let bytes = bytes.to_vec();
+
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        let mut order = NewOrderSingle::from_field_map(&decoder.decode(&bytes).unwrap()).unwrap();
+        assert_eq!(order.header.sender_comp_id, "A");
+        assert_eq!(order.cl_ord_id, "1");
+        assert_eq!(order.side, fix44::Side::Buy);
+        assert_eq!(order.price, Some(1.5));
+        assert_eq!(order.instrument.symbol.as_deref(), Some("FOO"));
+        assert!(order.parties.is_none());
+
+        let mut encoded = Vec::new();
+        let tree = order.to_message_tree().unwrap();
+        assert_eq!(
+            tree.encode(&mut encoder, &mut encoded).unwrap().0,
+            &bytes[..]
+        );
+
+        let party = |id: &str| PartiesNoPartyIDs {
+            party_id: Some(id.to_string()),
+            party_id_source: None,
+            party_role: None,
+            ptys_sub_grp: None,
+        };
+        order.parties = Some(Parties {
+            no_party_i_ds: vec![party("P1"), party("P2")],
+        });
+        let json = serde_json::to_string(&order).unwrap();
+        assert_eq!(
+            serde_json::from_str::<NewOrderSingle>(&json).unwrap(),
+            order
+        );
+
+        let tree = order.to_message_tree().unwrap();
+        let mut encoded = Vec::new();
+        let (encoded, _) = tree.encode(&mut encoder, &mut encoded).unwrap();
+        assert!(encoded.starts_with(b"8=FIX.4.4|9=00000119|35=D|49=A|56=B|34=1|52=20210510-12:30:00.000|11=1|453=2|448=P1|448=P2|55=FOO|"));
+
+        let mut json_encoder = crate::json::Encoder::new();
+        let json = json_encoder
+            .encode_tree(&tree, &Dictionary::fix44())
+            .unwrap();
+        let mut json_decoder =
+            crate::json::Decoder::<crate::json::Config>::new(Dictionary::fix44());
+        let message = json_decoder.decode(json.as_bytes()).unwrap();
+        assert_eq!(NewOrderSingle::from_field_map(&message).unwrap(), order);
+    }
+
+    #[test]
+    fn delimiters_are_not_checked_without_dictionary() {
+        let mut encoder = encoder();
+        encoder.set_dictionary(None);
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"D");
+        let mut parties = msg.start_group(fix44::NO_PARTY_I_DS);
+        parties.entry().set(fix44::PARTY_ROLE, 1u32);
+        assert_eq!(parties.done(), Ok(()));
+    }
 }
@@ -0,0 +1,235 @@
+//! Reordering of encoded fields according to the layouts of a [`Dictionary`].
+
+use super::EncodeError;
+use crate::dict::{FixDatatype, LayoutItem, LayoutItemKind};
+use crate::{Dictionary, FieldType, TagU32};
+use nohash_hasher::IntMap;
+use std::iter::Peekable;
+
+/// The canonical order of the fields within a message or a repeating group
+/// entry, with components expanded.
+#[derive(Debug, Default)]
+struct FieldOrder {
+    positions: IntMap<u32, usize>,
+    groups: IntMap<u32, FieldOrder>,
+    delimiter: Option<TagU32>,
+}
+
+impl FieldOrder {
+    fn add_items<'a>(&mut self, items: impl Iterator<Item = LayoutItem<'a>>) {
+        for item in items {
+            match item.kind() {
+                LayoutItemKind::Field(field) => self.add_tag(field.tag()),
+                LayoutItemKind::Component(component) => self.add_items(component.items()),
+                LayoutItemKind::Group(len_field, items) => {
+                    let tag = len_field.tag();
+                    self.add_tag(tag);
+                    let mut group = FieldOrder::default();
+                    group.add_items(items.into_iter());
+                    self.groups.entry(tag.get()).or_insert(group);
+                }
+            }
+        }
+    }
+
+    fn add_tag(&mut self, tag: TagU32) {
+        let position = self.positions.len();
+        self.positions.entry(tag.get()).or_insert(position);
+        self.delimiter.get_or_insert(tag);
+    }
+}
+
+/// A field, together with the entries of the repeating group it starts (if
+/// any).
+#[derive(Debug)]
+struct Node<'a> {
+    raw: &'a [u8],
+    position: usize,
+    entries: Vec<Vec<Node<'a>>>,
+}
+
+impl<'a> Node<'a> {
+    fn write(&self, output: &mut Vec<u8>) {
+        output.extend_from_slice(self.raw);
+        for entry in self.entries.iter() {
+            for node in entry.iter() {
+                node.write(output);
+            }
+        }
+    }
+}
+
+/// A field as it was written by the encoder, separator included.
+#[derive(Debug, Copy, Clone)]
+struct RawField<'a> {
+    tag: u32,
+    value: &'a [u8],
+    raw: &'a [u8],
+}
+
+/// Reorders `fields`, i.e. the encoded fields that follow `MsgType <35>`,
+/// according to `StandardHeader`, the layout of `msg_type` and
+/// `StandardTrailer` as defined by `dict`. Repeating group entries are kept in
+/// their original order, but their fields are reordered too.
+pub(crate) fn reorder(
+    dict: &Dictionary,
+    msg_type: &[u8],
+    fields: &[u8],
+    separator: u8,
+) -> Result<Vec<u8>, EncodeError> {
+    let message = std::str::from_utf8(msg_type)
+        .ok()
+        .and_then(|msg_type| dict.message_by_msgtype(msg_type))
+        .ok_or(EncodeError::InvalidMsgType)?;
+    let mut order = FieldOrder::default();
+    if let Some(header) = dict.component_by_name("StandardHeader") {
+        order.add_items(header.items());
+    }
+    order.add_items(message.layout());
+    if let Some(trailer) = dict.component_by_name("StandardTrailer") {
+        order.add_items(trailer.items());
+    }
+
+    let mut fields = split_fields(dict, fields, separator)?
+        .into_iter()
+        .peekable();
+    let mut nodes = Vec::new();
+    while let Some(field) = fields.next() {
+        nodes.push(read_node(&mut fields, &order, field)?);
+    }
+    sort(&mut nodes);
+    let mut output = Vec::new();
+    for node in nodes.iter() {
+        node.write(&mut output);
+    }
+    Ok(output)
+}
+
+fn split_fields<'a>(
+    dict: &Dictionary,
+    mut bytes: &'a [u8],
+    separator: u8,
+) -> Result<Vec<RawField<'a>>, EncodeError> {
+    let mut fields = Vec::new();
+    let mut data_length = None;
+    while !bytes.is_empty() {
+        let equal_sign_i = bytes
+            .iter()
+            .position(|byte| *byte == b'=')
+            .ok_or(EncodeError::FieldNotAllowed { tag: 0 })?;
+        let tag = std::str::from_utf8(&bytes[..equal_sign_i])
+            .ok()
+            .and_then(|tag| tag.parse::<u32>().ok())
+            .ok_or(EncodeError::FieldNotAllowed { tag: 0 })?;
+        let value_start_i = equal_sign_i + 1;
+        let datatype = dict.field_by_tag(tag).map(|field| field.fix_datatype());
+        let value_len = match (datatype, data_length.take()) {
+            // Data fields may contain the separator, so their length must be
+            // known in advance.
+            (Some(FixDatatype::Data), Some(len)) => len,
+            _ => bytes[value_start_i..]
+                .iter()
+                .position(|byte| *byte == separator)
+                .ok_or(EncodeError::FieldNotAllowed { tag })?,
+        };
+        let value_end_i = value_start_i + value_len;
+        if bytes.get(value_end_i) != Some(&separator) {
+            return Err(EncodeError::FieldNotAllowed { tag });
+        }
+        let value = &bytes[value_start_i..value_end_i];
+        if datatype == Some(FixDatatype::Length) {
+            data_length = usize::deserialize(value).ok();
+        }
+        fields.push(RawField {
+            tag,
+            value,
+            raw: &bytes[..=value_end_i],
+        });
+        bytes = &bytes[value_end_i + 1..];
+    }
+    Ok(fields)
+}
+
+fn read_node<'a, I>(
+    fields: &mut Peekable<I>,
+    order: &FieldOrder,
+    field: RawField<'a>,
+) -> Result<Node<'a>, EncodeError>
+where
+    I: Iterator<Item = RawField<'a>>,
+{
+    let position = *order
+        .positions
+        .get(&field.tag)
+        .ok_or(EncodeError::FieldNotAllowed { tag: field.tag })?;
+    let entries = match (order.groups.get(&field.tag), TagU32::new(field.tag)) {
+        (Some(group), Some(num_in_group_tag)) => {
+            let num_entries = usize::deserialize(field.value)
+                .map_err(|_| EncodeError::FieldNotAllowed { tag: field.tag })?;
+            read_entries(fields, group, num_in_group_tag, num_entries)?
+        }
+        _ => Vec::new(),
+    };
+    Ok(Node {
+        raw: field.raw,
+        position,
+        entries,
+    })
+}
+
+fn read_entries<'a, I>(
+    fields: &mut Peekable<I>,
+    order: &FieldOrder,
+    num_in_group_tag: TagU32,
+    num_entries: usize,
+) -> Result<Vec<Vec<Node<'a>>>, EncodeError>
+where
+    I: Iterator<Item = RawField<'a>>,
+{
+    let delimiter = order.delimiter.ok_or(EncodeError::FieldNotAllowed {
+        tag: num_in_group_tag.get(),
+    })?;
+    let mut entries = Vec::new();
+    for _ in 0..num_entries {
+        let field = match fields.next() {
+            Some(field) if field.tag == delimiter.get() => field,
+            Some(field) => {
+                return Err(match TagU32::new(field.tag) {
+                    Some(tag) => EncodeError::InvalidGroupDelimiter {
+                        num_in_group_tag,
+                        delimiter,
+                        tag,
+                    },
+                    None => EncodeError::FieldNotAllowed { tag: field.tag },
+                })
+            }
+            None => {
+                return Err(EncodeError::MissingField {
+                    tag: delimiter.get(),
+                })
+            }
+        };
+        let mut entry = vec![read_node(fields, order, field)?];
+        // An entry ends with the first field that doesn't belong to it, or that
+        // it already contains.
+        while let Some(field) = fields.peek().copied() {
+            let position = match order.positions.get(&field.tag) {
+                Some(position) if field.tag != delimiter.get() => *position,
+                _ => break,
+            };
+            if entry.iter().any(|node| node.position == position) {
+                break;
+            }
+            fields.next();
+            entry.push(read_node(fields, order, field)?);
+        }
+        sort(&mut entry);
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn sort(nodes: &mut [Node]) {
+    // Stable sorting, so that duplicate fields retain their original order.
+    nodes.sort_by_key(|node| node.position);
+}
@@ -0,0 +1,457 @@
+use super::{Configure, EncodeError, Encoder, Message};
+use crate::dict::IsFieldDefinition;
+use crate::field_types::MessageEncoding;
+use crate::{Buffer, FieldMap, FieldType, FieldValueError, RepeatingGroup, SetField, TagU32};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const BEGIN_STRING: u32 = 8;
+const BODY_LENGTH: u32 = 9;
+const CHECK_SUM: u32 = 10;
+const MSG_TYPE: u32 = 35;
+const MESSAGE_ENCODING: u32 = 347;
+
+/// A mutable, in-memory FIX message, with fields and repeating groups in
+/// sequential order.
+///
+/// [`MessageTree`] sits in between a decoded [`Message`], which is
+/// read-only, and an [`EncoderHandle`](super::EncoderHandle), which is
+/// write-only: it can be built from the former, modified via [`SetField`]
+/// and [`MessageTree::items_mut`], and then re-encoded with
+/// [`MessageTree::encode`].
+///
+/// `BodyLength <9>` and `CheckSum <10>` are not part of a [`MessageTree`],
+/// as they are recomputed during encoding. [`MessageTree`] is cheap to
+/// clone: its contents are only copied upon modification.
+///
+/// Text fields are decoded and encoded according to the `MessageEncoding
+/// <347>` of the tree itself: group entries don't know about the message
+/// they belong to.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::definitions::fix44;
+/// use fefix::tagvalue::{Config, Decoder, Encoder, MessageTree};
+/// use fefix::prelude::*;
+///
+/// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+/// decoder.config_mut().set_separator(b'|');
+/// let data = b"8=FIX.4.4|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=185|";
+/// let mut tree = MessageTree::from_message(&decoder.decode(data).unwrap());
+/// tree.set(fix44::SENDER_COMP_ID, "GATEWAY");
+///
+/// let mut encoder = Encoder::<Config>::new();
+/// encoder.config_mut().set_separator(b'|');
+/// let mut buffer = Vec::new();
+/// let (bytes, _) = tree.encode(&mut encoder, &mut buffer).unwrap();
+/// assert_eq!(
+///     bytes,
+///     b"8=FIX.4.4|9=00000048|35=0|49=GATEWAY|56=B|34=12|52=20100304-07:59:30|10=015|"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageTree {
+    items: Arc<Vec<MessageTreeItem>>,
+}
+
+/// A field or a repeating group within a [`MessageTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageTreeItem {
+    /// A FIX field and its raw value.
+    Field(TagU32, Vec<u8>),
+    /// A FIX repeating group, identified by the tag of its `NumInGroup` field,
+    /// and its entries.
+    Group(TagU32, Vec<MessageTree>),
+}
+
+impl MessageTreeItem {
+    /// Returns the tag of `self`, i.e. the tag of the `NumInGroup` field for
+    /// repeating groups.
+    pub fn tag(&self) -> TagU32 {
+        match self {
+            Self::Field(tag, _) => *tag,
+            Self::Group(tag, _) => *tag,
+        }
+    }
+}
+
+impl MessageTree {
+    /// Creates a new, empty [`MessageTree`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`MessageTree`] with the same fields and repeating
+    /// groups as `message`. If `message` is a group entry, only its contents
+    /// are copied.
+    ///
+    /// Only the last occurrence of duplicate fields is copied, and
+    /// [`Configure::should_decode_associative`] must be enabled.
+    pub fn from_message<T>(message: &Message<T>) -> Self {
+        let (root, fields) = message.fields_with_parents();
+        let mut children: HashMap<Option<(usize, usize)>, Vec<usize>> = HashMap::new();
+        let mut num_entries: HashMap<usize, usize> = HashMap::new();
+        for (i, (_, _, _, parent)) in fields.iter().enumerate() {
+            children.entry(*parent).or_default().push(i);
+            if let Some((index_of_group_tag, entry_index)) = parent {
+                let n = num_entries.entry(*index_of_group_tag).or_default();
+                *n = (*n).max(entry_index + 1);
+            }
+        }
+        let builder = TreeBuilder {
+            fields,
+            children,
+            num_entries,
+        };
+        builder.build(root)
+    }
+
+    /// Returns all fields and repeating groups of `self`, in sequential order.
+    pub fn items(&self) -> &[MessageTreeItem] {
+        &self.items[..]
+    }
+
+    /// Returns a mutable reference to the fields and repeating groups of
+    /// `self`, e.g. to insert items at specific positions.
+    pub fn items_mut(&mut self) -> &mut Vec<MessageTreeItem> {
+        Arc::make_mut(&mut self.items)
+    }
+
+    /// Returns the number of fields and repeating groups in `self`, not
+    /// counting the contents of group entries.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if `self` has no fields, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Removes the field or the repeating group with tag `tag` from `self`,
+    /// and returns it.
+    pub fn remove(&mut self, tag: u32) -> Option<MessageTreeItem> {
+        let i = self.position(tag)?;
+        Some(self.items_mut().remove(i))
+    }
+
+    /// Returns a mutable reference to the entries of the repeating group with
+    /// `NumInGroup` field `tag`, e.g. to add or remove entries.
+    pub fn group_mut(&mut self, tag: u32) -> Option<&mut Vec<MessageTree>> {
+        let i = self.position(tag)?;
+        match &mut self.items_mut()[i] {
+            MessageTreeItem::Group(_, entries) => Some(entries),
+            MessageTreeItem::Field(_, _) => None,
+        }
+    }
+
+    /// Sets the entries of the repeating group with `NumInGroup` field `tag`.
+    /// An existing field or group with the same tag is replaced in place,
+    /// otherwise the group is appended to `self`.
+    pub fn set_group(&mut self, tag: TagU32, entries: Vec<MessageTree>) {
+        self.set_item(MessageTreeItem::Group(tag, entries));
+    }
+
+    /// Encodes `self` via `encoder` and appends the result to `buffer`.
+    /// `BodyLength <9>` and `CheckSum <10>` are recomputed, while all other
+    /// fields keep their order, besides `BeginString <8>` and `MsgType <35>`
+    /// which always come first.
+    ///
+    /// Empty repeating groups are encoded with a `NumInGroup` field of zero;
+    /// remove them altogether to omit them.
+    pub fn encode<'a, B, C>(
+        &self,
+        encoder: &'a mut Encoder<C>,
+        buffer: &'a mut B,
+    ) -> Result<(&'a [u8], usize), EncodeError>
+    where
+        B: Buffer,
+        C: Configure,
+    {
+        let begin_string = self.required_field(BEGIN_STRING)?;
+        let msg_type = self.required_field(MSG_TYPE)?;
+        let mut msg = encoder.start_message(begin_string, buffer, msg_type);
+        let items = self.items.iter().filter(|item| {
+            !matches!(
+                item.tag().get(),
+                BEGIN_STRING | BODY_LENGTH | MSG_TYPE | CHECK_SUM
+            )
+        });
+        write_items(&mut msg, items);
+        Ok(msg.done())
+    }
+
+    fn text_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        let value = FieldMap::<u32>::fv_raw(self, MESSAGE_ENCODING);
+        value.map_or(Ok(MessageEncoding::default()), MessageEncoding::deserialize)
+    }
+
+    fn required_field(&self, tag: u32) -> Result<&[u8], EncodeError> {
+        self.fv_raw(tag).ok_or(EncodeError::MissingField { tag })
+    }
+
+    fn position(&self, tag: u32) -> Option<usize> {
+        self.items.iter().position(|item| item.tag().get() == tag)
+    }
+
+    fn set_item(&mut self, item: MessageTreeItem) {
+        match self.position(item.tag().get()) {
+            Some(i) => self.items_mut()[i] = item,
+            None => self.items_mut().push(item),
+        }
+    }
+}
+
+fn write_items<'a>(msg: &mut impl SetField<u32>, items: impl Iterator<Item = &'a MessageTreeItem>) {
+    for item in items {
+        match item {
+            MessageTreeItem::Field(tag, value) => msg.set(tag.get(), &value[..]),
+            MessageTreeItem::Group(tag, entries) => {
+                msg.set(tag.get(), entries.len());
+                for entry in entries {
+                    write_items(msg, entry.items.iter());
+                }
+            }
+        }
+    }
+}
+
+struct TreeBuilder<'a> {
+    fields: Vec<super::decoder::FieldWithParent<'a>>,
+    // Positions in `fields` of the contents of each group entry.
+    children: HashMap<Option<(usize, usize)>, Vec<usize>>,
+    // Number of entries of each group, by index of the `NumInGroup` field.
+    num_entries: HashMap<usize, usize>,
+}
+
+impl<'a> TreeBuilder<'a> {
+    fn build(&self, parent: Option<(usize, usize)>) -> MessageTree {
+        let positions = self.children.get(&parent).map(Vec::as_slice).unwrap_or(&[]);
+        let items = positions
+            .iter()
+            .map(|position| self.fields[*position])
+            .filter(|(_, tag, _, _)| {
+                parent.is_some() || !matches!(tag.get(), BODY_LENGTH | CHECK_SUM)
+            })
+            .map(|(i, tag, value, _)| match self.num_entries.get(&i) {
+                Some(num_entries) => MessageTreeItem::Group(
+                    tag,
+                    (0..*num_entries)
+                        .map(|entry_index| self.build(Some((i, entry_index))))
+                        .collect(),
+                ),
+                None => MessageTreeItem::Field(tag, value.to_vec()),
+            })
+            .collect();
+        MessageTree {
+            items: Arc::new(items),
+        }
+    }
+}
+
+impl FieldMap<u32> for MessageTree {
+    type Group = MessageTreeGroup;
+
+    fn group(
+        &self,
+        tag: u32,
+    ) -> Result<Self::Group, FieldValueError<<usize as FieldType<'_>>::Error>> {
+        let i = self.position(tag).ok_or(FieldValueError::Missing)?;
+        match &self.items[i] {
+            MessageTreeItem::Group(_, entries) => Ok(MessageTreeGroup {
+                entries: entries.clone(),
+            }),
+            // E.g. `NumInGroup` fields with a value of zero.
+            MessageTreeItem::Field(_, value) => {
+                usize::deserialize(value).map_err(FieldValueError::Invalid)?;
+                Ok(MessageTreeGroup {
+                    entries: Vec::new(),
+                })
+            }
+        }
+    }
+
+    fn fv_raw(&self, tag: u32) -> Option<&[u8]> {
+        self.items.iter().find_map(|item| match item {
+            MessageTreeItem::Field(t, value) if t.get() == tag => Some(&value[..]),
+            _ => None,
+        })
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        self.text_encoding()
+    }
+}
+
+impl<F> FieldMap<&F> for MessageTree
+where
+    F: IsFieldDefinition,
+{
+    type Group = MessageTreeGroup;
+
+    fn group(
+        &self,
+        field: &F,
+    ) -> Result<Self::Group, FieldValueError<<usize as FieldType<'_>>::Error>> {
+        self.group(field.tag().get())
+    }
+
+    fn fv_raw(&self, field: &F) -> Option<&[u8]> {
+        self.fv_raw(field.tag().get())
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        self.text_encoding()
+    }
+}
+
+impl SetField<u32> for MessageTree {
+    fn set_with<'s, V>(&'s mut self, tag: u32, value: V, settings: V::SerializeSettings)
+    where
+        V: FieldType<'s>,
+    {
+        if let Some(tag) = TagU32::new(tag) {
+            let mut bytes = Vec::new();
+            value.serialize_with(&mut bytes, settings);
+            self.set_item(MessageTreeItem::Field(tag, bytes));
+        }
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        self.text_encoding()
+    }
+}
+
+impl SetField<TagU32> for MessageTree {
+    fn set_with<'s, V>(&'s mut self, tag: TagU32, value: V, settings: V::SerializeSettings)
+    where
+        V: FieldType<'s>,
+    {
+        self.set_with(tag.get(), value, settings)
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        self.text_encoding()
+    }
+}
+
+impl<F> SetField<&F> for MessageTree
+where
+    F: IsFieldDefinition,
+{
+    fn set_with<'s, V>(&'s mut self, field: &F, value: V, settings: V::SerializeSettings)
+    where
+        V: FieldType<'s>,
+    {
+        self.set_with(field.tag(), value, settings)
+    }
+
+    fn message_encoding(&self) -> Result<MessageEncoding, &'static str> {
+        self.text_encoding()
+    }
+}
+
+/// A repeating group within a [`MessageTree`].
+#[derive(Debug, Clone)]
+pub struct MessageTreeGroup {
+    entries: Vec<MessageTree>,
+}
+
+impl RepeatingGroup for MessageTreeGroup {
+    type Entry = MessageTree;
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get(&self, i: usize) -> Option<Self::Entry> {
+        self.entries.get(i).cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::definitions::fix44;
+    use crate::tagvalue::{Config, Decoder};
+    use crate::{Dictionary, GetConfig};
+
+    const QUOTE: &[u8] = b"8=FIX.4.2|9=196|35=X|49=A|56=B|34=12|52=20100318-03:21:11.364|262=A|268=2|279=0|269=0|278=BID|55=EUR/USD|270=1.37215|15=EUR|271=2500000|346=1|279=0|269=1|278=OFFER|55=EUR/USD|270=1.37224|15=EUR|271=2503200|346=1|10=171|";
+
+    fn decoder() -> Decoder {
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        decoder
+    }
+
+    fn encode(tree: &MessageTree) -> Vec<u8> {
+        let mut encoder = Encoder::<Config>::new();
+        encoder.config_mut().set_separator(b'|');
+        let mut buffer = Vec::new();
+        tree.encode(&mut encoder, &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn round_trip_preserves_fields_and_groups() {
+        let mut decoder = decoder();
+        let tree = MessageTree::from_message(&decoder.decode(QUOTE).unwrap());
+        assert_eq!(tree.fv_raw(9), None);
+        assert_eq!(tree.fv_raw(10), None);
+        let group = tree.group(fix44::NO_MD_ENTRIES).unwrap();
+        assert_eq!(group.len(), 2);
+        assert_eq!(group.get(1).unwrap().fv(fix44::MD_ENTRY_PX), Ok("1.37224"));
+
+        let bytes = encode(&tree);
+        let payload = |msg: &[u8]| {
+            let msg = std::str::from_utf8(msg).unwrap().to_string();
+            msg[msg.find("|35=").unwrap()..msg.find("|10=").unwrap()].to_string()
+        };
+        assert_eq!(payload(&bytes), payload(QUOTE));
+        let decoded = MessageTree::from_message(&decoder.decode(&bytes[..]).unwrap());
+        assert_eq!(decoded, tree);
+    }
+
+    #[test]
+    fn modified_groups_are_reencoded() {
+        let mut tree = MessageTree::from_message(&decoder().decode(QUOTE).unwrap());
+        tree.set(fix44::SENDER_COMP_ID, "B");
+        tree.set(fix44::TARGET_COMP_ID, "A");
+        tree.remove(52);
+        let entries = tree.group_mut(268).unwrap();
+        entries.remove(0);
+        entries[0].set(fix44::MD_ENTRY_PX, "1.5");
+        let mut party = MessageTree::new();
+        party.set(fix44::PARTY_ID, "P");
+        tree.set_group(fix44::NO_PARTY_I_DS.tag(), vec![party]);
+
+        assert_eq!(
+            encode(&tree),
+            b"8=FIX.4.2|9=00000111|35=X|49=B|56=A|34=12|262=A|268=1|279=0|269=1|278=OFFER|55=EUR/USD|270=1.5|15=EUR|271=2503200|346=1|453=1|448=P|10=088|"
+        );
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let mut tree = MessageTree::from_message(&decoder().decode(QUOTE).unwrap());
+        let clone = tree.clone();
+        tree.group_mut(268).unwrap()[1].set(fix44::MD_ENTRY_PX, "1.5");
+        let group = clone.group(268).unwrap();
+        assert_eq!(group.get(1).unwrap().fv(fix44::MD_ENTRY_PX), Ok("1.37224"));
+    }
+
+    #[test]
+    fn begin_string_and_msg_type_are_required() {
+        let mut tree = MessageTree::new();
+        tree.set(fix44::BEGIN_STRING, "FIX.4.4");
+        let mut encoder = Encoder::<Config>::new();
+        let mut buffer = Vec::new();
+        assert_eq!(
+            tree.encode(&mut encoder, &mut buffer).err(),
+            Some(EncodeError::MissingField { tag: 35 })
+        );
+        tree.set(fix44::MSG_TYPE, "0");
+        assert!(tree.encode(&mut encoder, &mut buffer).is_ok());
+    }
+}
@@ -39,24 +39,83 @@
 //! |[`RawDecoderStreaming`]|byte streams             |[`RawFrame`]|
 //! |[`Decoder`]           |`&[u8]`                  |[`Message`] |
 //! |[`DecoderStreaming`]   |data streams             |[`Message`] |
+//! |`TokioRawDecoder`     |[`tokio_util`] codecs    |[`RawFrame`]|
+//! |`TokioDecoder`        |[`tokio_util`] codecs    |[`OwnedMessage`]|
 
+use crate::TagU32;
 use std::fmt::Debug;
 
 mod config;
 mod decoder;
 mod encoder;
+mod field_order;
+mod message_tree;
 mod raw_decoder;
 mod utils;
+mod validator;
 
-pub use config::{Config, Configure};
-pub use decoder::{Decoder, DecoderStreaming, Fields, Message, MessageGroup};
-pub use encoder::{Encoder, EncoderHandle};
+pub use config::{Config, Configure, DuplicateTagPolicy, UnknownTagPolicy};
+pub use decoder::{
+    Decoder, DecoderStreaming, Fields, Message, MessageGroup, OwnedMessage, OwnedMessageGroup,
+};
+pub use encoder::{Encoder, EncoderGroup, EncoderHandle, StartGroup};
+pub use message_tree::{MessageTree, MessageTreeGroup, MessageTreeItem};
+pub(crate) use raw_decoder::garbled_len;
 pub use raw_decoder::{RawDecoder, RawDecoderStreaming, RawFrame};
+pub use validator::{ValidationError, ValidationRules, Validator};
 
 #[cfg(feature = "utils-tokio")]
 mod tokio_decoder;
 #[cfg(feature = "utils-tokio")]
-pub use tokio_decoder::TokioDecoder;
+pub use tokio_decoder::{TokioDecoder, TokioRawDecoder};
+
+/// The type returned in the event of an error during message encoding.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// A field was added to a repeating group before its first entry.
+    #[error("Tag {tag} was set outside of the entries of group {num_in_group_tag}.")]
+    FieldOutsideOfGroupEntry {
+        /// The tag of the `NumInGroup` field.
+        num_in_group_tag: TagU32,
+        /// The tag of the offending field.
+        tag: TagU32,
+    },
+    /// A repeating group entry doesn't start with the delimiter field.
+    #[error("Entries of group {num_in_group_tag} must start with tag {delimiter}, not {tag}.")]
+    InvalidGroupDelimiter {
+        /// The tag of the `NumInGroup` field.
+        num_in_group_tag: TagU32,
+        /// The tag of the delimiter field.
+        delimiter: TagU32,
+        /// The tag of the first field of the entry.
+        tag: TagU32,
+    },
+    /// A repeating group entry has no fields.
+    #[error("Group {num_in_group_tag} has an empty entry.")]
+    EmptyGroupEntry {
+        /// The tag of the `NumInGroup` field.
+        num_in_group_tag: TagU32,
+    },
+    /// A mandatory field is missing, e.g. `MsgType <35>`.
+    #[error("Missing field {tag}.")]
+    MissingField {
+        /// The tag of the missing field.
+        tag: u32,
+    },
+    /// The [`Dictionary`](crate::Dictionary) of the encoder doesn't allow a
+    /// field in its current position, i.e. neither in `StandardHeader`, the
+    /// message body, `StandardTrailer` nor the enclosing repeating group.
+    #[error("Tag {tag} is not allowed here.")]
+    FieldNotAllowed {
+        /// The tag of the offending field.
+        tag: u32,
+    },
+    /// The [`Dictionary`](crate::Dictionary) of the encoder doesn't define the
+    /// message type.
+    #[error("Invalid MsgType <35>.")]
+    InvalidMsgType,
+}
 
 /// The type returned in the event of an error during message decoding.
 #[derive(Debug, thiserror::Error)]
@@ -67,6 +126,35 @@ pub enum DecodeError {
     /// Invalid FIX message syntax, `BodyLength <9>` value mismatch, or similar errors.
     #[error("Invalid FIX message syntax.")]
     Invalid,
+    /// A `Length` field has an invalid value, or its data field doesn't end
+    /// where it says.
+    #[error("Invalid length field {tag}.")]
+    InvalidDataLength {
+        /// The tag of the `Length` field.
+        tag: u32,
+    },
+    /// A tag appears more than once, and
+    /// [`Configure::duplicate_tag_policy`] is [`DuplicateTagPolicy::Error`].
+    #[error("Duplicate tag {tag}.")]
+    DuplicateTag {
+        /// The duplicate tag.
+        tag: u32,
+    },
+    /// A tag is not defined by the [`Dictionary`](crate::Dictionary) of the
+    /// decoder, and [`Configure::unknown_tag_policy`] is
+    /// [`UnknownTagPolicy::Reject`].
+    #[error("Unknown tag {tag}.")]
+    UnknownTag {
+        /// The unknown tag.
+        tag: u32,
+    },
+    /// A `StandardHeader` field comes after a message body field, and
+    /// [`Configure::verify_header_order`] is on.
+    #[error("Header field {tag} after the message body.")]
+    HeaderFieldAfterBody {
+        /// The tag of the header field.
+        tag: u32,
+    },
     /// Invalid `CheckSum <10>` FIX field value.
     #[error("Invalid `CheckSum <10>` FIX field value.")]
     CheckSum,
@@ -164,7 +164,11 @@ impl<C> GetConfig for RawDecoder<C> {
 #[derive(Debug)]
 enum ParserState {
     Empty,
+    // `BeginString <8>` and `BodyLength <9>` are still incomplete; we need at
+    // least this many bytes.
+    PartialHeader(usize),
     Header(HeaderInfo, usize),
+    // The message is garbled; the buffer must be cleared.
     Failed,
 }
 
@@ -196,30 +200,67 @@ where
     fn num_bytes_required(&self) -> usize {
         match self.state {
             ParserState::Empty => utils::MIN_FIX_MESSAGE_LEN_IN_BYTES,
+            ParserState::PartialHeader(len) => len,
             ParserState::Header(_, expected_len) => expected_len,
-            ParserState::Failed => 0,
+            ParserState::Failed => self.buffer.len(),
         }
     }
 
     fn try_parse(&mut self) -> Result<Option<()>, Self::Error> {
+        let result = self.try_parse_frame();
+        if result.is_err() {
+            self.state = ParserState::Failed;
+        }
+        result
+    }
+}
+
+impl<B, C> RawDecoderStreaming<B, C>
+where
+    B: Buffer,
+    C: Configure,
+{
+    fn try_parse_frame(&mut self) -> Result<Option<()>, DecodeError> {
         match self.state {
-            ParserState::Empty => {
-                let header_info =
-                    HeaderInfo::parse(self.buffer.as_slice(), self.config().separator());
-                if let Some(header_info) = header_info {
-                    let expected_len_of_frame = header_info.field_1.end
-                        + 1
-                        + header_info.nominal_body_len
-                        + utils::FIELD_CHECKSUM_LEN_IN_BYTES;
-
-                    self.state = ParserState::Header(header_info, expected_len_of_frame);
-                    Ok(None)
-                } else {
-                    Err(DecodeError::Invalid)
+            ParserState::Empty | ParserState::PartialHeader(_) => {
+                let separator = self.config().separator();
+                let data = self.buffer.as_slice();
+                match frame_len(data, separator)? {
+                    Some(len) if matches!(self.config().max_message_size(), Some(max) if len > max) => {
+                        Err(DecodeError::Invalid)
+                    }
+                    Some(len) => {
+                        let header_info =
+                            HeaderInfo::parse(data, separator).ok_or(DecodeError::Invalid)?;
+                        self.state = ParserState::Header(header_info, len);
+                        Ok(None)
+                    }
+                    None => {
+                        // Zero-padded `BodyLength <9>` values can make the
+                        // header longer than usual, so we just need more bytes.
+                        self.state = ParserState::PartialHeader(data.len() + 1);
+                        Ok(None)
+                    }
                 }
             }
-            ParserState::Header(_, _) => Ok(Some(())),
-            ParserState::Failed => panic!("Failed state"),
+            ParserState::Header(_, len) if self.buffer.len() >= len => {
+                let data = &self.buffer.as_slice()[..len];
+                // A wrong `BodyLength <9>` shows up as a misplaced
+                // `CheckSum <10>`.
+                let checksum_field = &data[len - utils::FIELD_CHECKSUM_LEN_IN_BYTES..];
+                if !checksum_field.starts_with(b"10=")
+                    || checksum_field[utils::FIELD_CHECKSUM_LEN_IN_BYTES - 1]
+                        != self.config().separator()
+                {
+                    return Err(DecodeError::Invalid);
+                }
+                if self.config().verify_checksum() {
+                    utils::verify_checksum(data)?;
+                }
+                Ok(Some(()))
+            }
+            ParserState::Header(_, _) => Ok(None),
+            ParserState::Failed => Err(DecodeError::Invalid),
         }
     }
 }
@@ -301,6 +342,65 @@ impl HeaderInfo {
     }
 }
 
+/// Returns the length in bytes of the FIX message at the start of `data`, as
+/// announced by its `BodyLength <9>`, or [`None`] if `data` doesn't contain
+/// the whole header yet. `data` may contain less bytes than the returned
+/// length.
+pub(crate) fn frame_len(data: &[u8], separator: u8) -> Result<Option<usize>, DecodeError> {
+    if let Some(header_info) = HeaderInfo::parse(data, separator) {
+        let body_length = &data[header_info.field_1.clone()];
+        if body_length.is_empty() {
+            return Err(DecodeError::Invalid);
+        }
+        // `HeaderInfo::parse` is lenient with malformed and overly long
+        // values, but we must not trust them to allocate buffers.
+        let mut nominal_body_len = 0usize;
+        for byte in body_length {
+            if !byte.is_ascii_digit() {
+                return Err(DecodeError::Invalid);
+            }
+            nominal_body_len = nominal_body_len
+                .checked_mul(10)
+                .and_then(|n| n.checked_add((byte - b'0') as usize))
+                .ok_or(DecodeError::Invalid)?;
+        }
+        nominal_body_len
+            .checked_add(header_info.field_1.end + 1 + utils::FIELD_CHECKSUM_LEN_IN_BYTES)
+            .map(Some)
+            .ok_or(DecodeError::Invalid)
+    } else if data.len() < utils::MAX_HEADER_LEN_IN_BYTES
+        && data.iter().filter(|byte| **byte == separator).count() < 2
+    {
+        Ok(None)
+    } else {
+        Err(DecodeError::Invalid)
+    }
+}
+
+/// The first bytes of every FIX message, regardless of `BeginString <8>`.
+const START_OF_MESSAGE: &[u8] = b"8=FIX";
+
+/// Returns the number of bytes at the start of the garbled `data` that must be
+/// discarded to resynchronize with the stream, i.e. all bytes before the next
+/// `8=FIX`. The result is always positive if `data` isn't empty.
+///
+/// A trailing partial `8=FIX` is not discarded, as the rest of it might
+/// still be on its way.
+pub(crate) fn garbled_len(data: &[u8]) -> usize {
+    if let Some(i) = data
+        .windows(START_OF_MESSAGE.len())
+        .skip(1)
+        .position(|window| window == START_OF_MESSAGE)
+    {
+        return i + 1;
+    }
+    let len_of_partial_start = (1..START_OF_MESSAGE.len())
+        .rev()
+        .find(|len| *len < data.len() && data.ends_with(&START_OF_MESSAGE[..*len]))
+        .unwrap_or(0);
+    data.len() - len_of_partial_start
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -403,4 +503,65 @@ mod test {
             b"35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|"
         );
     }
+
+    #[test]
+    fn streaming_decoder_with_zero_padded_body_length() {
+        let stream = b"8=FIX.4.4|9=00000005|35=0|10=107|";
+        let mut i = 0;
+        let mut decoder = new_decoder().streaming(vec![]);
+        let mut ready = false;
+        while !ready {
+            let buf = decoder.fillable();
+            buf.clone_from_slice(&stream[i..i + buf.len()]);
+            i += buf.len();
+            ready = decoder.try_parse().unwrap().is_some();
+        }
+        assert_eq!(i, stream.len());
+        assert_eq!(decoder.raw_frame().begin_string(), b"FIX.4.4");
+        assert_eq!(decoder.raw_frame().payload(), b"35=0|");
+    }
+
+    #[test]
+    fn streaming_decoder_rejects_large_messages() {
+        let mut decoder = new_decoder();
+        decoder.config_mut().set_max_message_size(Some(32));
+        let mut decoder = decoder.streaming(vec![]);
+        decoder
+            .buffer()
+            .extend_from_slice(b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|");
+        assert!(matches!(decoder.try_parse(), Err(DecodeError::Invalid)));
+        // Further attempts keep failing, without panicking.
+        assert!(matches!(decoder.try_parse(), Err(DecodeError::Invalid)));
+        decoder.clear();
+        assert!(matches!(decoder.try_parse(), Ok(None)));
+    }
+
+    #[test]
+    fn streaming_decoder_detects_wrong_body_length() {
+        let stream = b"8=FIX.4.2|9=39|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let mut decoder = new_decoder().streaming(vec![]);
+        let mut i = 0;
+        let result = loop {
+            let buf = decoder.fillable();
+            buf.clone_from_slice(&stream[i..i + buf.len()]);
+            i += buf.len();
+            match decoder.try_parse() {
+                Ok(None) => (),
+                result => break result,
+            }
+        };
+        assert!(matches!(result, Err(DecodeError::Invalid)));
+    }
+
+    #[test]
+    fn garbled_bytes_end_at_the_next_message() {
+        assert_eq!(garbled_len(b"8=FIX.4.2|9=4|foo8=FIX.4.4|"), 17);
+        assert_eq!(garbled_len(b"8=FIX.4.2|9=4|foo8=FIXT.1.1|"), 17);
+        assert_eq!(garbled_len(b"8=FIX.4.2|9=4|foo"), 17);
+        assert_eq!(garbled_len(b"8=FIX.4.2|9=4|foo8=F"), 17);
+        assert_eq!(garbled_len(b"foo8"), 3);
+        assert_eq!(garbled_len(b"8=FI"), 4);
+        assert_eq!(garbled_len(b"x"), 1);
+        assert_eq!(garbled_len(b""), 0);
+    }
 }
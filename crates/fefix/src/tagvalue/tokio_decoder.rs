@@ -1,30 +1,47 @@
-use super::{Config, Configure, DecodeError, Decoder, RawDecoder, RawFrame};
+use super::raw_decoder::frame_len;
+use super::{Config, Configure, DecodeError, Decoder, OwnedMessage, RawDecoder, RawFrame};
 use crate::{Dictionary, GetConfig};
 use bytes::{Bytes, BytesMut};
 use tokio_util::codec;
 
-#[derive(Debug)]
+/// A [`tokio_util::codec::Decoder`] that frames FIX messages according to
+/// their `BodyLength <9>` and yields [`RawFrame`]s.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "utils-tokio")))]
 pub struct TokioRawDecoder<C = Config> {
-    dict: Dictionary,
     raw_decoder: RawDecoder<C>,
 }
 
-impl codec::Decoder for TokioRawDecoder {
+impl<C> TokioRawDecoder<C>
+where
+    C: Configure,
+{
+    /// Creates a new [`TokioRawDecoder`] with default configuration options.
+    pub fn new() -> Self {
+        Self {
+            raw_decoder: RawDecoder::new(),
+        }
+    }
+}
+
+impl<C> codec::Decoder for TokioRawDecoder<C>
+where
+    C: Configure,
+{
     type Item = RawFrame<Bytes>;
     type Error = DecodeError;
 
     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        let split = src.split();
-        let result = self.raw_decoder.decode(split);
-        match result {
-            Ok(raw_frame) => Ok(Some(RawFrame {
-                data: raw_frame.data.freeze(),
-                begin_string: raw_frame.begin_string,
-                payload: raw_frame.payload,
-            })),
-            Err(DecodeError::Invalid) => Ok(None),
-            Err(e) => Err(e),
-        }
+        let bytes = match split_frame(src, self.config())? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+        let raw_frame = self.raw_decoder.decode(&bytes[..])?;
+        Ok(Some(RawFrame {
+            begin_string: raw_frame.begin_string,
+            payload: raw_frame.payload,
+            data: bytes,
+        }))
     }
 }
 
@@ -40,27 +57,248 @@ impl<C> GetConfig for TokioRawDecoder<C> {
     }
 }
 
+/// A [`tokio_util::codec::Decoder`] that frames FIX messages according to
+/// their `BodyLength <9>` and yields [`OwnedMessage`]s, which can be freely
+/// moved across tasks.
+///
+/// [`TokioDecoder`] also implements [`tokio_util::codec::Encoder`] for
+/// already-encoded messages, so that it can be used with
+/// [`tokio_util::codec::Framed`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::prelude::*;
+/// use fefix::tagvalue::{Config, Configure, TokioDecoder};
+/// use bytes::BytesMut;
+/// use tokio_util::codec::Decoder as _;
+///
+/// let mut codec = TokioDecoder::<Config>::new(Dictionary::fix44());
+/// codec.config_mut().set_separator(b'|');
+/// let mut buffer = BytesMut::from(&b"8=FIX.4.4|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=185|8=FIX"[..]);
+/// let message = codec.decode(&mut buffer).unwrap().unwrap();
+/// assert_eq!(message.fv(fix44::MSG_SEQ_NUM), Ok(12));
+/// // The start of the next message is left untouched.
+/// assert_eq!(&buffer[..], b"8=FIX");
+/// assert!(codec.decode(&mut buffer).unwrap().is_none());
+/// ```
 #[derive(Debug)]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "utils-tokio")))]
 pub struct TokioDecoder<C = Config>
 where
     C: Configure,
 {
-    dict: Dictionary,
-    raw_decoder: Decoder<C>,
+    decoder: Decoder<C>,
+}
+
+impl<C> TokioDecoder<C>
+where
+    C: Configure,
+{
+    /// Creates a new [`TokioDecoder`] for the tag-value format. `dict` is used
+    /// to parse messages.
+    pub fn new(dict: Dictionary) -> Self {
+        Self {
+            decoder: Decoder::new(dict),
+        }
+    }
+
+    /// Returns an immutable reference to the underlying [`Decoder`].
+    pub fn decoder(&self) -> &Decoder<C> {
+        &self.decoder
+    }
+
+    /// Returns a mutable reference to the underlying [`Decoder`].
+    pub fn decoder_mut(&mut self) -> &mut Decoder<C> {
+        &mut self.decoder
+    }
+}
+
+impl<C> codec::Decoder for TokioDecoder<C>
+where
+    C: Configure,
+{
+    type Item = OwnedMessage<Bytes>;
+    type Error = DecodeError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let bytes = match split_frame(src, self.config())? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+        self.decoder.decode_owned(bytes).map(Some)
+    }
+}
+
+impl<'a, C> codec::Encoder<&'a [u8]> for TokioDecoder<C>
+where
+    C: Configure,
+{
+    type Error = DecodeError;
+
+    fn encode(&mut self, item: &'a [u8], dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.extend_from_slice(item);
+        Ok(())
+    }
+}
+
+impl<C, T> codec::Encoder<OwnedMessage<T>> for TokioDecoder<C>
+where
+    C: Configure,
+    T: AsRef<[u8]>,
+{
+    type Error = DecodeError;
+
+    fn encode(&mut self, item: OwnedMessage<T>, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.extend_from_slice(item.as_bytes());
+        Ok(())
+    }
+}
+
+impl<C> GetConfig for TokioDecoder<C>
+where
+    C: Configure,
+{
+    type Config = C;
+
+    fn config(&self) -> &Self::Config {
+        self.decoder.config()
+    }
+
+    fn config_mut(&mut self) -> &mut Self::Config {
+        self.decoder.config_mut()
+    }
+}
+
+/// Splits the first FIX message off `src`, if it's complete. Otherwise,
+/// reserves enough capacity for it and returns [`None`].
+fn split_frame(src: &mut BytesMut, config: &impl Configure) -> Result<Option<Bytes>, DecodeError> {
+    match frame_len(&src[..], config.separator())? {
+        Some(len) if matches!(config.max_message_size(), Some(max) if len > max) => {
+            Err(DecodeError::Invalid)
+        }
+        Some(len) if src.len() >= len => Ok(Some(src.split_to(len).freeze())),
+        Some(len) => {
+            src.reserve(len - src.len());
+            Ok(None)
+        }
+        None => Ok(None),
+    }
 }
 
-//impl codec::Decoder for TokioDecoder {
-//    type Item = Message;
-//    type Error = DecodeError;
-//
-//    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-//        let split = src.split();
-//        let result = self.raw_decoder.decode(split);
-//        match result {
-//            Ok(raw_frame) => Ok(Some(raw_frame.map_data(|bytes_mut| bytes_mut.freeze()))),
-//            Err(DecodeError::Invalid) => Ok(None),
-//            Err(e) => Err(e),
-//        }
-//    }
-//}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use crate::tagvalue::Encoder;
+    use futures::{SinkExt, StreamExt};
+    use tokio_util::codec::{Decoder as _, Encoder as _, Framed};
+
+    const HEARTBEAT: &[u8] = b"8=FIX.4.4|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=185|";
+
+    fn codec() -> TokioDecoder {
+        let mut codec = TokioDecoder::<Config>::new(Dictionary::fix44());
+        codec.config_mut().set_separator(b'|');
+        codec
+    }
+
+    #[test]
+    fn several_messages_in_one_buffer() {
+        let mut codec = codec();
+        let mut buffer = BytesMut::new();
+        buffer.extend_from_slice(HEARTBEAT);
+        buffer.extend_from_slice(HEARTBEAT);
+        for _ in 0..2 {
+            let message = codec.decode(&mut buffer).unwrap().unwrap();
+            assert_eq!(message.as_bytes(), HEARTBEAT);
+            assert_eq!(message.fv(fix44::SENDER_COMP_ID), Ok("A"));
+        }
+        assert!(buffer.is_empty());
+        assert!(codec.decode(&mut buffer).unwrap().is_none());
+    }
+
+    #[test]
+    fn partial_messages_need_more_bytes() {
+        let mut codec = codec();
+        let mut buffer = BytesMut::new();
+        for (i, byte) in HEARTBEAT.iter().enumerate() {
+            buffer.extend_from_slice(&[*byte]);
+            let result = codec.decode(&mut buffer).unwrap();
+            assert_eq!(result.is_some(), i == HEARTBEAT.len() - 1);
+        }
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn malformed_body_length_is_invalid() {
+        let mut codec = codec();
+        let mut buffer = BytesMut::from(&b"8=FIX.4.4|9=4a|35=0|"[..]);
+        assert!(matches!(
+            codec.decode(&mut buffer),
+            Err(DecodeError::Invalid)
+        ));
+        let mut buffer = BytesMut::from(&b"8=FIX.4.4|9=99999999999999999999999|35=0|"[..]);
+        assert!(matches!(
+            codec.decode(&mut buffer),
+            Err(DecodeError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn raw_decoder_yields_frames() {
+        let mut codec = TokioRawDecoder::<Config>::new();
+        codec.config_mut().set_separator(b'|');
+        let mut buffer = BytesMut::from(HEARTBEAT);
+        buffer.extend_from_slice(b"8=FIX.4.4|9=4");
+        let frame = codec.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(frame.as_bytes(), HEARTBEAT);
+        assert_eq!(frame.begin_string(), b"FIX.4.4");
+        assert!(codec.decode(&mut buffer).unwrap().is_none());
+        assert_eq!(&buffer[..], b"8=FIX.4.4|9=4");
+    }
+
+    #[test]
+    fn repeating_groups_of_owned_messages() {
+        let mut codec = codec();
+        let mut buffer = BytesMut::from(
+            &b"8=FIX.4.4|9=66|35=W|55=EUR/USD|268=2|269=0|270=1.1|271=100|269=1|270=1.2|271=200|10=202|"[..],
+        );
+        let message = codec.decode(&mut buffer).unwrap().unwrap();
+        let group = message.group(fix44::NO_MD_ENTRIES).unwrap();
+        assert_eq!(group.len(), 2);
+        let entry = group.get(1).unwrap();
+        assert_eq!(entry.fv_raw(fix44::MD_ENTRY_PX), Some(&b"1.2"[..]));
+        assert_eq!(entry.fv(fix44::MD_ENTRY_SIZE), Ok(200));
+        assert!(group.get(2).is_none());
+    }
+
+    #[test]
+    fn encoding_extends_the_buffer() {
+        let mut codec = codec();
+        let mut encoder = Encoder::<Config>::default();
+        encoder.config_mut().set_separator(b'|');
+        let mut buffer = Vec::new();
+        let mut msg = encoder.start_message(b"FIX.4.4", &mut buffer, b"0");
+        msg.set(fix44::MSG_SEQ_NUM, 1u32);
+        let (bytes, _) = msg.done();
+
+        let mut dst = BytesMut::new();
+        codec.encode(bytes, &mut dst).unwrap();
+        let message = codec.decode(&mut dst).unwrap().unwrap();
+        assert_eq!(message.fv(fix44::MSG_SEQ_NUM), Ok(1));
+        codec.encode(message.clone(), &mut dst).unwrap();
+        assert_eq!(codec.decode(&mut dst).unwrap(), Some(message));
+    }
+
+    #[tokio::test]
+    async fn owned_messages_are_sent_across_tasks() {
+        let (client, server) = tokio::io::duplex(64);
+        let mut client = Framed::new(client, codec());
+        let mut server = Framed::new(server, codec());
+
+        let handle = tokio::spawn(async move { server.next().await.unwrap().unwrap() });
+        client.send(HEARTBEAT).await.unwrap();
+        let message = handle.await.unwrap();
+        assert_eq!(message.fv(fix44::TARGET_COMP_ID), Ok("B"));
+    }
+}
@@ -8,6 +8,10 @@ use std::convert::TryInto;
 //   8=?|9=?|35=?|10=???|
 pub const MIN_FIX_MESSAGE_LEN_IN_BYTES: usize = 20;
 
+// `BeginString <8>` and `BodyLength <9>` together are never longer than this,
+// even with zero-padding.
+pub const MAX_HEADER_LEN_IN_BYTES: usize = 64;
+
 /// The checksum field is composed of:
 ///  - `10=`       (3 characters)
 ///  - `XYZ`       (checksum value, always 3 characters)
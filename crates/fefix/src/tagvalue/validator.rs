@@ -0,0 +1,740 @@
+use super::Message;
+use crate::dict::{FixDatatype, LayoutItem, LayoutItemKind};
+use crate::field_types::{Date, MonthYear, Time, Timestamp};
+use crate::{Dictionary, FieldMap, FieldType};
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::{HashMap, HashSet};
+
+const BEGIN_STRING: u32 = 8;
+const BODY_LENGTH: u32 = 9;
+const MSG_TYPE: u32 = 35;
+const CHECK_SUM: u32 = 10;
+
+/// The first tag number that is reserved for user-defined fields.
+const FIRST_USER_DEFINED_TAG: u32 = 5000;
+
+/// A violation of the [`Dictionary`] detected by [`Validator`].
+///
+/// Every [`ValidationError`] maps to a `SessionRejectReason <373>` via
+/// [`ValidationError::session_reject_reason`], so that the offending message
+/// can be refused with a `Reject <3>`.
+///
+/// Please note that [`ValidationError`] is marked with `#[non_exhaustive]`,
+/// which future-proofs the enumeration type in case more variants are added.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// A required field is missing.
+    #[error("Required tag missing ({tag})")]
+    RequiredTagMissing {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The field is known to the [`Dictionary`], but not part of this
+    /// `MsgType <35>`.
+    #[error("Tag not defined for this message type ({tag})")]
+    TagNotDefinedForMsgType {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The field is unknown to the [`Dictionary`].
+    #[error("Undefined tag ({tag})")]
+    UndefinedTag {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The field has an empty value.
+    #[error("Tag specified without a value ({tag})")]
+    TagSpecifiedWithoutValue {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The field value is not one of the values allowed by the
+    /// [`Dictionary`].
+    #[error("Value is incorrect (out of range) for this tag ({tag})")]
+    ValueIsIncorrect {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The field value doesn't match the datatype of the field.
+    #[error("Incorrect data format for value ({tag})")]
+    IncorrectDataFormat {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// `MsgType <35>` is unknown to the [`Dictionary`].
+    #[error("Invalid MsgType(35)")]
+    InvalidMsgType,
+    /// The field appears more than once outside of repeating groups, or more
+    /// than once within the same group entry.
+    #[error("Tag appears more than once ({tag})")]
+    TagAppearsMoreThanOnce {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// A header field appears after body fields, a body field appears after
+    /// trailer fields, or `MsgType <35>` is not the third field.
+    #[error("Tag specified out of required order ({tag})")]
+    TagSpecifiedOutOfRequiredOrder {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The fields of a repeating group entry are not in the order defined by
+    /// the [`Dictionary`], or the entry doesn't start with its delimiter.
+    #[error("Repeating group fields out of order ({tag})")]
+    RepeatingGroupFieldsOutOfOrder {
+        /// The offending tag.
+        tag: u32,
+    },
+    /// The number of entries of a repeating group doesn't match the value
+    /// of its `NumInGroup` field.
+    #[error("Incorrect NumInGroup count for repeating group ({tag})")]
+    IncorrectNumInGroupCount {
+        /// The `NumInGroup` tag of the repeating group.
+        tag: u32,
+    },
+}
+
+impl ValidationError {
+    /// Returns the `SessionRejectReason <373>` that corresponds to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::ValidationError;
+    ///
+    /// let err = ValidationError::RequiredTagMissing { tag: 54 };
+    /// assert_eq!(err.session_reject_reason(), 1);
+    /// ```
+    pub fn session_reject_reason(&self) -> u32 {
+        match self {
+            Self::RequiredTagMissing { .. } => 1,
+            Self::TagNotDefinedForMsgType { .. } => 2,
+            Self::UndefinedTag { .. } => 3,
+            Self::TagSpecifiedWithoutValue { .. } => 4,
+            Self::ValueIsIncorrect { .. } => 5,
+            Self::IncorrectDataFormat { .. } => 6,
+            Self::InvalidMsgType => 11,
+            Self::TagAppearsMoreThanOnce { .. } => 13,
+            Self::TagSpecifiedOutOfRequiredOrder { .. } => 14,
+            Self::RepeatingGroupFieldsOutOfOrder { .. } => 15,
+            Self::IncorrectNumInGroupCount { .. } => 16,
+        }
+    }
+
+    /// Returns the tag that caused `self`, i.e. the `RefTagID <371>` of the
+    /// `Reject <3>`, if any.
+    pub fn ref_tag_id(&self) -> Option<u32> {
+        match self {
+            Self::RequiredTagMissing { tag }
+            | Self::TagNotDefinedForMsgType { tag }
+            | Self::UndefinedTag { tag }
+            | Self::TagSpecifiedWithoutValue { tag }
+            | Self::ValueIsIncorrect { tag }
+            | Self::IncorrectDataFormat { tag }
+            | Self::TagAppearsMoreThanOnce { tag }
+            | Self::TagSpecifiedOutOfRequiredOrder { tag }
+            | Self::RepeatingGroupFieldsOutOfOrder { tag }
+            | Self::IncorrectNumInGroupCount { tag } => Some(*tag),
+            Self::InvalidMsgType => Some(MSG_TYPE),
+        }
+    }
+}
+
+/// The checks performed by a [`Validator`]. Everything is checked by default,
+/// except that user-defined fields (i.e. tags 5000 and above) that are unknown
+/// to the [`Dictionary`] are allowed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationRules {
+    /// Report [`ValidationError::RequiredTagMissing`].
+    pub check_required_fields: bool,
+    /// Report [`ValidationError::ValueIsIncorrect`] and
+    /// [`ValidationError::IncorrectDataFormat`].
+    pub check_field_values: bool,
+    /// Report [`ValidationError::TagSpecifiedOutOfRequiredOrder`] and
+    /// [`ValidationError::RepeatingGroupFieldsOutOfOrder`].
+    pub check_field_order: bool,
+    /// Don't report [`ValidationError::UndefinedTag`] and
+    /// [`ValidationError::TagNotDefinedForMsgType`].
+    pub allow_unknown_fields: bool,
+    /// Don't report [`ValidationError::UndefinedTag`] for tags 5000 and
+    /// above.
+    pub allow_user_defined_fields: bool,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        Self {
+            check_required_fields: true,
+            check_field_values: true,
+            check_field_order: true,
+            allow_unknown_fields: false,
+            allow_user_defined_fields: true,
+        }
+    }
+}
+
+/// Validates decoded [`Message`]s against the message layouts, datatypes and
+/// code sets of a [`Dictionary`].
+///
+/// [`Decoder`](super::Decoder) only uses its [`Dictionary`] to find
+/// repeating groups and data fields; [`Validator`] checks everything else
+/// according to its [`ValidationRules`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{Config, Decoder, ValidationError, Validator};
+/// use fefix::prelude::*;
+///
+/// let validator = Validator::new(Dictionary::fix44());
+/// let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+/// decoder.config_mut().set_separator(b'|');
+///
+/// // `Side <54>` is missing.
+/// let data = b"8=FIX.4.4|9=89|35=D|49=A|56=B|34=2|52=20210302-10:00:00|11=ORDER-1|55=EUR/USD|60=20210302-10:00:00|40=1|10=000|";
+/// let message = decoder.decode(data).unwrap();
+/// let err = validator.validate(&message).unwrap_err();
+/// assert_eq!(err, ValidationError::RequiredTagMissing { tag: 54 });
+/// assert_eq!(err.session_reject_reason(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Validator {
+    rules: ValidationRules,
+    fields: IntMap<u32, FieldInfo>,
+    header: Scope,
+    trailer: Scope,
+    bodies: HashMap<Vec<u8>, Scope>,
+}
+
+impl Validator {
+    /// Creates a new [`Validator`] for `dict`, with the default
+    /// [`ValidationRules`].
+    pub fn new(dict: Dictionary) -> Self {
+        let component_scope = |name: &str| {
+            let mut scope = Scope::default();
+            if let Some(component) = dict.component_by_name(name) {
+                scope.add_items(component.items(), true);
+            }
+            scope
+        };
+        let header = component_scope("StandardHeader");
+        let trailer = component_scope("StandardTrailer");
+        let bodies = dict
+            .iter_messages()
+            .map(|message| {
+                let mut scope = Scope::default();
+                scope.add_items(message.layout(), true);
+                (message.msg_type().as_bytes().to_vec(), scope)
+            })
+            .collect();
+        let fields = dict
+            .iter_fields()
+            .map(|field| {
+                let info = FieldInfo {
+                    datatype: field.fix_datatype(),
+                    enums: field.enums().map(|enums| {
+                        enums
+                            .map(|variant| variant.value().as_bytes().to_vec())
+                            .collect()
+                    }),
+                };
+                (field.tag().get(), info)
+            })
+            .collect();
+        Self {
+            rules: ValidationRules::default(),
+            fields,
+            header,
+            trailer,
+            bodies,
+        }
+    }
+
+    /// Returns an immutable reference to the [`ValidationRules`] of `self`.
+    pub fn rules(&self) -> &ValidationRules {
+        &self.rules
+    }
+
+    /// Returns a mutable reference to the [`ValidationRules`] of `self`.
+    pub fn rules_mut(&mut self) -> &mut ValidationRules {
+        &mut self.rules
+    }
+
+    /// Returns `true` if and only if `msg_type` is defined by the
+    /// [`Dictionary`] of `self`.
+    pub fn knows_msg_type(&self, msg_type: &[u8]) -> bool {
+        self.bodies.contains_key(msg_type)
+    }
+
+    /// Validates `message` and returns the first [`ValidationError`] found,
+    /// if any.
+    pub fn validate<T>(&self, message: &Message<T>) -> Result<(), ValidationError>
+    where
+        T: AsRef<[u8]> + Clone,
+    {
+        let msg_type = message
+            .fv_raw(MSG_TYPE)
+            .ok_or(ValidationError::RequiredTagMissing { tag: MSG_TYPE })?;
+        let body = self
+            .bodies
+            .get(msg_type)
+            .ok_or(ValidationError::InvalidMsgType)?;
+        let sections = [&self.header, body, &self.trailer];
+        let mut section_i = 0;
+        let mut seen = IntSet::default();
+        let mut groups: Vec<GroupState> = Vec::new();
+        for (i, (tag, value)) in message.fields().enumerate() {
+            let tag = tag.get();
+            if self.rules.check_field_order && i == 1 && tag != MSG_TYPE {
+                return Err(ValidationError::TagSpecifiedOutOfRequiredOrder { tag: MSG_TYPE });
+            }
+            self.validate_value(tag, value)?;
+            // Repeating groups, from the innermost one.
+            let mut is_within_group = false;
+            while let Some(group) = groups.last_mut() {
+                if group.accept(tag, &self.rules)? {
+                    is_within_group = true;
+                    break;
+                }
+                if let Some(group) = groups.pop() {
+                    group.finish(&self.rules)?;
+                }
+            }
+            let scope = if is_within_group {
+                groups.last().map(|group| group.scope)
+            } else {
+                match sections.iter().position(|section| section.contains(tag)) {
+                    Some(j) => {
+                        if self.rules.check_field_order && j < section_i {
+                            return Err(ValidationError::TagSpecifiedOutOfRequiredOrder { tag });
+                        }
+                        section_i = section_i.max(j);
+                        if !seen.insert(tag) {
+                            return Err(ValidationError::TagAppearsMoreThanOnce { tag });
+                        }
+                        Some(sections[j])
+                    }
+                    None => {
+                        if self.fields.contains_key(&tag) && !self.rules.allow_unknown_fields {
+                            return Err(ValidationError::TagNotDefinedForMsgType { tag });
+                        }
+                        None
+                    }
+                }
+            };
+            if let Some(group_scope) = scope.and_then(|scope| scope.groups.get(&tag)) {
+                let num_entries = usize::deserialize(value)
+                    .map_err(|_| ValidationError::IncorrectDataFormat { tag })?;
+                groups.push(GroupState::new(tag, group_scope, num_entries));
+            }
+        }
+        while let Some(group) = groups.pop() {
+            group.finish(&self.rules)?;
+        }
+        if self.rules.check_required_fields {
+            for section in sections.iter() {
+                section.check_required(|tag| {
+                    seen.contains(&tag) || [BEGIN_STRING, BODY_LENGTH, CHECK_SUM].contains(&tag)
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_value(&self, tag: u32, value: &[u8]) -> Result<(), ValidationError> {
+        if value.is_empty() {
+            return Err(ValidationError::TagSpecifiedWithoutValue { tag });
+        }
+        let info = match self.fields.get(&tag) {
+            Some(info) => info,
+            None if self.rules.allow_unknown_fields => return Ok(()),
+            None if self.rules.allow_user_defined_fields && tag >= FIRST_USER_DEFINED_TAG => {
+                return Ok(())
+            }
+            None => return Err(ValidationError::UndefinedTag { tag }),
+        };
+        if !self.rules.check_field_values {
+            return Ok(());
+        }
+        if !is_valid_format(info.datatype, value) {
+            return Err(ValidationError::IncorrectDataFormat { tag });
+        }
+        if let Some(enums) = &info.enums {
+            let is_multiple_value = matches!(
+                info.datatype,
+                FixDatatype::MultipleCharValue | FixDatatype::MultipleStringValue
+            );
+            let is_valid = if is_multiple_value {
+                value
+                    .split(|byte| *byte == b' ')
+                    .all(|value| enums.contains(value))
+            } else {
+                enums.contains(value)
+            };
+            if !is_valid {
+                return Err(ValidationError::ValueIsIncorrect { tag });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FieldInfo {
+    datatype: FixDatatype,
+    enums: Option<HashSet<Vec<u8>>>,
+}
+
+/// The fields of a message section (i.e. header, body, or trailer) or of a
+/// repeating group entry, with components expanded.
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    // Tags in order of appearance.
+    tags: Vec<u32>,
+    positions: IntMap<u32, usize>,
+    required: Vec<u32>,
+    groups: IntMap<u32, Scope>,
+}
+
+impl Scope {
+    fn add_items<'a>(&mut self, items: impl Iterator<Item = LayoutItem<'a>>, is_required: bool) {
+        for item in items {
+            let is_required = is_required && item.required();
+            match item.kind() {
+                LayoutItemKind::Field(field) => self.add_tag(field.tag().get(), is_required),
+                LayoutItemKind::Component(component) => {
+                    self.add_items(component.items(), is_required)
+                }
+                LayoutItemKind::Group(len_field, items) => {
+                    let tag = len_field.tag().get();
+                    self.add_tag(tag, is_required);
+                    let mut group = Scope::default();
+                    group.add_items(items.into_iter(), true);
+                    self.groups.insert(tag, group);
+                }
+            }
+        }
+    }
+
+    fn add_tag(&mut self, tag: u32, is_required: bool) {
+        if self.positions.contains_key(&tag) {
+            return;
+        }
+        self.positions.insert(tag, self.tags.len());
+        self.tags.push(tag);
+        if is_required {
+            self.required.push(tag);
+        }
+    }
+
+    fn contains(&self, tag: u32) -> bool {
+        self.positions.contains_key(&tag)
+    }
+
+    fn check_required(&self, is_present: impl Fn(u32) -> bool) -> Result<(), ValidationError> {
+        match self.required.iter().find(|tag| !is_present(**tag)) {
+            Some(tag) => Err(ValidationError::RequiredTagMissing { tag: *tag }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Keeps track of the entries of a repeating group while validating them.
+struct GroupState<'a> {
+    tag: u32,
+    scope: &'a Scope,
+    num_entries: usize,
+    entries: usize,
+    // Tags of the current entry.
+    seen: IntSet<u32>,
+    last_position: usize,
+}
+
+impl<'a> GroupState<'a> {
+    fn new(tag: u32, scope: &'a Scope, num_entries: usize) -> Self {
+        Self {
+            tag,
+            scope,
+            num_entries,
+            entries: 0,
+            seen: IntSet::default(),
+            last_position: 0,
+        }
+    }
+
+    /// Returns `true` if `tag` belongs to `self`.
+    fn accept(&mut self, tag: u32, rules: &ValidationRules) -> Result<bool, ValidationError> {
+        let position = match self.scope.positions.get(&tag) {
+            Some(position) => *position,
+            None => return Ok(false),
+        };
+        if position == 0 {
+            // The delimiter starts a new entry.
+            self.finish_entry(rules)?;
+            if self.entries == self.num_entries {
+                return Err(ValidationError::IncorrectNumInGroupCount { tag: self.tag });
+            }
+            self.entries += 1;
+        } else if self.entries == 0 {
+            if rules.check_field_order {
+                return Err(ValidationError::RepeatingGroupFieldsOutOfOrder { tag });
+            }
+            // Without a delimiter, there's no entry to add `tag` to.
+            return Ok(false);
+        } else if self.seen.contains(&tag) {
+            return Err(ValidationError::TagAppearsMoreThanOnce { tag });
+        } else if rules.check_field_order && position < self.last_position {
+            return Err(ValidationError::RepeatingGroupFieldsOutOfOrder { tag });
+        }
+        self.seen.insert(tag);
+        self.last_position = position;
+        Ok(true)
+    }
+
+    fn finish_entry(&mut self, rules: &ValidationRules) -> Result<(), ValidationError> {
+        if self.entries > 0 && rules.check_required_fields {
+            let seen = &self.seen;
+            self.scope.check_required(|tag| seen.contains(&tag))?;
+        }
+        self.seen.clear();
+        self.last_position = 0;
+        Ok(())
+    }
+
+    fn finish(mut self, rules: &ValidationRules) -> Result<(), ValidationError> {
+        self.finish_entry(rules)?;
+        if self.entries != self.num_entries {
+            return Err(ValidationError::IncorrectNumInGroupCount { tag: self.tag });
+        }
+        Ok(())
+    }
+}
+
+fn is_valid_format(datatype: FixDatatype, value: &[u8]) -> bool {
+    match datatype {
+        FixDatatype::Int => is_valid_int(value.strip_prefix(b"-").unwrap_or(value)),
+        FixDatatype::Length
+        | FixDatatype::NumInGroup
+        | FixDatatype::SeqNum
+        | FixDatatype::TagNum
+        | FixDatatype::DayOfMonth => is_valid_int(value),
+        FixDatatype::Float
+        | FixDatatype::Amt
+        | FixDatatype::Price
+        | FixDatatype::PriceOffset
+        | FixDatatype::Qty
+        | FixDatatype::Percentage => is_valid_float(value),
+        FixDatatype::Char => value.len() == 1,
+        FixDatatype::Boolean => bool::deserialize(value).is_ok(),
+        FixDatatype::UtcTimestamp => Timestamp::deserialize(value).is_ok(),
+        FixDatatype::UtcTimeOnly => Time::deserialize(value).is_ok(),
+        FixDatatype::UtcDateOnly | FixDatatype::LocalMktDate => Date::deserialize(value).is_ok(),
+        FixDatatype::MonthYear => MonthYear::deserialize(value).is_ok(),
+        _ => true,
+    }
+}
+
+fn is_valid_int(value: &[u8]) -> bool {
+    !value.is_empty() && value.iter().all(u8::is_ascii_digit)
+}
+
+fn is_valid_float(value: &[u8]) -> bool {
+    let value = value.strip_prefix(b"-").unwrap_or(value);
+    let mut num_dots = 0;
+    let mut num_digits = 0;
+    for byte in value {
+        match byte {
+            b'.' => num_dots += 1,
+            b'0'..=b'9' => num_digits += 1,
+            _ => return false,
+        }
+    }
+    num_dots <= 1 && num_digits > 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::{Config, Decoder};
+    use crate::GetConfig;
+
+    fn decoder() -> Decoder<Config> {
+        let mut decoder = Decoder::<Config>::new(Dictionary::fix44());
+        decoder.config_mut().set_separator(b'|');
+        decoder
+    }
+
+    fn validate(body: &str) -> Result<(), ValidationError> {
+        validate_with(&Validator::new(Dictionary::fix44()), body)
+    }
+
+    fn validate_with(validator: &Validator, body: &str) -> Result<(), ValidationError> {
+        let data = format!("8=FIX.4.4|9={}|{}10=000|", body.len(), body);
+        let mut decoder = decoder();
+        let message = decoder.decode(data.as_bytes()).unwrap();
+        validator.validate(&message)
+    }
+
+    const HEADER: &str = "49=A|56=B|34=2|52=20210302-10:00:00.000|";
+
+    #[test]
+    fn valid_messages() {
+        validate(&format!("35=0|{}", HEADER)).unwrap();
+        validate(&format!(
+            "35=D|{}11=ORDER-1|55=EUR/USD|54=1|60=20210302-10:00:00|38=100|40=2|44=1.2|",
+            HEADER
+        ))
+        .unwrap();
+        validate(&format!(
+            "35=D|{}11=ORDER-1|453=2|448=A|447=D|452=1|448=B|447=D|55=EUR/USD|54=1|60=20210302-10:00:00|40=1|",
+            HEADER
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn msg_type_and_header_errors() {
+        assert_eq!(
+            validate(&format!("35=ZZ|{}", HEADER)),
+            Err(ValidationError::InvalidMsgType)
+        );
+        assert_eq!(
+            validate("35=0|49=A|56=B|52=20210302-10:00:00.000|"),
+            Err(ValidationError::RequiredTagMissing { tag: 34 })
+        );
+        assert_eq!(
+            validate("49=A|35=0|56=B|34=2|52=20210302-10:00:00.000|"),
+            Err(ValidationError::TagSpecifiedOutOfRequiredOrder { tag: 35 })
+        );
+        assert_eq!(
+            validate("35=1|49=A|56=B|34=2|112=TEST|52=20210302-10:00:00.000|"),
+            Err(ValidationError::TagSpecifiedOutOfRequiredOrder { tag: 52 })
+        );
+        assert_eq!(
+            validate(&format!("35=1|{}112=TEST|10=000|112=TEST|", HEADER)),
+            Err(ValidationError::TagSpecifiedOutOfRequiredOrder { tag: 112 })
+        );
+    }
+
+    #[test]
+    fn field_errors() {
+        assert_eq!(
+            validate(&format!("35=0|{}55=EUR/USD|", HEADER)),
+            Err(ValidationError::TagNotDefinedForMsgType { tag: 55 })
+        );
+        assert_eq!(
+            validate(&format!("35=0|{}4999=X|", HEADER)),
+            Err(ValidationError::UndefinedTag { tag: 4999 })
+        );
+        validate(&format!("35=0|{}5001=X|", HEADER)).unwrap();
+        assert_eq!(
+            validate(&format!("35=1|{}112=|", HEADER)),
+            Err(ValidationError::TagSpecifiedWithoutValue { tag: 112 })
+        );
+        assert_eq!(
+            validate(&format!("35=1|{}112=A|112=B|", HEADER)),
+            Err(ValidationError::TagAppearsMoreThanOnce { tag: 112 })
+        );
+        assert_eq!(
+            validate(&format!(
+                "35=D|{}11=ORDER-1|55=EUR/USD|54=Z|60=20210302-10:00:00|40=1|",
+                HEADER
+            )),
+            Err(ValidationError::ValueIsIncorrect { tag: 54 })
+        );
+        assert_eq!(
+            validate(&format!(
+                "35=D|{}11=ORDER-1|55=EUR/USD|54=1|60=20210302-10:00:00|40=1|38=1e3|",
+                HEADER
+            )),
+            Err(ValidationError::IncorrectDataFormat { tag: 38 })
+        );
+    }
+
+    #[test]
+    fn repeating_group_errors() {
+        let order = |parties: &str| {
+            format!(
+                "35=D|{}11=ORDER-1|{}55=EUR/USD|54=1|60=20210302-10:00:00|40=1|",
+                HEADER, parties
+            )
+        };
+        assert_eq!(
+            validate(&order("453=2|448=A|447=D|")),
+            Err(ValidationError::IncorrectNumInGroupCount { tag: 453 })
+        );
+        assert_eq!(
+            validate(&order("453=1|448=A|447=D|448=B|")),
+            Err(ValidationError::IncorrectNumInGroupCount { tag: 453 })
+        );
+        assert_eq!(
+            validate(&order("453=1|447=D|448=A|")),
+            Err(ValidationError::RepeatingGroupFieldsOutOfOrder { tag: 447 })
+        );
+        assert_eq!(
+            validate(&order("453=1|448=A|452=1|447=D|")),
+            Err(ValidationError::RepeatingGroupFieldsOutOfOrder { tag: 447 })
+        );
+    }
+
+    #[test]
+    fn rules_disable_checks() {
+        let mut validator = Validator::new(Dictionary::fix44());
+        validator.rules_mut().check_required_fields = false;
+        validator.rules_mut().check_field_values = false;
+        validator.rules_mut().allow_unknown_fields = true;
+        validate_with(&validator, &format!("35=D|{}54=Z|4999=X|112=A|", HEADER)).unwrap();
+    }
+
+    #[test]
+    fn venue_specific_dictionary() {
+        let dict = Dictionary::fix44()
+            .with_overlay(
+                r#"<fix>
+                    <fields>
+                        <field number="5001" name="VenueAccount" type="STRING">
+                            <value enum="H" description="HOUSE"/>
+                        </field>
+                        <field number="5002" name="NoVenueFees" type="NUMINGROUP"/>
+                        <field number="5003" name="VenueFeeType" type="CHAR"/>
+                    </fields>
+                    <messages>
+                        <message name="NewOrderSingle" msgtype="D">
+                            <field name="VenueAccount" required="Y"/>
+                            <group name="NoVenueFees" required="N">
+                                <field name="VenueFeeType" required="Y"/>
+                            </group>
+                        </message>
+                    </messages>
+                </fix>"#,
+            )
+            .unwrap();
+        let validator = Validator::new(dict.clone());
+        let mut decoder = Decoder::<Config>::new(dict);
+        decoder.config_mut().set_separator(b'|');
+        let mut validate = |venue_fields: &str| {
+            let body = format!(
+                "35=D|{}11=ORDER-1|55=EUR/USD|54=1|60=20210302-10:00:00|40=1|{}",
+                HEADER, venue_fields
+            );
+            let data = format!("8=FIX.4.4|9={}|{}10=000|", body.len(), body);
+            let message = decoder.decode(data.as_bytes()).unwrap();
+            validator.validate(&message)
+        };
+        validate("5001=H|").unwrap();
+        validate("5001=H|5002=2|5003=A|5003=B|").unwrap();
+        assert_eq!(
+            validate(""),
+            Err(ValidationError::RequiredTagMissing { tag: 5001 })
+        );
+        assert_eq!(
+            validate("5001=X|"),
+            Err(ValidationError::ValueIsIncorrect { tag: 5001 })
+        );
+        assert_eq!(
+            validate("5001=H|5002=2|5003=A|"),
+            Err(ValidationError::IncorrectNumInGroupCount { tag: 5002 })
+        );
+    }
+}